@@ -0,0 +1,288 @@
+use super::{default_transport, fetch_once, get_json, APIResult, DEFAULT_TIMEOUT};
+use serde::Deserialize;
+use tracing::info_span;
+
+/// Everything that has been written, filmed, and photographed about a single game. We only
+/// pick out the highlight videos, and only enough of those to put a thumbnail of each on screen,
+/// along with the condensed game and the recap.
+#[derive(Deserialize)]
+pub struct GameContent {
+    #[serde(default)]
+    pub highlights: Option<HighlightsContent>,
+    #[serde(default)]
+    pub media: Option<Media>,
+}
+
+#[derive(Deserialize)]
+pub struct Media {
+    /// The videos that go along with the broadcast, sorted into lists by what sort they are.
+    #[serde(rename = "epgAlternate", default)]
+    pub epg_alternate: Vec<Epg>,
+}
+
+#[derive(Deserialize)]
+pub struct Epg {
+    /// Which sort of video is in this list, E.G. "Extended Highlights".
+    pub title: String,
+    #[serde(default)]
+    pub items: Vec<Video>,
+}
+
+/// The sorts of videos that get the whole game over with in a few minutes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    /// Every pitch that mattered, which usually comes out to about ten minutes.
+    Condensed,
+    /// The couple of minutes that made the game what it was.
+    Recap,
+}
+
+impl Kind {
+    // The title of the list that the API puts this sort of video in.
+    fn title(self) -> &'static str {
+        match self {
+            Kind::Condensed => "Extended Highlights",
+            Kind::Recap => "Daily Recap",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Video {
+    /// E.G. "Condensed Game: CWS@BOS - 9/23/19"
+    #[serde(default)]
+    pub headline: String,
+    /// E.G. "00:12:05"
+    pub duration: Option<String>,
+    /// The same video in every format that it comes in.
+    #[serde(default)]
+    pub playbacks: Vec<Playback>,
+}
+
+#[derive(Deserialize)]
+pub struct Playback {
+    /// E.G. "mp4Avc" or "HTTP_CLOUD_WIRED_60".
+    pub name: String,
+    pub url: String,
+}
+
+// Yes, it really is highlights all the way down.
+#[derive(Deserialize)]
+pub struct HighlightsContent {
+    pub highlights: Option<HighlightItems>,
+}
+
+#[derive(Deserialize)]
+pub struct HighlightItems {
+    #[serde(default)]
+    pub items: Vec<Highlight>,
+}
+
+#[derive(Deserialize)]
+pub struct Highlight {
+    /// E.G. "Lopez's strong start"
+    #[serde(default)]
+    pub headline: String,
+    /// E.G. "00:00:31"
+    pub duration: Option<String>,
+    pub image: Option<HighlightImage>,
+}
+
+#[derive(Deserialize)]
+pub struct HighlightImage {
+    // Unlike the photos in the schedule these come as a list, in no order in particular and
+    // in far more sizes than anybody needs.
+    #[serde(default)]
+    pub cuts: Vec<super::Photo>,
+}
+
+impl GameContent {
+    pub fn url(game_pk: u64) -> String {
+        format!("http://statsapi.mlb.com/api/v1/game/{}/content", game_pk)
+    }
+
+    pub async fn try_from(game_pk: u64) -> APIResult<GameContent> {
+        get_json(&*default_transport(), &Self::url(game_pk), DEFAULT_TIMEOUT).await
+    }
+
+    /// The game's highlight videos, in the order that the API has them (which is, roughly,
+    /// the order that they happened in).
+    pub fn highlights(self) -> Vec<Highlight> {
+        self.highlights
+            .and_then(|highlights| highlights.highlights)
+            .map(|highlights| highlights.items)
+            .unwrap_or_default()
+    }
+
+    /// The game's video of the given sort, if one has been put up yet. That's only once the
+    /// game is over, and even then not until a while after.
+    pub fn video(&self, kind: Kind) -> Option<&Video> {
+        self.media
+            .as_ref()?
+            .epg_alternate
+            .iter()
+            .find(|epg| epg.title == kind.title())?
+            .items
+            .first()
+    }
+}
+
+impl Video {
+    /// Where the video can be watched in a browser. A plain MP4 plays just about anywhere, so
+    /// that's the one to have. The streams (HLS and the like) are a last resort.
+    pub fn url(&self) -> Option<&str> {
+        self.playbacks
+            .iter()
+            .find(|playback| playback.name == "mp4Avc")
+            .or_else(|| {
+                self.playbacks
+                    .iter()
+                    .find(|playback| playback.url.ends_with(".mp4"))
+            })
+            .or_else(|| self.playbacks.first())
+            .map(|playback| playback.url.as_str())
+    }
+
+    /// How long the video runs, the same as Highlight::duration.
+    pub fn duration(&self) -> Option<String> {
+        clock(self.duration.as_deref()?)
+    }
+}
+
+impl Highlight {
+    /// The source of the smallest thumbnail that is still at least the given width, or of the
+    /// biggest one there is if none of them are that big.
+    pub fn thumbnail(&self, width: u32) -> Option<&str> {
+        let cuts = &self.image.as_ref()?.cuts;
+        cuts.iter()
+            .filter(|cut| cut.width >= width)
+            .min_by_key(|cut| cut.width)
+            .or_else(|| cuts.iter().max_by_key(|cut| cut.width))
+            .map(|cut| cut.src.as_str())
+    }
+
+    /// How long the video runs, E.G. "0:31" or "1:02:03", going without the hours when there
+    /// aren't any (which is nearly always).
+    pub fn duration(&self) -> Option<String> {
+        clock(self.duration.as_deref()?)
+    }
+}
+
+// The API writes out how long everything runs as HH:MM:SS, hours or not.
+fn clock(duration: &str) -> Option<String> {
+    let parts: Vec<u32> = duration
+        .split(':')
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+    match parts.as_slice() {
+        [0, minutes, seconds] | [minutes, seconds] => Some(format!("{}:{:02}", minutes, seconds)),
+        [hours, minutes, seconds] => Some(format!("{}:{:02}:{:02}", hours, minutes, seconds)),
+        _ => None,
+    }
+}
+
+/// Gets the content for the given game once, handing it back over the returned channel. See
+/// fetch_once for what becomes of a failure.
+pub fn fetch(game_pk: u64) -> crossbeam_channel::Receiver<GameContent> {
+    let lookup = GameContent::try_from(game_pk);
+    fetch_once(lookup, info_span!("content", game_pk), "the game's content")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights() {
+        let content: GameContent = serde_json::from_str(
+            r#"{
+                "editorial": {},
+                "highlights": {
+                    "highlights": {
+                        "items": [
+                            {
+                                "headline": "Lopez's strong start",
+                                "duration": "00:00:31",
+                                "image": {
+                                    "cuts": [
+                                        {"width": 640, "height": 360, "src": "640.jpg"},
+                                        {"width": 124, "height": 70, "src": "124.jpg"},
+                                        {"width": 320, "height": 180, "src": "320.jpg"}
+                                    ]
+                                }
+                            },
+                            {"headline": "Moncada's long home run", "duration": "00:12:05"},
+                            {"headline": "The whole game", "duration": "01:02:03"}
+                        ]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let highlights = content.highlights();
+        assert_eq!(highlights.len(), 3);
+        assert_eq!(highlights[0].thumbnail(320), Some("320.jpg"));
+        assert_eq!(highlights[0].thumbnail(300), Some("320.jpg"));
+        assert_eq!(highlights[0].thumbnail(1280), Some("640.jpg"));
+        assert_eq!(highlights[1].thumbnail(320), None);
+        let durations: Vec<_> = highlights.iter().map(Highlight::duration).collect();
+        assert_eq!(
+            durations,
+            vec![
+                Some("0:31".to_string()),
+                Some("12:05".to_string()),
+                Some("1:02:03".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn condensed_and_recap() {
+        let content: GameContent = serde_json::from_str(
+            r#"{
+                "media": {
+                    "epgAlternate": [
+                        {
+                            "title": "Extended Highlights",
+                            "items": [{
+                                "headline": "Condensed Game: CWS@BOS - 9/23/19",
+                                "duration": "00:12:05",
+                                "playbacks": [
+                                    {"name": "HTTP_CLOUD_WIRED_60", "url": "condensed.m3u8"},
+                                    {"name": "mp4Avc", "url": "condensed.mp4"}
+                                ]
+                            }]
+                        },
+                        {
+                            "title": "Daily Recap",
+                            "items": [{
+                                "headline": "Recap: CWS 8, BOS 1",
+                                "playbacks": [{"name": "HTTP_CLOUD_WIRED_60", "url": "recap.m3u8"}]
+                            }]
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        let condensed = content.video(Kind::Condensed).unwrap();
+        assert_eq!(condensed.url(), Some("condensed.mp4"));
+        assert_eq!(condensed.duration(), Some("12:05".to_string()));
+        let recap = content.video(Kind::Recap).unwrap();
+        assert_eq!(recap.headline, "Recap: CWS 8, BOS 1");
+        assert_eq!(recap.url(), Some("recap.m3u8"));
+        assert_eq!(recap.duration(), None);
+        // Neither one is up before the game is over.
+        let content: GameContent = serde_json::from_str(r#"{"media": {}}"#).unwrap();
+        assert!(content.video(Kind::Condensed).is_none());
+    }
+
+    #[test]
+    fn no_highlights() {
+        let content: GameContent = serde_json::from_str(r#"{"highlights": {}}"#).unwrap();
+        assert!(content.highlights().is_empty());
+        let content: GameContent = serde_json::from_str("{}").unwrap();
+        assert!(content.highlights().is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use super::{fetch_once, APIResult, Schedule, ScheduleRequest};
+use chrono::NaiveDate;
+use tracing::info_span;
+
+/// The schedules of a whole stretch of days, which came back from a single date range query
+/// (see ScheduleRequest::date_range), for handing back out a day (or a few) at a time.
+///
+/// The API leaves days without any games off entirely, so it's the range that was asked for
+/// that says which days this knows about, and not whichever dates happened to come back.
+#[derive(Clone)]
+pub struct Days {
+    start: NaiveDate,
+    end: NaiveDate,
+    schedule: Schedule,
+}
+
+impl Days {
+    /// Asks for every game from start through end, inclusively, in the given sport. Only the
+    /// clubs are hydrated, which is plenty for listing the games but not for much else.
+    pub async fn try_from(start: NaiveDate, end: NaiveDate, sport_id: u32) -> APIResult<Days> {
+        let url = ScheduleRequest::new()
+            .date_range(start, end)
+            .sport_id(sport_id)
+            .hydrate("team")
+            .url();
+        Ok(Days::new(Schedule::try_from(url).await?, start, end))
+    }
+
+    /// Holds on to the given schedule, which is what came back when asking for start through
+    /// end, inclusively.
+    pub fn new(schedule: Schedule, start: NaiveDate, end: NaiveDate) -> Days {
+        Days {
+            start,
+            end,
+            schedule,
+        }
+    }
+
+    /// Whether or not every one of the given number of days, starting at the given one, is in
+    /// here.
+    pub fn covers(&self, start: NaiveDate, days: u32) -> bool {
+        start >= self.start && last(start, days) <= self.end
+    }
+
+    /// The schedule for the given number of days starting at the given one, the same as
+    /// though it had been asked for on its own. None unless every one of those days is in
+    /// here.
+    pub fn schedule(&self, start: NaiveDate, days: u32) -> Option<Schedule> {
+        if !self.covers(start, days) {
+            return None;
+        }
+        let end = last(start, days);
+        let dates = self
+            .schedule
+            .dates
+            .iter()
+            .filter(|date| {
+                NaiveDate::parse_from_str(&date.date, "%Y-%m-%d")
+                    .map(|date| date >= start && date <= end)
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .collect();
+        Some(Schedule {
+            copyright: self.schedule.copyright.clone(),
+            dates,
+        })
+    }
+}
+
+// The last of the given number of days starting at the given one. Zero days is taken to mean
+// the one.
+fn last(start: NaiveDate, days: u32) -> NaiveDate {
+    start + chrono::Duration::days(days.max(1) as i64 - 1)
+}
+
+/// Looks up the days from start through end once, handing them back over the returned channel.
+/// See fetch_once for what becomes of a failure.
+pub fn fetch(start: NaiveDate, end: NaiveDate, sport_id: u32) -> crossbeam_channel::Receiver<Days> {
+    let lookup = Days::try_from(start, end, sport_id);
+    fetch_once(lookup, info_span!("days", %start, %end), "the days")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days() {
+        let schedule: Schedule = serde_json::from_str(
+            r#"{"copyright": "Copyright", "dates": [
+                {"date": "2019-09-22", "games": []},
+                {"date": "2019-09-24", "games": []},
+                {"date": "2019-09-25", "games": []}
+            ]}"#,
+        )
+        .unwrap();
+        let days = Days::new(
+            schedule,
+            NaiveDate::from_ymd_opt(2019, 9, 20).unwrap(),
+            NaiveDate::from_ymd_opt(2019, 9, 26).unwrap(),
+        );
+        let dates = |schedule: Schedule| -> Vec<String> {
+            schedule.dates.into_iter().map(|date| date.date).collect()
+        };
+        let day = NaiveDate::from_ymd_opt(2019, 9, 24).unwrap();
+        assert_eq!(dates(days.schedule(day, 1).unwrap()), ["2019-09-24"]);
+        assert_eq!(
+            dates(days.schedule(day, 2).unwrap()),
+            ["2019-09-24", "2019-09-25"]
+        );
+        // An off day that it knows about is different from one that it doesn't.
+        let off = days
+            .schedule(NaiveDate::from_ymd_opt(2019, 9, 23).unwrap(), 1)
+            .unwrap();
+        assert!(off.dates.is_empty());
+        assert_eq!(off.copyright, "Copyright");
+        assert!(days.covers(NaiveDate::from_ymd_opt(2019, 9, 20).unwrap(), 7));
+        assert!(days
+            .schedule(NaiveDate::from_ymd_opt(2019, 9, 26).unwrap(), 2)
+            .is_none());
+        assert!(days
+            .schedule(NaiveDate::from_ymd_opt(2019, 9, 19).unwrap(), 1)
+            .is_none());
+    }
+}
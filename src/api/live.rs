@@ -0,0 +1,453 @@
+use super::{default_transport, fetch_once, get_json, spawn, APIResult, DEFAULT_TIMEOUT};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{info_span, warn, Instrument};
+
+/// The (enormous) live feed for a single game. We only pick out the linescore, which is
+/// where the inning, count, and score live, the plays that scored runs, the pitchers'
+/// records out of the boxscore, and the weather.
+#[derive(Deserialize)]
+pub struct Feed {
+    #[serde(rename = "gameData", default)]
+    pub game_data: GameData,
+    #[serde(rename = "liveData")]
+    pub live_data: LiveData,
+}
+
+#[derive(Default, Deserialize)]
+pub struct GameData {
+    /// Only filled in once the game has gotten underway.
+    pub weather: Option<Weather>,
+}
+
+/// What it was like out at first pitch. Every bit of it is written out for people, so none of
+/// it is any good for doing arithmetic with.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Weather {
+    /// E.G. "Partly Cloudy", or "Dome" or "Roof Closed" for games played indoors.
+    pub condition: Option<String>,
+    /// In degrees Fahrenheit, E.G. "72".
+    pub temp: Option<String>,
+    /// E.G. "9 mph, Out To CF".
+    pub wind: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct LiveData {
+    pub linescore: Linescore,
+    #[serde(default)]
+    pub plays: Plays,
+    #[serde(default)]
+    pub boxscore: Boxscore,
+}
+
+#[derive(Default, Deserialize)]
+pub struct Plays {
+    /// Where the plays that scored are in all_plays.
+    #[serde(rename = "scoringPlays", default)]
+    pub scoring_plays: Vec<usize>,
+    /// Every last play of the game, which is where most of the feed's bulk comes from.
+    #[serde(rename = "allPlays", default)]
+    pub all_plays: Vec<Play>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Play {
+    pub result: PlayResult,
+    pub about: About,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct PlayResult {
+    /// E.G. "Jose Abreu homers (30) on a fly ball to left center field."
+    #[serde(default)]
+    pub description: String,
+    /// The score once the play was over.
+    #[serde(rename = "awayScore", default)]
+    pub away_score: u32,
+    #[serde(rename = "homeScore", default)]
+    pub home_score: u32,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct About {
+    pub inning: u32,
+    #[serde(rename = "isTopInning", default)]
+    pub is_top_inning: bool,
+}
+
+#[derive(Default, Deserialize)]
+pub struct Boxscore {
+    pub teams: Option<BoxscoreTeams>,
+}
+
+#[derive(Deserialize)]
+pub struct BoxscoreTeams {
+    pub away: BoxscoreTeam,
+    pub home: BoxscoreTeam,
+}
+
+#[derive(Deserialize)]
+pub struct BoxscoreTeam {
+    /// Everybody on the roster, keyed by "ID" and then their id, E.G. "ID625643".
+    #[serde(default)]
+    pub players: HashMap<String, Player>,
+}
+
+#[derive(Deserialize)]
+pub struct Player {
+    pub person: super::Person,
+    #[serde(rename = "seasonStats", default)]
+    pub season_stats: SeasonStats,
+}
+
+#[derive(Default, Deserialize)]
+pub struct SeasonStats {
+    #[serde(default)]
+    pub pitching: Record,
+}
+
+/// How a pitcher's season is going, as of the end of this game.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+pub struct Record {
+    #[serde(default)]
+    pub wins: u32,
+    #[serde(default)]
+    pub losses: u32,
+    #[serde(default)]
+    pub saves: u32,
+}
+
+/// What is worth holding on to out of a live feed, which is a good deal less than all of it.
+#[derive(Clone)]
+pub struct Snapshot {
+    pub linescore: Linescore,
+    /// The plays that scored, in the order that they happened.
+    pub scoring_plays: Vec<Play>,
+    /// Every player's pitching record for the season, by their id. Most of them have never
+    /// pitched, but they're all there and there's no telling who from the feed itself.
+    pub records: HashMap<u64, Record>,
+    pub weather: Option<Weather>,
+}
+
+impl From<Feed> for Snapshot {
+    fn from(feed: Feed) -> Self {
+        let mut live_data = feed.live_data;
+        let plays = &mut live_data.plays;
+        let scoring_plays = plays
+            .scoring_plays
+            .iter()
+            .filter_map(|index| plays.all_plays.get(*index).cloned())
+            .collect();
+        let records = live_data
+            .boxscore
+            .teams
+            .into_iter()
+            .flat_map(|teams| teams.away.players.into_iter().chain(teams.home.players))
+            .map(|(_, player)| (player.person.id, player.season_stats.pitching))
+            .collect();
+        Snapshot {
+            linescore: live_data.linescore,
+            scoring_plays,
+            records,
+            weather: feed.game_data.weather,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Linescore {
+    /// E.G. "5th"
+    #[serde(rename = "currentInningOrdinal")]
+    pub inning: Option<String>,
+    /// One of "Top", "Middle", "Bottom", or "End".
+    #[serde(rename = "inningState")]
+    pub inning_state: Option<String>,
+    #[serde(default)]
+    pub balls: u32,
+    #[serde(default)]
+    pub strikes: u32,
+    #[serde(default)]
+    pub outs: u32,
+    pub teams: LinescoreTeams,
+    /// Every inning that has been started, in order.
+    #[serde(default)]
+    pub innings: Vec<Inning>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Inning {
+    /// 1 for the first, and so on into extras.
+    pub num: u32,
+    #[serde(default)]
+    pub away: InningHalf,
+    #[serde(default)]
+    pub home: InningHalf,
+}
+
+#[derive(Clone, Default, Deserialize)]
+pub struct InningHalf {
+    /// Missing for a half that hasn't been played (yet, or at all, E.G. the bottom of the 9th
+    /// when the home team is already ahead).
+    pub runs: Option<u32>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct LinescoreTeams {
+    pub away: LinescoreTeam,
+    pub home: LinescoreTeam,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct LinescoreTeam {
+    #[serde(default)]
+    pub runs: u32,
+    #[serde(default)]
+    pub hits: u32,
+    #[serde(default)]
+    pub errors: u32,
+}
+
+impl Feed {
+    pub fn url(game_pk: u64) -> String {
+        format!(
+            "http://statsapi.mlb.com/api/v1.1/game/{}/feed/live",
+            game_pk
+        )
+    }
+
+    pub async fn try_from(game_pk: u64) -> APIResult<Feed> {
+        get_json(&*default_transport(), &Self::url(game_pk), DEFAULT_TIMEOUT).await
+    }
+}
+
+impl Linescore {
+    /// E.G. "3-2, Top 5th, 2-1, 1 out"
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{}-{}", self.teams.away.runs, self.teams.home.runs);
+        if let (Some(state), Some(inning)) = (&self.inning_state, &self.inning) {
+            summary.push_str(&format!(", {} {}", state, inning));
+        }
+        // The count only means anything while someone is actually at the plate.
+        if let Some("Top") | Some("Bottom") = self.inning_state.as_deref() {
+            summary.push_str(&format!(
+                ", {}-{}, {} {}",
+                self.balls,
+                self.strikes,
+                self.outs,
+                if self.outs == 1 { "out" } else { "outs" }
+            ));
+        }
+        summary
+    }
+}
+
+/// Polls the live feed for the given game every interval, handing back each new snapshot of it
+/// over the returned channel.
+///
+/// Polling stops on its own once the receiver is dropped, so the lifetime of the poll is
+/// simply the lifetime of whoever is holding on to the other end. A failed poll is logged
+/// and then tried again on the next tick.
+pub fn poll(game_pk: u64, interval: Duration) -> crossbeam_channel::Receiver<Snapshot> {
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let polling = async move {
+        let mut ticks = tokio::time::interval(interval);
+        loop {
+            ticks.tick().await;
+            let snapshot = match Feed::try_from(game_pk).await {
+                Ok(feed) => Snapshot::from(feed),
+                Err(err) => {
+                    warn!(error = %err, "Failed to poll the live feed");
+                    continue;
+                }
+            };
+            // The channel only holds one snapshot at a time. If the last one hasn't been
+            // picked up yet then this one is fresher anyways, so it is fine to skip it and
+            // try again next time. A disconnect, however, means that nobody cares anymore.
+            if let Err(crossbeam_channel::TrySendError::Disconnected(_)) = tx.try_send(snapshot) {
+                return;
+            }
+        }
+    };
+    spawn(polling.instrument(info_span!("live", game_pk)));
+    rx
+}
+
+/// Gets a snapshot of the live feed for the given game once, handing it back over the returned
+/// channel. This is the one-off version of poll, for games that are over and so aren't going to
+/// change. See fetch_once for what becomes of a failure.
+pub fn fetch(game_pk: u64) -> crossbeam_channel::Receiver<Snapshot> {
+    let lookup = async move { Feed::try_from(game_pk).await.map(Snapshot::from) };
+    fetch_once(lookup, info_span!("live", game_pk), "the live feed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary() {
+        let linescore: Linescore = serde_json::from_str(
+            r#"{
+                "currentInningOrdinal": "5th",
+                "inningState": "Top",
+                "balls": 2,
+                "strikes": 1,
+                "outs": 1,
+                "teams": {"away": {"runs": 3}, "home": {"runs": 2}}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(linescore.summary(), "3-2, Top 5th, 2-1, 1 out");
+    }
+
+    #[test]
+    fn summary_between_innings() {
+        let linescore: Linescore = serde_json::from_str(
+            r#"{
+                "currentInningOrdinal": "7th",
+                "inningState": "Middle",
+                "outs": 3,
+                "teams": {"away": {"runs": 0}, "home": {"runs": 0}}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(linescore.summary(), "0-0, Middle 7th");
+    }
+
+    #[test]
+    fn innings() {
+        let linescore: Linescore = serde_json::from_str(
+            r#"{
+                "innings": [
+                    {"num": 1, "away": {"runs": 2, "hits": 3}, "home": {"runs": 0}},
+                    {"num": 2, "away": {"runs": 0}, "home": {}}
+                ],
+                "teams": {
+                    "away": {"runs": 2, "hits": 5, "errors": 1},
+                    "home": {"runs": 0, "hits": 2}
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(linescore.innings.len(), 2);
+        assert_eq!(linescore.innings[0].away.runs, Some(2));
+        assert_eq!(linescore.innings[1].home.runs, None);
+        assert_eq!(linescore.teams.away.hits, 5);
+        assert_eq!(linescore.teams.home.errors, 0);
+    }
+
+    #[test]
+    fn scoring_plays() {
+        let feed: Feed = serde_json::from_str(
+            r#"{
+                "liveData": {
+                    "linescore": {"teams": {"away": {"runs": 1}, "home": {"runs": 1}}},
+                    "plays": {
+                        "scoringPlays": [1, 2],
+                        "allPlays": [
+                            {
+                                "result": {"description": "Tim Anderson strikes out swinging."},
+                                "about": {"inning": 1, "isTopInning": true}
+                            },
+                            {
+                                "result": {
+                                    "description": "Jose Abreu homers (30).",
+                                    "awayScore": 1,
+                                    "homeScore": 0
+                                },
+                                "about": {"inning": 1, "isTopInning": true}
+                            },
+                            {
+                                "result": {
+                                    "description": "Rafael Devers singles. Mookie Betts scores.",
+                                    "awayScore": 1,
+                                    "homeScore": 1
+                                },
+                                "about": {"inning": 3, "isTopInning": false}
+                            }
+                        ]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let snapshot = Snapshot::from(feed);
+        assert_eq!(snapshot.linescore.teams.home.runs, 1);
+        assert!(snapshot.records.is_empty());
+        assert!(snapshot.weather.is_none());
+        let plays = &snapshot.scoring_plays;
+        assert_eq!(plays.len(), 2);
+        assert_eq!(plays[0].result.description, "Jose Abreu homers (30).");
+        assert!(plays[0].about.is_top_inning);
+        assert_eq!(plays[1].about.inning, 3);
+        assert_eq!(
+            (plays[1].result.away_score, plays[1].result.home_score),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn records() {
+        let feed: Feed = serde_json::from_str(
+            r#"{
+                "liveData": {
+                    "linescore": {"teams": {"away": {"runs": 8}, "home": {"runs": 1}}},
+                    "boxscore": {
+                        "teams": {
+                            "away": {
+                                "players": {
+                                    "ID625643": {
+                                        "person": {"id": 625643, "fullName": "Reynaldo Lopez"},
+                                        "seasonStats": {
+                                            "batting": {"hits": 0},
+                                            "pitching": {"wins": 9, "losses": 14, "saves": 0}
+                                        }
+                                    }
+                                }
+                            },
+                            "home": {
+                                "players": {
+                                    "ID519144": {
+                                        "person": {"id": 519144, "fullName": "Rick Porcello"},
+                                        "seasonStats": {"pitching": {"wins": 13, "losses": 12}}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let records = Snapshot::from(feed).records;
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[&625643],
+            Record {
+                wins: 9,
+                losses: 14,
+                saves: 0
+            }
+        );
+        assert_eq!(records[&519144].losses, 12);
+    }
+
+    #[test]
+    fn weather() {
+        let feed: Feed = serde_json::from_str(
+            r#"{
+                "gameData": {
+                    "weather": {"condition": "Partly Cloudy", "temp": "72", "wind": "9 mph, Out To CF"}
+                },
+                "liveData": {"linescore": {"teams": {"away": {}, "home": {}}}}
+            }"#,
+        )
+        .unwrap();
+        let weather = Snapshot::from(feed).weather.unwrap();
+        assert_eq!(weather.condition.as_deref(), Some("Partly Cloudy"));
+        assert_eq!(weather.temp.as_deref(), Some("72"));
+        assert_eq!(weather.wind.as_deref(), Some("9 mph, Out To CF"));
+    }
+}
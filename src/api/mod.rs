@@ -1,15 +1,41 @@
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use std::fmt::Formatter;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::{debug, debug_span, warn, Instrument, Span};
 
-#[derive(Deserialize)]
+pub mod content;
+pub mod days;
+pub mod live;
+pub mod people;
+mod request;
+pub mod scoreboard;
+mod season;
+mod sport;
+pub mod teams;
+pub mod transactions;
+mod transport;
+
+pub use request::ScheduleRequest;
+pub use season::Season;
+pub use sport::Sport;
+pub use transport::{default_transport, Download, Hyper, Transport};
+
+/// How long any one request is given to come back before it is given up on, unless
+/// the caller says otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Deserialize)]
 pub struct Schedule {
+    #[serde(default)]
     pub copyright: String,
+    #[serde(default)]
     pub dates: Vec<Date>,
 }
 
-pub static DEFAULT: &str = "http://statsapi.mlb.com/api/v1/schedule?hydrate=\
-    game(content(editorial(recap))),decisions&date=2018-06-10&sportId=1";
-
 impl Schedule {
     /// I do not believe that there is an async version of std::convert provided by anyone.
     /// This'd be a good point of conversation if you know otherwise because, of course,
@@ -22,7 +48,7 @@ impl Schedule {
     /// lets the caller be more carefree on their types and lets the templating
     /// system take the wheel. E.G.
     ///
-    ///  ```
+    ///  ```ignore
     /// let url = "https://black.coffee".to_string();
     /// Schedule::try_from(&url).unwrap(); // as a borrow
     /// Schedule::try_from(url).unwrap(); // as a move
@@ -35,90 +61,463 @@ impl Schedule {
     /// which increases the raw size of the binary. Alternatively, a Box::<dyn trait> incurs
     /// the wrath of a fat pointer with a dynamic lookup to the concrete type. Pick your poison.
     pub async fn try_from<T: AsRef<str>>(src: T) -> APIResult<Schedule> {
-        let target = src.as_ref().parse::<hyper::Uri>().map_err(|err| APIError {
-            src: src.as_ref().to_string(),
-            context: ErrorContext::URIParsing,
-            original: err.to_string(),
-        })?;
-        let resp = hyper::Client::default()
-            .get(target)
-            .await
-            .map_err(|err| APIError {
-                src: src.as_ref().to_string(),
-                context: ErrorContext::ConnectionEstablishment,
-                original: err.to_string(),
-            })?;
-        let buf = hyper::body::to_bytes(resp).await.map_err(|err| APIError {
-            src: src.as_ref().to_string(),
-            context: ErrorContext::Downloading,
-            original: err.to_string(),
-        })?;
-        serde_json::from_slice(&buf).map_err(|err| APIError {
-            src: src.as_ref().to_string(),
-            context: ErrorContext::Deserializing,
-            original: err.to_string(),
+        Self::try_from_using(&*default_transport(), src).await
+    }
+
+    /// The same as try_from, but over the given transport rather than the default one.
+    pub async fn try_from_using<T: AsRef<str>>(
+        transport: &dyn Transport,
+        src: T,
+    ) -> APIResult<Schedule> {
+        get_json(transport, src.as_ref(), DEFAULT_TIMEOUT).await
+    }
+
+    /// The same as try_from, except that the request is given up on after the given timeout
+    /// or as soon as the Cancel on the other end of cancelled says so, whichever comes first.
+    pub async fn try_from_within<T: AsRef<str>>(
+        src: T,
+        timeout: Duration,
+        mut cancelled: Cancelled,
+    ) -> APIResult<Schedule> {
+        let src = src.as_ref();
+        let transport = default_transport();
+        tokio::select! {
+            schedule = get_json(&*transport, src, timeout) => schedule,
+            _ = cancelled.wait() => Err(APIError {
+                src: src.to_string(),
+                context: ErrorContext::Cancelled,
+                original: "the request was called off".to_string(),
+            }),
+        }
+    }
+
+    /// Whether or not there is a single game to be had in this schedule.
+    pub fn is_empty(&self) -> bool {
+        self.dates.iter().all(|date| date.games.is_empty())
+    }
+}
+
+// Every endpoint in the statsapi is a plain GET that hands back JSON, so they all
+// funnel through here. A stalled connection would otherwise hang on forever, so the
+// whole round trip, body and all, has to fit inside of the given timeout.
+async fn get_json<T: DeserializeOwned>(
+    transport: &dyn Transport,
+    src: &str,
+    timeout: Duration,
+) -> APIResult<T> {
+    let download = download_json(transport, src).instrument(debug_span!("request", src));
+    tokio::time::timeout(timeout, download)
+        .await
+        .unwrap_or_else(|err| {
+            Err(APIError {
+                src: src.to_string(),
+                context: ErrorContext::TimedOut,
+                original: format!("{} after {:?}", err, timeout),
+            })
         })
+}
+
+async fn download_json<T: DeserializeOwned>(transport: &dyn Transport, src: &str) -> APIResult<T> {
+    let buf = transport.get(src).await?;
+    debug!(bytes = buf.len(), "Downloaded");
+    serde_json::from_slice(&buf).map_err(|err| APIError {
+        src: src.to_string(),
+        context: ErrorContext::Deserializing,
+        original: err.to_string(),
+    })
+}
+
+/// The first bit of a response body, for putting into error messages. Error pages can be
+/// whole HTML documents, and nobody wants to scroll through one of those in a log.
+pub(crate) fn snippet(body: &[u8]) -> String {
+    const MAX: usize = 200;
+    let body = String::from_utf8_lossy(body);
+    let body = body.trim();
+    match body.char_indices().nth(MAX) {
+        Some((end, _)) => format!("{}...", &body[..end]),
+        None => body.to_string(),
+    }
+}
+
+/// Calls off every request that was handed its Cancelled, either when cancel is called or
+/// when this is dropped. The latter makes it easy to tie a request to the lifetime of
+/// whatever is waiting on it.
+pub struct Cancel(watch::Sender<bool>);
+
+/// The other end of a Cancel, to be handed to the requests that it is in charge of.
+///
+/// Every Cancelled is also called off by shutdown, no matter what its Cancel has to say.
+#[derive(Clone)]
+pub struct Cancelled {
+    cancel: watch::Receiver<bool>,
+    shutdown: watch::Receiver<bool>,
+}
+
+lazy_static! {
+    // The Cancel behind shutdown, which is taken (and used) the first time that it is called.
+    static ref SHUTDOWN: (Mutex<Option<watch::Sender<bool>>>, watch::Receiver<bool>) = {
+        let (tx, rx) = watch::channel(false);
+        (Mutex::new(Some(tx)), rx)
+    };
+}
+
+/// Makes a new pair of Cancel and Cancelled.
+pub fn cancellation() -> (Cancel, Cancelled) {
+    let (tx, rx) = watch::channel(false);
+    let cancelled = Cancelled {
+        cancel: rx,
+        shutdown: SHUTDOWN.1.clone(),
+    };
+    (Cancel(tx), cancelled)
+}
+
+/// Calls off every Cancelled there is (and ever will be), along with every task that was
+/// handed to spawn. This is for when the app is on its way out, so that nothing is left
+/// running (and complaining about its results having nowhere to go) while it goes.
+pub fn shutdown() {
+    if let Some(tx) = SHUTDOWN.0.lock().unwrap().take() {
+        let _ = tx.broadcast(true);
+    }
+}
+
+/// Spawns the given task onto the runtime, where it runs until it is done or until
+/// shutdown is called, whichever comes first. Everything that this library puts in the
+/// background goes through here.
+pub fn spawn<F: Future<Output = ()> + Send + 'static>(task: F) {
+    tokio::task::spawn(until(task, SHUTDOWN.1.clone()));
+}
+
+/// Runs the given lookup once in the background (inside of the given span), handing whatever it
+/// comes up with back over the returned channel.
+///
+/// A failure is logged as a failure to get what, E.G. "the game's content", and then the
+/// channel is simply hung up on. So from the other end, a disconnect without anything having
+/// come through it means that there's nothing coming.
+pub fn fetch_once<T, F>(lookup: F, span: Span, what: &'static str) -> crossbeam_channel::Receiver<T>
+where
+    T: Send + 'static,
+    F: Future<Output = APIResult<T>> + Send + 'static,
+{
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let fetching = async move {
+        match lookup.await {
+            // Nobody waiting on it anymore is no big deal.
+            Ok(found) => drop(tx.send(found)),
+            Err(err) => warn!(error = %err, "Failed to get {}", what),
+        }
+    };
+    spawn(fetching.instrument(span));
+    rx
+}
+
+// Parses one of our own endpoints, for adding a query on to. They're our own constants, so
+// this failing is a bug on our end.
+fn endpoint(endpoint: &str) -> url::Url {
+    url::Url::parse(endpoint).unwrap()
+}
+
+// Runs the given task until it is done or until the given watch says to stop.
+async fn until<F: Future<Output = ()>>(task: F, mut stop: watch::Receiver<bool>) {
+    tokio::select! {
+        _ = task => (),
+        _ = stopped(&mut stop) => (),
+    }
+}
+
+impl Cancel {
+    pub fn cancel(self) {
+        let _ = self.0.broadcast(true);
+    }
+}
+
+impl Cancelled {
+    /// Resolves once the Cancel on the other end has been used or dropped, or once shutdown
+    /// has been called.
+    pub async fn wait(&mut self) {
+        tokio::select! {
+            _ = stopped(&mut self.cancel) => (),
+            _ = stopped(&mut self.shutdown) => (),
+        }
     }
 }
 
-#[derive(Deserialize)]
+// Resolves once the given watch says to stop, or once there is nobody left to say otherwise.
+async fn stopped(rx: &mut watch::Receiver<bool>) {
+    // The first value out of a watch is whatever it was made with, so
+    // we have to keep waiting until we see something that says to stop.
+    while let Some(false) = rx.recv().await {}
+}
+
+#[derive(Clone, Deserialize)]
 pub struct Date {
     pub date: String,
+    #[serde(default)]
     pub games: Vec<Game>,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct Game {
-    pub content: Content,
+    /// The primary key of the game, which is what every other game centric endpoint
+    /// (E.G. the live feed) wants to be given.
+    #[serde(rename = "gamePk")]
+    pub game_pk: u64,
+    /// When the first pitch is (or was) scheduled for, E.G. "2018-06-10T17:05:00Z".
+    #[serde(rename = "gameDate")]
+    pub game_date: Option<String>,
+    /// E.G. "2019".
+    pub season: Option<String>,
+    pub status: Status,
+    pub teams: Teams,
+    // Only games that have been decided have decisions, for obvious reasons.
+    pub decisions: Option<Decisions>,
+    /// Where the game is being played.
+    pub venue: Option<Venue>,
+    /// Everybody carrying the game. Only hydrated when asked for, with "broadcasts(all)".
+    #[serde(default)]
+    pub broadcasts: Vec<Broadcast>,
+    /// Anything at all not being the shape that we expect (which the minor leagues are prone
+    /// to) costs the game its story and photos, rather than the whole schedule.
+    #[serde(default, deserialize_with = "lenient")]
+    pub content: Option<Content>,
+    /// Where the game stands, inning by inning. Only hydrated when asked for, with "linescore".
+    #[serde(default, deserialize_with = "lenient")]
+    pub linescore: Option<live::Linescore>,
+}
+
+impl Game {
+    /// The best article that there is to be had about this game. That is, the recap written
+    /// for the home crowd, then the one written for the away crowd, and then the preview.
+    ///
+    /// Articles without a headline don't count. The minor leagues have plenty of those, which
+    /// are little more than a placeholder.
+    pub fn article(&self) -> Option<&Article> {
+        let editorial = self.content.as_ref()?.editorial.as_ref()?;
+        let recap = editorial.recap.as_ref();
+        let written = |article: &&Article| !article.headline.trim().is_empty();
+        recap
+            .and_then(|recap| recap.home.as_ref().filter(written))
+            .or_else(|| recap.and_then(|recap| recap.away.as_ref().filter(written)))
+            .or_else(|| {
+                editorial
+                    .preview
+                    .as_ref()
+                    .and_then(|preview| preview.items.iter().find(written))
+            })
+    }
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Status {
+    /// One of "Preview", "Live", or "Final".
+    #[serde(rename = "abstractGameState")]
+    pub abstract_game_state: String,
+    /// The human friendly version, E.G. "In Progress", "Postponed", or "Final".
+    #[serde(rename = "detailedState")]
+    pub detailed_state: String,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Teams {
+    pub away: TeamSide,
+    pub home: TeamSide,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct TeamSide {
+    // Absent until the game has actually started.
+    pub score: Option<u32>,
+    #[serde(rename = "leagueRecord")]
+    pub league_record: Option<LeagueRecord>,
+    pub team: Team,
+    /// Who is expected to start, for games that haven't. Only hydrated when asked for, with
+    /// "probablePitcher", and even then only once the club has said.
+    #[serde(rename = "probablePitcher")]
+    pub probable_pitcher: Option<Person>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct LeagueRecord {
+    pub wins: u32,
+    pub losses: u32,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Team {
+    pub id: u32,
+    pub name: String,
+    /// E.G. "BOS". Only hydrated when asked for, with "team".
+    pub abbreviation: Option<String>,
+}
+
+impl Team {
+    /// Where the logo for the team with the given id can be had, as a 128 pixel square PNG. It's
+    /// the same one that goes on their caps, on a transparent background. Every team id that the
+    /// API hands out has one, minor leaguers and all.
+    pub fn logo(id: u32) -> String {
+        format!("https://midfield.mlbstatic.com/v1/team/{}/spots/128", id)
+    }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
+pub struct Decisions {
+    pub winner: Option<Person>,
+    pub loser: Option<Person>,
+    /// Only there for games that had one, of course.
+    pub save: Option<Person>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Person {
+    #[serde(default)]
+    pub id: u64,
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Broadcast {
+    /// E.G. "NESN", "WEEI 850", or "MLB.TV".
+    pub name: String,
+    /// One of "TV", "AM", or "FM" (or, lately, something along the lines of "Streaming").
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Whose market it's for, "home" or "away". National broadcasts are listed once for each.
+    #[serde(rename = "homeAway")]
+    pub home_away: Option<String>,
+    #[serde(rename = "isNational", default)]
+    pub is_national: bool,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Venue {
+    pub id: u32,
+    /// E.G. "Fenway Park".
+    pub name: String,
+    /// Only hydrated when asked for, with "venue(location)".
+    pub location: Option<Location>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Location {
+    /// E.G. "Boston".
+    pub city: Option<String>,
+    /// E.G. "MA". Ballparks outside of the States (Toronto, say) have a province here instead.
+    #[serde(rename = "stateAbbrev")]
+    pub state_abbrev: Option<String>,
+}
+
+impl Venue {
+    /// Where the ballpark is, E.G. "Boston, MA", or None if the location wasn't hydrated.
+    pub fn city(&self) -> Option<String> {
+        let location = self.location.as_ref()?;
+        let city = location.city.clone()?;
+        Some(match &location.state_abbrev {
+            Some(state) => format!("{}, {}", city, state),
+            None => city,
+        })
+    }
+}
+
+// Just about everything from here on down is only there for games that have had something
+// written about them, which rules out most games that haven't been played yet (or never will
+// be, thanks to the rain). Missing any one piece of it is no reason to throw out the whole
+// schedule, so it is Options all the way down.
+#[derive(Clone, Deserialize)]
 pub struct Content {
-    pub editorial: Editorial,
+    pub editorial: Option<Editorial>,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct Editorial {
-    pub recap: Recap,
+    pub recap: Option<Recap>,
+    /// Only hydrated when asked for, E.G. "game(content(editorial(preview,recap)))".
+    pub preview: Option<Preview>,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct Recap {
-    pub home: Home,
+    pub home: Option<Article>,
+    pub away: Option<Article>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct Preview {
+    #[serde(default)]
+    pub items: Vec<Article>,
 }
 
-#[derive(Deserialize)]
-pub struct Home {
+#[derive(Clone, Deserialize)]
+pub struct Article {
+    #[serde(default)]
     pub headline: String,
+    #[serde(default)]
     pub subhead: String,
-    pub photo: Photos,
+    /// The same goes for the photo as for the content as a whole. The minor leagues' cuts
+    /// sometimes come as a list rather than by size.
+    #[serde(default, deserialize_with = "lenient")]
+    pub photo: Option<Photos>,
+    /// E.G. "reynaldo-lopez-stifles-red-sox-in-win".
+    pub slug: Option<String>,
+    /// Where the article lives on mlb.com, E.G. "/news/reynaldo-lopez-stifles-red-sox-in-win/c-280642806".
+    pub canonical: Option<String>,
 }
 
-#[derive(Deserialize)]
+impl Article {
+    // Where the paths that the API hands out are relative to.
+    const SITE: &'static str = "https://www.mlb.com";
+
+    /// The address of the whole story, for reading in a browser. The canonical path is the one
+    /// that the site itself links to, but the slug gets there too (by way of a redirect) for the
+    /// articles that don't have one.
+    pub fn url(&self) -> Option<String> {
+        match (&self.canonical, &self.slug) {
+            (Some(canonical), _) if canonical.starts_with("http") => Some(canonical.clone()),
+            (Some(canonical), _) if canonical.starts_with('/') => {
+                Some(format!("{}{}", Self::SITE, canonical))
+            }
+            (_, Some(slug)) if !slug.is_empty() => Some(format!("{}/news/{}", Self::SITE, slug)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
 pub struct Photos {
+    #[serde(default)]
     pub cuts: Cuts,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Default, Deserialize)]
 pub struct Cuts {
     #[serde(alias = "480x270")]
-    pub large: Photo,
+    pub large: Option<Photo>,
     #[serde(alias = "320x180")]
-    pub small: Photo,
+    pub small: Option<Photo>,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 pub struct Photo {
     pub width: u32,
     pub height: u32,
     pub src: String,
 }
 
+// Deserializes whatever is there if it can, and otherwise carries on as though there was
+// nothing there at all. The API's leaves are far less predictable than its trunk.
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).ok())
+}
+
 // I Decided to use this corner to show how one might create their own error types.
 // I've also used boilerplate reducers in the past, such as error_chain, which help. But those
 // are also more appropriate for top level application code that is trying to tie
 // a bunch of APIs together rather than a library itself.
-type APIResult<T> = Result<T, APIError>;
+pub type APIResult<T> = Result<T, APIError>;
 
 pub struct APIError {
     src: String,
@@ -126,6 +525,18 @@ pub struct APIError {
     original: String,
 }
 
+impl APIError {
+    /// What we were in the middle of when things went sideways.
+    pub fn context(&self) -> &ErrorContext {
+        &self.context
+    }
+
+    /// Whatever it was that we were trying to get at.
+    pub fn src(&self) -> &str {
+        &self.src
+    }
+}
+
 impl std::error::Error for APIError {}
 
 impl std::fmt::Display for APIError {
@@ -148,6 +559,10 @@ pub enum ErrorContext {
     ConnectionEstablishment,
     Downloading,
     Deserializing,
+    TimedOut,
+    Cancelled,
+    /// The endpoint answered, but with something other than a 2xx.
+    HttpStatus(u16),
 }
 
 impl std::fmt::Display for ErrorContext {
@@ -161,6 +576,12 @@ impl std::fmt::Display for ErrorContext {
             Self::Deserializing => {
                 f.write_str("Failed to deserialize data from the given API endpoint")
             }
+            Self::TimedOut => f.write_str("Timed out waiting on the given API endpoint"),
+            Self::Cancelled => f.write_str("Gave up on the given API endpoint when asked to"),
+            Self::HttpStatus(status) => f.write_fmt(format_args!(
+                "The given API endpoint responded with HTTP {}",
+                status
+            )),
         }
     }
 }
@@ -171,17 +592,245 @@ mod tests {
 
     static TEST_DATA: &[u8] = include_bytes!("test.json");
 
+    // Hands back the same body no matter what is asked of it, or a 404 if there isn't one.
+    struct Canned(Option<&'static [u8]>);
+
+    impl Transport for Canned {
+        fn get<'a>(&'a self, src: &'a str) -> Download<'a> {
+            Box::pin(async move {
+                self.0.map(|body| body.to_vec()).ok_or_else(|| APIError {
+                    src: src.to_string(),
+                    context: ErrorContext::HttpStatus(404),
+                    original: "Not Found".to_string(),
+                })
+            })
+        }
+    }
+
     #[test]
     fn smoke() {
         let _: Schedule = serde_json::from_slice(TEST_DATA).unwrap();
     }
 
+    #[test]
+    fn game_identity() {
+        let schedule: Schedule = serde_json::from_slice(TEST_DATA).unwrap();
+        let game = &schedule.dates[0].games[0];
+        assert_eq!(game.game_pk, 530376);
+        assert_eq!(game.status.abstract_game_state, "Final");
+        assert_eq!(game.teams.away.team.id, 145);
+        assert_eq!(game.teams.away.score, Some(5));
+        let record = game.teams.home.league_record.as_ref().unwrap();
+        assert_eq!((record.wins, record.losses), (44, 22));
+    }
+
+    #[test]
+    fn venue() {
+        let venue: Venue = serde_json::from_str(
+            r#"{
+                "id": 3,
+                "name": "Fenway Park",
+                "location": {"city": "Boston", "state": "Massachusetts", "stateAbbrev": "MA"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(venue.city().as_deref(), Some("Boston, MA"));
+        let venue: Venue = serde_json::from_str(r#"{"id": 3, "name": "Fenway Park"}"#).unwrap();
+        assert!(venue.city().is_none());
+    }
+
+    #[test]
+    fn missing_editorial() {
+        let schedule: Schedule = serde_json::from_str(
+            r#"{"copyright": "", "dates": [{"date": "2020-03-26", "games": [
+                {
+                    "gamePk": 1,
+                    "status": {"abstractGameState": "Preview", "detailedState": "Postponed"},
+                    "teams": {
+                        "away": {"team": {"id": 1, "name": "Away"}},
+                        "home": {"team": {"id": 2, "name": "Home"}}
+                    }
+                },
+                {
+                    "gamePk": 2,
+                    "status": {"abstractGameState": "Preview", "detailedState": "Scheduled"},
+                    "teams": {
+                        "away": {"team": {"id": 1, "name": "Away"}},
+                        "home": {"team": {"id": 2, "name": "Home"}}
+                    },
+                    "content": {"editorial": {
+                        "recap": {"mlb": {}},
+                        "preview": {"items": [{"headline": "Coming up"}]}
+                    }}
+                }
+            ]}]}"#,
+        )
+        .unwrap();
+        let games = &schedule.dates[0].games;
+        assert!(games[0].article().is_none());
+        let preview = games[1].article().unwrap();
+        assert_eq!(preview.headline, "Coming up");
+        assert!(preview.photo.is_none());
+        assert!(preview.url().is_none());
+    }
+
+    #[test]
+    fn minor_leagues() {
+        let schedule: Schedule = serde_json::from_str(
+            r#"{"dates": [{"date": "2019-08-02", "games": [
+                {
+                    "gamePk": 1,
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"team": {"id": 533, "name": "Pawtucket Red Sox"}},
+                        "home": {"team": {"id": 552, "name": "Syracuse Mets"}}
+                    },
+                    "content": {"editorial": {"recap": {"home": {"headline": ""}}}}
+                },
+                {
+                    "gamePk": 2,
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"team": {"id": 533, "name": "Pawtucket Red Sox"}},
+                        "home": {"team": {"id": 552, "name": "Syracuse Mets"}}
+                    },
+                    "content": {"editorial": {"recap": {"home": {
+                        "headline": "PawSox walk off",
+                        "photo": {"cuts": [{"aspectRatio": "16:9", "src": "x.jpg"}]}
+                    }}}}
+                },
+                {
+                    "gamePk": 3,
+                    "status": {"abstractGameState": "Preview", "detailedState": "Scheduled"},
+                    "teams": {
+                        "away": {"team": {"id": 533, "name": "Pawtucket Red Sox"}},
+                        "home": {"team": {"id": 552, "name": "Syracuse Mets"}}
+                    },
+                    "content": {"editorial": []}
+                }
+            ]}]}"#,
+        )
+        .unwrap();
+        let games = &schedule.dates[0].games;
+        assert!(games[0].article().is_none());
+        let recap = games[1].article().unwrap();
+        assert_eq!(recap.headline, "PawSox walk off");
+        assert!(recap.photo.is_none());
+        assert!(games[2].content.is_none());
+        assert!(schedule.copyright.is_empty());
+    }
+
+    #[test]
+    fn article_urls() {
+        let schedule: Schedule = serde_json::from_slice(TEST_DATA).unwrap();
+        let mut recap =
+            serde_json::from_str::<Article>(r#"{"headline": "", "slug": "lopez-stifles-red-sox"}"#)
+                .unwrap();
+        assert_eq!(
+            schedule.dates[0].games[0].article().unwrap().url().unwrap(),
+            "https://www.mlb.com/news/reynaldo-lopez-stifles-red-sox-in-win/c-280642806"
+        );
+        assert_eq!(
+            recap.url().unwrap(),
+            "https://www.mlb.com/news/lopez-stifles-red-sox"
+        );
+        recap.canonical = Some("https://www.mlb.com/whitesox/news/lopez".to_string());
+        assert_eq!(
+            recap.url().unwrap(),
+            "https://www.mlb.com/whitesox/news/lopez"
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        let schedule: Schedule = serde_json::from_slice(TEST_DATA).unwrap();
+        assert!(!schedule.is_empty());
+        let schedule: Schedule = serde_json::from_str(r#"{"copyright": "", "dates": []}"#).unwrap();
+        assert!(schedule.is_empty());
+    }
+
+    #[test]
+    fn http_status() {
+        let err = APIError {
+            src: "http://statsapi.mlb.com/api/v1/nope".to_string(),
+            context: ErrorContext::HttpStatus(404),
+            original: snippet(b"  Not Found\n"),
+        };
+        assert_eq!(
+            err.to_string(),
+            "The given API endpoint responded with HTTP 404. Error: Not Found. \
+             Source: http://statsapi.mlb.com/api/v1/nope"
+        );
+        let long = snippet("a".repeat(1000).as_bytes());
+        assert_eq!(long, format!("{}...", "a".repeat(200)));
+    }
+
+    #[test]
+    fn pluggable_transport() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let schedule = runtime
+            .block_on(Schedule::try_from_using(&Canned(Some(TEST_DATA)), "canned"))
+            .unwrap();
+        assert_eq!(schedule.dates[0].games[0].game_pk, 530376);
+        let err = runtime
+            .block_on(Schedule::try_from_using(&Canned(None), "canned"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.context.to_string(),
+            ErrorContext::HttpStatus(404).to_string()
+        );
+        let err = runtime
+            .block_on(Schedule::try_from_using(&Canned(Some(b"<html>")), "canned"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.context.to_string(),
+            ErrorContext::Deserializing.to_string()
+        );
+    }
+
+    #[test]
+    fn cancellation() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let (cancel, cancelled) = super::cancellation();
+        cancel.cancel();
+        // The address doesn't matter, the request is called off before it gets anywhere.
+        let err = runtime
+            .block_on(Schedule::try_from_within(
+                "http://10.255.255.1/schedule",
+                Duration::from_secs(60),
+                cancelled,
+            ))
+            .err()
+            .unwrap();
+        assert_eq!(err.context.to_string(), ErrorContext::Cancelled.to_string());
+        // Dropping the Cancel counts too.
+        let (cancel, mut cancelled) = super::cancellation();
+        drop(cancel);
+        runtime.block_on(cancelled.wait());
+        // Spawned tasks stop short once they're told to.
+        let (stop, stopped) = watch::channel(false);
+        stop.broadcast(true).unwrap();
+        let stopped = runtime.block_on(async {
+            let forever = tokio::time::delay_for(Duration::from_secs(60 * 60));
+            tokio::time::timeout(Duration::from_secs(5), until(forever, stopped)).await
+        });
+        assert!(stopped.is_ok());
+    }
+
     #[test]
     fn smoke_async_real_download() {
         // This just smoke checks that our api call is working.
         let _: Schedule = tokio::runtime::Runtime::new()
             .unwrap()
-            .block_on(Schedule::try_from(DEFAULT))
+            .block_on(Schedule::try_from(
+                ScheduleRequest::new()
+                    .date(chrono::NaiveDate::from_ymd_opt(2018, 6, 10).unwrap())
+                    .hydrate("game(content(editorial(recap)))")
+                    .hydrate("decisions")
+                    .url(),
+            ))
             .unwrap();
     }
 }
@@ -0,0 +1,296 @@
+use super::{default_transport, endpoint, fetch_once, get_json, spawn, APIResult, DEFAULT_TIMEOUT};
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::{info_span, warn, Instrument};
+
+static PEOPLE_ENDPOINT: &str = "http://statsapi.mlb.com/api/v1/people";
+
+/// Where the headshot of the player with the given id can be had, 213 pixels across by 320 down.
+/// Anybody that doesn't have one gets a generic silhouette from the same place instead, so this
+/// is always good for a picture of some sort.
+///
+/// These are only the URLs. The pictures themselves come down the same way as every other
+/// photo, through the lineup's download queue and photo cache.
+pub fn headshot(id: u64) -> String {
+    format!(
+        "https://img.mlbstatic.com/mlb-photos/image/upload/\
+         d_people:generic:headshot:67:current.png/w_213,q_auto:best/v1/people/{}/headshot/67/current",
+        id
+    )
+}
+
+/// A handful of players, each with how their season on the mound has gone. This is all that
+/// the schedule's probable pitchers are missing, since it only ever names them (that and their
+/// faces, which is what headshot is for).
+#[derive(Deserialize)]
+pub struct People {
+    #[serde(default)]
+    pub people: Vec<Player>,
+}
+
+/// A player, and however much of their biography and stats were asked for. Everything past
+/// their id is left out by the API whenever it doesn't apply (nobody has debuted before their
+/// debut), so it's all optional.
+#[derive(Deserialize)]
+pub struct Player {
+    pub id: u64,
+    #[serde(rename = "fullName")]
+    pub full_name: Option<String>,
+    /// E.G. "22". It comes as a string, like every other number that isn't for counting.
+    #[serde(rename = "primaryNumber")]
+    pub primary_number: Option<String>,
+    #[serde(rename = "primaryPosition")]
+    pub primary_position: Option<Position>,
+    #[serde(rename = "batSide")]
+    pub bat_side: Option<Code>,
+    #[serde(rename = "pitchHand")]
+    pub pitch_hand: Option<Code>,
+    /// E.G. "6' 5\"".
+    pub height: Option<String>,
+    /// In pounds.
+    pub weight: Option<u32>,
+    #[serde(rename = "currentAge")]
+    pub current_age: Option<u32>,
+    /// E.G. "1988-12-27".
+    #[serde(rename = "birthDate")]
+    pub birth_date: Option<String>,
+    #[serde(rename = "birthCity")]
+    pub birth_city: Option<String>,
+    /// E.G. "NJ". Only for places that have states or provinces.
+    #[serde(rename = "birthStateProvince")]
+    pub birth_state_province: Option<String>,
+    #[serde(rename = "birthCountry")]
+    pub birth_country: Option<String>,
+    /// E.G. "2009-04-09".
+    #[serde(rename = "mlbDebutDate")]
+    pub mlb_debut_date: Option<String>,
+    /// Only hydrated when asked for, and even then left out for anybody who hasn't played
+    /// yet this season.
+    #[serde(default)]
+    pub stats: Vec<Stats>,
+}
+
+#[derive(Deserialize)]
+pub struct Position {
+    /// E.G. "P", "SS", or "TWP" for the rare two way player.
+    pub abbreviation: String,
+}
+
+#[derive(Deserialize)]
+pub struct Code {
+    /// "L", "R", or "S" for switch hitters.
+    pub code: String,
+}
+
+#[derive(Deserialize)]
+pub struct Stats {
+    /// E.G. "season" or "career".
+    #[serde(rename = "type")]
+    pub kind: Option<Label>,
+    /// E.G. "hitting" or "pitching".
+    pub group: Option<Label>,
+    #[serde(default)]
+    pub splits: Vec<Split>,
+}
+
+#[derive(Deserialize)]
+pub struct Label {
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+}
+
+#[derive(Deserialize)]
+pub struct Split {
+    /// E.G. "2019". Career splits don't have one.
+    pub season: Option<String>,
+    pub stat: Stat,
+}
+
+/// The numbers that go on a stat line. Hitters and pitchers have different ones, so whichever
+/// don't apply are left out. The rate stats (E.G. ".311" or "4.48") come as strings, already
+/// rounded, and as "-.--" (or ".---") for anybody who hasn't had any chances yet.
+#[derive(Deserialize)]
+pub struct Stat {
+    #[serde(rename = "gamesPlayed")]
+    pub games_played: Option<u32>,
+    pub avg: Option<String>,
+    #[serde(rename = "homeRuns")]
+    pub home_runs: Option<u32>,
+    pub rbi: Option<u32>,
+    pub ops: Option<String>,
+    pub wins: Option<u32>,
+    pub losses: Option<u32>,
+    pub saves: Option<u32>,
+    pub era: Option<String>,
+    #[serde(rename = "inningsPitched")]
+    pub innings_pitched: Option<String>,
+    #[serde(rename = "strikeOuts")]
+    pub strike_outs: Option<u32>,
+    pub whip: Option<String>,
+}
+
+impl People {
+    /// Where to find the given players' pitching for the given season, E.G. "2019".
+    pub fn url(ids: &[u64], season: &str) -> String {
+        let mut url = endpoint(PEOPLE_ENDPOINT);
+        let ids: Vec<String> = ids.iter().map(u64::to_string).collect();
+        url.query_pairs_mut()
+            .append_pair("personIds", &ids.join(","))
+            .append_pair(
+                "hydrate",
+                &format!("stats(group=[pitching],type=[season],season={})", season),
+            );
+        url.to_string()
+    }
+
+    /// Where to find everything about the player with the given id: their biography, along
+    /// with their hitting and pitching both for this season and for their whole career.
+    pub fn player_url(id: u64) -> String {
+        format!(
+            "{}/{}?hydrate=stats(group=[hitting,pitching],type=[season,career])",
+            PEOPLE_ENDPOINT, id
+        )
+    }
+
+    pub async fn try_from(ids: &[u64], season: &str) -> APIResult<People> {
+        get_json(
+            &*default_transport(),
+            &Self::url(ids, season),
+            DEFAULT_TIMEOUT,
+        )
+        .await
+    }
+
+    /// Everybody's ERA for the season, by their id. Anybody who hasn't pitched yet is left out.
+    pub fn eras(self) -> HashMap<u64, String> {
+        self.people
+            .into_iter()
+            .filter_map(|player| {
+                let era = player
+                    .stats
+                    .into_iter()
+                    .flat_map(|stats| stats.splits)
+                    .find_map(|split| split.stat.era)?;
+                Some((player.id, era))
+            })
+            .collect()
+    }
+}
+
+/// Looks up the given players' ERAs for the given season once, handing them back over the
+/// returned channel. See fetch_once for what becomes of a failure.
+pub fn fetch(ids: Vec<u64>, season: String) -> crossbeam_channel::Receiver<HashMap<u64, String>> {
+    let lookup = async move { People::try_from(&ids, &season).await.map(People::eras) };
+    fetch_once(lookup, info_span!("people"), "the probable pitchers")
+}
+
+/// Looks up everything about the player with the given id once, handing it back over the
+/// returned channel. The same goes for failures here as for fetch.
+pub fn player(id: u64) -> crossbeam_channel::Receiver<Player> {
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let fetching = async move {
+        let player = get_json::<People>(
+            &*default_transport(),
+            &People::player_url(id),
+            DEFAULT_TIMEOUT,
+        )
+        .await;
+        match player.map(|people| people.people.into_iter().next()) {
+            Ok(Some(player)) => drop(tx.send(player)),
+            Ok(None) => warn!("There's nobody by that id"),
+            Err(err) => warn!(error = %err, "Failed to look up the player"),
+        }
+    };
+    spawn(fetching.instrument(info_span!("player", id)));
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url() {
+        assert_eq!(
+            People::url(&[625643, 519144], "2019"),
+            "http://statsapi.mlb.com/api/v1/people?personIds=625643%2C519144\
+             &hydrate=stats%28group%3D%5Bpitching%5D%2Ctype%3D%5Bseason%5D%2Cseason%3D2019%29"
+        );
+    }
+
+    #[test]
+    fn headshot() {
+        let src = super::headshot(625643);
+        assert!(src.starts_with("https://img.mlbstatic.com/"));
+        assert!(src.contains("/people/625643/headshot/"));
+        // With a stand-in for anybody who hasn't had their picture taken yet.
+        assert!(src.contains("d_people:generic:headshot"));
+    }
+
+    #[test]
+    fn eras() {
+        let people: People = serde_json::from_str(
+            r#"{
+                "copyright": "",
+                "people": [
+                    {
+                        "id": 625643,
+                        "fullName": "Reynaldo Lopez",
+                        "stats": [{
+                            "type": {"displayName": "season"},
+                            "group": {"displayName": "pitching"},
+                            "splits": [{"season": "2019", "stat": {"wins": 9, "era": "5.38"}}]
+                        }]
+                    },
+                    {"id": 519144, "fullName": "Rick Porcello"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let eras = people.eras();
+        assert_eq!(eras.len(), 1);
+        assert_eq!(eras[&625643], "5.38");
+    }
+
+    #[test]
+    fn player() {
+        assert_eq!(
+            People::player_url(519144),
+            "http://statsapi.mlb.com/api/v1/people/519144\
+             ?hydrate=stats(group=[hitting,pitching],type=[season,career])"
+        );
+        let people: People = serde_json::from_str(
+            r#"{"people": [{
+                "id": 519144,
+                "fullName": "Rick Porcello",
+                "primaryNumber": "22",
+                "birthDate": "1988-12-27",
+                "currentAge": 30,
+                "birthCity": "Morristown",
+                "birthStateProvince": "NJ",
+                "birthCountry": "USA",
+                "height": "6' 5\"",
+                "weight": 205,
+                "primaryPosition": {"code": "1", "abbreviation": "P"},
+                "batSide": {"code": "R", "description": "Right"},
+                "pitchHand": {"code": "R", "description": "Right"},
+                "stats": [{
+                    "type": {"displayName": "career"},
+                    "group": {"displayName": "pitching"},
+                    "splits": [{"stat": {"wins": 149, "losses": 113, "era": "4.40"}}]
+                }]
+            }]}"#,
+        )
+        .unwrap();
+        let player = &people.people[0];
+        assert_eq!(player.full_name.as_deref(), Some("Rick Porcello"));
+        assert_eq!(player.height.as_deref(), Some("6' 5\""));
+        assert_eq!(player.pitch_hand.as_ref().unwrap().code, "R");
+        // Anything that wasn't in the response is simply missing.
+        assert!(player.mlb_debut_date.is_none());
+        let career = &player.stats[0];
+        assert_eq!(career.kind.as_ref().unwrap().display_name, "career");
+        assert!(career.splits[0].season.is_none());
+        assert_eq!(career.splits[0].stat.wins, Some(149));
+    }
+}
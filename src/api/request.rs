@@ -0,0 +1,212 @@
+use chrono::NaiveDate;
+
+static SCHEDULE_ENDPOINT: &str = "http://statsapi.mlb.com/api/v1/schedule";
+
+/// A typed query against the statsapi schedule endpoint.
+///
+/// Every method takes and returns self by value so that a request reads as a single chain:
+///
+/// ```
+/// # use chrono::NaiveDate;
+/// # use mlb::ScheduleRequest;
+/// let url = ScheduleRequest::new()
+///     .date(NaiveDate::from_ymd_opt(2018, 6, 10).unwrap())
+///     .hydrate("decisions")
+///     .url();
+/// ```
+///
+/// Out of the box the request is for MLB proper (sportId 1), with nothing hydrated, on
+/// whatever the API considers to be today.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleRequest {
+    sport_id: u32,
+    dates: Dates,
+    team_id: Option<u32>,
+    language: Option<String>,
+    hydrate: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Dates {
+    Today,
+    Single(NaiveDate),
+    Range(NaiveDate, NaiveDate),
+}
+
+impl ScheduleRequest {
+    pub fn new() -> ScheduleRequest {
+        ScheduleRequest {
+            sport_id: 1,
+            dates: Dates::Today,
+            team_id: None,
+            language: None,
+            hydrate: vec![],
+        }
+    }
+
+    /// The one query that the lineup knows how to make sense of, for the given number of days
+    /// starting at date. The editorial content is where all of the headlines and photos live.
+    /// Games that haven't been played yet don't have a recap, but they might well have a
+    /// preview.
+    ///
+    /// More than one day at a time is asked for as a range, which comes back with a date (and
+    /// so a row) for each day that has something on it.
+    pub fn lineup(date: NaiveDate, days: u32, sport_id: u32) -> ScheduleRequest {
+        let request = match days {
+            1 => ScheduleRequest::new().date(date),
+            _ => ScheduleRequest::new()
+                .date_range(date, date + chrono::Duration::days(days as i64 - 1)),
+        };
+        request
+            .sport_id(sport_id)
+            .hydrate("game(content(editorial(preview,recap)))")
+            .hydrate("decisions")
+            .hydrate("probablePitcher")
+            .hydrate("team")
+            .hydrate("broadcasts(all)")
+            .hydrate("venue(location)")
+    }
+
+    /// Asks for the games of a single day. Replaces any previously given date range.
+    pub fn date(mut self, date: NaiveDate) -> ScheduleRequest {
+        self.dates = Dates::Single(date);
+        self
+    }
+
+    /// Asks for the games from start through end, inclusively. Replaces any previously
+    /// given single date.
+    pub fn date_range(mut self, start: NaiveDate, end: NaiveDate) -> ScheduleRequest {
+        self.dates = Dates::Range(start, end);
+        self
+    }
+
+    pub fn sport_id(mut self, sport_id: u32) -> ScheduleRequest {
+        self.sport_id = sport_id;
+        self
+    }
+
+    /// Restricts the schedule to games that the given team is playing in.
+    pub fn team_id(mut self, team_id: u32) -> ScheduleRequest {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    /// Asks for the editorial content (headlines, subheads, and so on) in the given language,
+    /// E.G. "es", rather than in English.
+    pub fn language<T: Into<String>>(mut self, language: T) -> ScheduleRequest {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Adds a hydration, E.G. "decisions" or "game(content(editorial(recap)))". Hydrations
+    /// accumulate, so call this once for each one that you want.
+    pub fn hydrate<T: Into<String>>(mut self, hydration: T) -> ScheduleRequest {
+        self.hydrate.push(hydration.into());
+        self
+    }
+
+    pub fn url(&self) -> String {
+        let mut url = super::endpoint(SCHEDULE_ENDPOINT);
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("sportId", &self.sport_id.to_string());
+            match self.dates {
+                Dates::Today => (),
+                Dates::Single(date) => {
+                    query.append_pair("date", &date.format("%Y-%m-%d").to_string());
+                }
+                Dates::Range(start, end) => {
+                    query.append_pair("startDate", &start.format("%Y-%m-%d").to_string());
+                    query.append_pair("endDate", &end.format("%Y-%m-%d").to_string());
+                }
+            }
+            if let Some(team_id) = self.team_id {
+                query.append_pair("teamId", &team_id.to_string());
+            }
+            if let Some(language) = &self.language {
+                query.append_pair("language", language);
+            }
+            if !self.hydrate.is_empty() {
+                query.append_pair("hydrate", &self.hydrate.join(","));
+            }
+        }
+        url.to_string()
+    }
+}
+
+impl Default for ScheduleRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare() {
+        assert_eq!(
+            ScheduleRequest::new().url(),
+            "http://statsapi.mlb.com/api/v1/schedule?sportId=1"
+        );
+    }
+
+    #[test]
+    fn everything() {
+        let url = ScheduleRequest::new()
+            .date(NaiveDate::from_ymd_opt(2018, 6, 10).unwrap())
+            .sport_id(11)
+            .team_id(147)
+            .language("es")
+            .hydrate("game(content(editorial(recap)))")
+            .hydrate("decisions")
+            .url();
+        let parsed = url::Url::parse(&url).unwrap();
+        let pairs: Vec<(String, String)> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("sportId".to_string(), "11".to_string()),
+                ("date".to_string(), "2018-06-10".to_string()),
+                ("teamId".to_string(), "147".to_string()),
+                ("language".to_string(), "es".to_string()),
+                (
+                    "hydrate".to_string(),
+                    "game(content(editorial(recap))),decisions".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn range_replaces_date() {
+        let url = ScheduleRequest::new()
+            .date(NaiveDate::from_ymd_opt(2018, 6, 10).unwrap())
+            .date_range(
+                NaiveDate::from_ymd_opt(2018, 6, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2018, 6, 7).unwrap(),
+            )
+            .url();
+        assert_eq!(
+            url,
+            "http://statsapi.mlb.com/api/v1/schedule?sportId=1&startDate=2018-06-01&endDate=2018-06-07"
+        );
+    }
+
+    #[test]
+    fn lineup_spans_the_days() {
+        let date = NaiveDate::from_ymd_opt(2018, 6, 10).unwrap();
+        assert_eq!(
+            ScheduleRequest::lineup(date, 1, 11).dates,
+            Dates::Single(date)
+        );
+        let lineup = ScheduleRequest::lineup(date, 3, 11);
+        assert_eq!(
+            lineup.dates,
+            Dates::Range(date, NaiveDate::from_ymd_opt(2018, 6, 12).unwrap())
+        );
+        assert_eq!(lineup.sport_id, 11);
+        assert!(lineup.hydrate.contains(&"decisions".to_string()));
+    }
+}
@@ -0,0 +1,62 @@
+use super::{spawn, Schedule, ScheduleRequest};
+use chrono::NaiveDate;
+use std::time::Duration;
+use tracing::{info_span, warn, Instrument};
+
+/// Where every game on the given day stands, as a schedule with only the clubs and the
+/// linescores hydrated. That's a fraction of what the carousel asks for, which makes it cheap
+/// enough to ask for over and over while games are being played.
+pub fn url(date: NaiveDate, sport_id: u32) -> String {
+    ScheduleRequest::new()
+        .date(date)
+        .sport_id(sport_id)
+        .hydrate("team")
+        .hydrate("linescore")
+        .url()
+}
+
+/// Polls the scoreboard for the given day every interval, handing back each new one over the
+/// returned channel.
+///
+/// As with live::poll, this carries on until the receiver is dropped, and a failed poll is
+/// logged and then tried again on the next tick.
+pub fn poll(
+    date: NaiveDate,
+    sport_id: u32,
+    interval: Duration,
+) -> crossbeam_channel::Receiver<Schedule> {
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let url = url(date, sport_id);
+    let polling = async move {
+        let mut ticks = tokio::time::interval(interval);
+        loop {
+            ticks.tick().await;
+            let schedule = match Schedule::try_from(&url).await {
+                Ok(schedule) => schedule,
+                Err(err) => {
+                    warn!(error = %err, "Failed to poll the scoreboard");
+                    continue;
+                }
+            };
+            // A newer scoreboard is just as good as one that hasn't been picked up yet.
+            if let Err(crossbeam_channel::TrySendError::Disconnected(_)) = tx.try_send(schedule) {
+                return;
+            }
+        }
+    };
+    spawn(polling.instrument(info_span!("scoreboard", %date)));
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url() {
+        assert_eq!(
+            super::url(NaiveDate::from_ymd_opt(2019, 9, 24).unwrap(), 1),
+            "http://statsapi.mlb.com/api/v1/schedule?sportId=1&date=2019-09-24&hydrate=team%2Clinescore"
+        );
+    }
+}
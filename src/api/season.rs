@@ -0,0 +1,89 @@
+use chrono::{Datelike, NaiveDate};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// Which past season to go back to for "on this day", which is the same day of the year as
+/// some other one but in that season instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Season {
+    /// The given year, E.G. 2004.
+    Year(i32),
+    /// Any year from FIRST up until the one being gone back from, picked anew every time.
+    Random,
+}
+
+impl Season {
+    /// The first season of the modern era, when the American League joined the National, which
+    /// is as far back as a random year will go.
+    pub const FIRST: i32 = 1901;
+
+    /// Reads a season off of the command line, which is either a year or "random".
+    pub fn parse(season: &str) -> Result<Season, String> {
+        if season.eq_ignore_ascii_case("random") {
+            return Ok(Season::Random);
+        }
+        match season.parse::<i32>() {
+            Ok(year) if year >= Self::FIRST => Ok(Season::Year(year)),
+            Ok(year) => Err(format!(
+                "{} is before the {} season, which is as far back as it goes",
+                year,
+                Self::FIRST
+            )),
+            Err(err) => Err(format!(
+                "{} is neither a year nor \"random\" ({})",
+                season, err
+            )),
+        }
+    }
+
+    /// The same day as the given one, in this season. Leap days fall back to the 28th in
+    /// seasons that don't have one.
+    pub fn same_day(self, date: NaiveDate) -> NaiveDate {
+        let year = match self {
+            Season::Year(year) => year,
+            Season::Random => random_year(date.year()),
+        };
+        NaiveDate::from_ymd_opt(year, date.month(), date.day())
+            .or_else(|| NaiveDate::from_ymd_opt(year, date.month(), date.day() - 1))
+            .unwrap()
+    }
+}
+
+// Any year from the first season up until (but not including) the given one.
+fn random_year(before: i32) -> i32 {
+    let seasons = (before - Season::FIRST).max(1) as u64;
+    // The same trick as download::backoff, for the same reason.
+    let roll = RandomState::new().build_hasher().finish() % seasons;
+    Season::FIRST + roll as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Season::parse("2004"), Ok(Season::Year(2004)));
+        assert_eq!(Season::parse("Random"), Ok(Season::Random));
+        assert!(Season::parse("1876").is_err());
+        assert!(Season::parse("last year").is_err());
+    }
+
+    #[test]
+    fn same_day() {
+        let today = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+        assert_eq!(
+            Season::Year(2004).same_day(today),
+            NaiveDate::from_ymd_opt(2004, 2, 29).unwrap()
+        );
+        assert_eq!(
+            Season::Year(2019).same_day(today),
+            NaiveDate::from_ymd_opt(2019, 2, 28).unwrap()
+        );
+        for _ in 0..100 {
+            let past = Season::Random.same_day(NaiveDate::from_ymd_opt(2019, 10, 3).unwrap());
+            assert!(past.year() >= Season::FIRST && past.year() < 2019);
+            assert_eq!((past.month(), past.day()), (10, 3));
+        }
+    }
+}
@@ -0,0 +1,86 @@
+/// One of the levels of baseball that the API has schedules for, by the sportId that it goes
+/// by. These are the ones that can be flipped between at runtime. Any other sportId still works
+/// with --sport-id, it just doesn't have a name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sport {
+    pub id: u32,
+    /// What it goes by on the command line, and (with "sport-" out in front) in the locales,
+    /// E.G. "aaa".
+    pub name: &'static str,
+}
+
+impl Sport {
+    /// MLB proper and then the minor leagues, from the top on down.
+    pub const ALL: [Sport; 6] = [
+        Sport { id: 1, name: "mlb" },
+        Sport {
+            id: 11,
+            name: "aaa",
+        },
+        Sport { id: 12, name: "aa" },
+        Sport {
+            id: 13,
+            name: "high-a",
+        },
+        Sport {
+            id: 14,
+            name: "single-a",
+        },
+        Sport {
+            id: 16,
+            name: "rookie",
+        },
+    ];
+
+    /// The level with the given sportId, if it's one of ours.
+    pub fn by_id(id: u32) -> Option<Sport> {
+        Self::ALL.iter().copied().find(|sport| sport.id == id)
+    }
+
+    /// The level after the one with the given sportId, going down through the minors and then
+    /// back around to MLB. Anything that isn't one of ours goes back to MLB as well.
+    pub fn after(id: u32) -> Sport {
+        let next = Self::ALL
+            .iter()
+            .position(|sport| sport.id == id)
+            .map_or(0, |index| (index + 1) % Self::ALL.len());
+        Self::ALL[next]
+    }
+
+    /// Reads a sportId off of the command line, which is either a level's name (in any case)
+    /// or the number itself, E.G. "AAA" or "11".
+    pub fn parse(id: &str) -> Result<u32, String> {
+        let named = Self::ALL
+            .iter()
+            .find(|sport| sport.name.eq_ignore_ascii_case(id));
+        match named {
+            Some(sport) => Ok(sport.id),
+            None => id
+                .parse::<u32>()
+                .map_err(|err| format!("{} is not a valid sport ID ({})", id, err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Sport::parse("1"), Ok(1));
+        assert_eq!(Sport::parse("AAA"), Ok(11));
+        assert_eq!(Sport::parse("high-a"), Ok(13));
+        assert_eq!(Sport::parse("17"), Ok(17));
+        assert!(Sport::parse("the beer league").is_err());
+    }
+
+    #[test]
+    fn after() {
+        assert_eq!(Sport::after(1).name, "aaa");
+        assert_eq!(Sport::after(16).name, "mlb");
+        assert_eq!(Sport::after(17).name, "mlb");
+        assert_eq!(Sport::by_id(12).map(|sport| sport.name), Some("aa"));
+        assert_eq!(Sport::by_id(17), None);
+    }
+}
@@ -0,0 +1,267 @@
+use super::{
+    default_transport, fetch_once, get_json, APIResult, Person, Schedule, ScheduleRequest,
+    DEFAULT_TIMEOUT,
+};
+use chrono::{Duration, NaiveDate};
+use serde::Deserialize;
+use tracing::info_span;
+
+static TEAMS_ENDPOINT: &str = "http://statsapi.mlb.com/api/v1/teams";
+
+/// Everything that goes on a team's page: who they are, their games from a month on either side
+/// of a date, and who is on their active roster. It takes three calls to put together.
+pub struct Club {
+    pub team: Team,
+    pub schedule: Schedule,
+    pub roster: Vec<Member>,
+}
+
+/// What comes back from the teams endpoint, which is a list even when asking after one team.
+#[derive(Deserialize)]
+pub struct Teams {
+    #[serde(default)]
+    pub teams: Vec<Team>,
+}
+
+#[derive(Deserialize)]
+pub struct Team {
+    pub id: u32,
+    /// E.G. "Boston Red Sox"
+    pub name: String,
+    /// E.G. "BOS"
+    pub abbreviation: Option<String>,
+    /// Where they play their home games.
+    pub venue: Option<Named>,
+}
+
+#[derive(Deserialize)]
+pub struct Named {
+    /// E.G. "Fenway Park"
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct Roster {
+    #[serde(default)]
+    pub roster: Vec<Member>,
+}
+
+/// Which of a team's rosters to ask for. They all come back in the same shape, just with more
+/// (or differently sorted) people in them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RosterType {
+    /// The (usually) 26 players who can actually get into a game today, and nobody on the
+    /// injured list or down in the minors.
+    Active,
+    /// Everybody under a major league contract, which is the active roster plus whoever is on
+    /// the short injured lists or has been optioned down.
+    FortyMan,
+    /// The active roster by position, from the starter on down. A player can show up more than
+    /// once, at each position that they're the depth at.
+    DepthChart,
+}
+
+impl RosterType {
+    /// What the API calls this roster, E.G. "40Man".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RosterType::Active => "active",
+            RosterType::FortyMan => "40Man",
+            RosterType::DepthChart => "depthChart",
+        }
+    }
+}
+
+/// One player on a roster.
+#[derive(Deserialize)]
+pub struct Member {
+    pub person: Person,
+    /// E.G. "50". It comes as a string, and not everybody has been handed one yet.
+    #[serde(rename = "jerseyNumber")]
+    pub jersey_number: Option<String>,
+    /// Where they play or, on a depth chart, the position that this spot on it is for.
+    pub position: Position,
+    /// Whether they're available, E.G. "Active" or "Injured 10-Day". Only the rosters with
+    /// people on them who aren't say so.
+    pub status: Option<Status>,
+}
+
+#[derive(Deserialize)]
+pub struct Position {
+    /// E.G. "P", "SS", or "DH". Depth charts split the pitchers up into "SP", "RP", and "CL".
+    pub abbreviation: String,
+}
+
+#[derive(Deserialize)]
+pub struct Status {
+    /// E.G. "A" or "D10".
+    pub code: String,
+    pub description: String,
+}
+
+impl Teams {
+    /// Where to find the team with the given id.
+    pub fn url(id: u32) -> String {
+        format!("{}/{}", TEAMS_ENDPOINT, id)
+    }
+}
+
+impl Roster {
+    /// Where to find the given roster of the team with the given id.
+    pub fn url(id: u32, kind: RosterType) -> String {
+        format!(
+            "{}/{}/roster?rosterType={}",
+            TEAMS_ENDPOINT,
+            id,
+            kind.as_str()
+        )
+    }
+
+    pub async fn try_from(id: u32, kind: RosterType) -> APIResult<Roster> {
+        get_json(&*default_transport(), &Self::url(id, kind), DEFAULT_TIMEOUT).await
+    }
+
+    /// Everybody on the roster grouped up by their position, in the order that the positions
+    /// first come up. On a depth chart, that puts each position's starter at the front of it.
+    pub fn by_position(&self) -> Vec<(&str, Vec<&Member>)> {
+        let mut positions: Vec<(&str, Vec<&Member>)> = vec![];
+        for member in &self.roster {
+            let position = member.position.abbreviation.as_str();
+            match positions.iter_mut().find(|(seen, _)| *seen == position) {
+                Some((_, members)) => members.push(member),
+                None => positions.push((position, vec![member])),
+            }
+        }
+        positions
+    }
+}
+
+impl Club {
+    // How far on either side of the date to look for games. A month is plenty to turn up the
+    // last five and the next five, short of the offseason.
+    const DAYS: i64 = 30;
+
+    /// Where to find the games that the team with the given id plays in around the given date.
+    pub fn schedule_url(id: u32, date: NaiveDate, sport_id: u32) -> String {
+        let span = Duration::days(Self::DAYS);
+        ScheduleRequest::new()
+            .sport_id(sport_id)
+            .team_id(id)
+            .date_range(date - span, date + span)
+            .hydrate("team")
+            .url()
+    }
+
+    /// Puts together the page of the team with the given id, as of the given date. All three
+    /// calls go out at once, and if any of them fails then so does the whole thing.
+    pub async fn try_from(id: u32, date: NaiveDate, sport_id: u32) -> APIResult<Club> {
+        let transport = default_transport();
+        let urls = (
+            Teams::url(id),
+            Self::schedule_url(id, date, sport_id),
+            Roster::url(id, RosterType::Active),
+        );
+        let (teams, schedule, roster) = tokio::try_join!(
+            get_json::<Teams>(&*transport, &urls.0, DEFAULT_TIMEOUT),
+            get_json::<Schedule>(&*transport, &urls.1, DEFAULT_TIMEOUT),
+            get_json::<Roster>(&*transport, &urls.2, DEFAULT_TIMEOUT),
+        )?;
+        // An id that the API has never heard of comes back as an empty list rather than an
+        // error, so we make do with what the schedule knows about them.
+        let team = teams.teams.into_iter().next().unwrap_or_else(|| Team {
+            id,
+            name: id.to_string(),
+            abbreviation: None,
+            venue: None,
+        });
+        Ok(Club {
+            team,
+            schedule,
+            roster: roster.roster,
+        })
+    }
+}
+
+/// Puts together the page of the team with the given id once, handing it back over the returned
+/// channel. See fetch_once for what becomes of a failure.
+pub fn fetch(id: u32, date: NaiveDate, sport_id: u32) -> crossbeam_channel::Receiver<Club> {
+    let lookup = Club::try_from(id, date, sport_id);
+    fetch_once(lookup, info_span!("team", id), "the team")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn urls() {
+        assert_eq!(Teams::url(111), "http://statsapi.mlb.com/api/v1/teams/111");
+        assert_eq!(
+            Roster::url(111, RosterType::Active),
+            "http://statsapi.mlb.com/api/v1/teams/111/roster?rosterType=active"
+        );
+        assert!(Roster::url(111, RosterType::FortyMan).ends_with("?rosterType=40Man"));
+        assert!(Roster::url(111, RosterType::DepthChart).ends_with("?rosterType=depthChart"));
+        let schedule = Club::schedule_url(111, NaiveDate::from_ymd_opt(2019, 9, 23).unwrap(), 1);
+        assert!(schedule.contains("teamId=111"));
+        assert!(schedule.contains("startDate=2019-08-24"));
+        assert!(schedule.contains("endDate=2019-10-23"));
+    }
+
+    #[test]
+    fn roster() {
+        let roster: Roster = serde_json::from_str(
+            r#"{
+                "copyright": "",
+                "roster": [
+                    {
+                        "person": {"id": 519144, "fullName": "Rick Porcello"},
+                        "jerseyNumber": "22",
+                        "position": {"code": "1", "abbreviation": "P", "type": "Pitcher"}
+                    },
+                    {
+                        "person": {"id": 646240, "fullName": "Rafael Devers"},
+                        "position": {"code": "5", "abbreviation": "3B", "type": "Infielder"}
+                    }
+                ],
+                "teamId": 111,
+                "rosterType": "active"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(roster.roster.len(), 2);
+        assert_eq!(roster.roster[0].person.full_name, "Rick Porcello");
+        assert_eq!(roster.roster[0].jersey_number.as_deref(), Some("22"));
+        assert_eq!(roster.roster[1].position.abbreviation, "3B");
+        assert!(roster.roster[1].jersey_number.is_none());
+    }
+
+    #[test]
+    fn depth_chart() {
+        let roster: Roster = serde_json::from_str(
+            r#"{"roster": [
+                {"person": {"id": 1, "fullName": "Chris Sale"}, "position": {"abbreviation": "SP"}},
+                {"person": {"id": 2, "fullName": "Christian Vazquez"},
+                 "position": {"abbreviation": "C"}},
+                {"person": {"id": 3, "fullName": "Rick Porcello"}, "position": {"abbreviation": "SP"}},
+                {
+                    "person": {"id": 4, "fullName": "Nathan Eovaldi"},
+                    "position": {"abbreviation": "SP"},
+                    "status": {"code": "D10", "description": "Injured 10-Day"}
+                }
+            ]}"#,
+        )
+        .unwrap();
+        let positions = roster.by_position();
+        assert_eq!(positions.len(), 2);
+        let (position, starters) = &positions[0];
+        assert_eq!(*position, "SP");
+        let names: Vec<&str> = starters
+            .iter()
+            .map(|member| member.person.full_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Chris Sale", "Rick Porcello", "Nathan Eovaldi"]);
+        assert_eq!(starters[2].status.as_ref().unwrap().code, "D10");
+        assert_eq!(positions[1].0, "C");
+    }
+}
@@ -0,0 +1,125 @@
+use super::{
+    default_transport, endpoint, fetch_once, get_json, APIResult, Person, DEFAULT_TIMEOUT,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use tracing::info_span;
+
+static TRANSACTIONS_ENDPOINT: &str = "http://statsapi.mlb.com/api/v1/transactions";
+
+/// Every trade, signing, call up, and trip to the injured list over a span of days, either for
+/// one team or for a whole sport.
+#[derive(Deserialize)]
+pub struct Transactions {
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Deserialize)]
+pub struct Transaction {
+    /// When it happened, E.G. "2019-09-20".
+    pub date: Option<String>,
+    /// E.G. "Trade", "Signed as Free Agent", or "Status Change".
+    #[serde(rename = "typeDesc")]
+    pub type_desc: Option<String>,
+    /// The whole thing, written out. E.G. "Boston Red Sox activated RHP Nathan Eovaldi from
+    /// the 10-day injured list."
+    pub description: Option<String>,
+    /// Who it happened to. Moves that are about a team rather than a player don't have one.
+    pub person: Option<Person>,
+}
+
+/// Whose transactions to ask for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scope {
+    /// The team with the given id.
+    Team(u32),
+    /// Every team in the sport with the given id (1 being MLB proper).
+    Sport(u32),
+}
+
+impl Transactions {
+    /// Where to find the given scope's transactions from start through end, inclusively.
+    pub fn url(scope: Scope, start: NaiveDate, end: NaiveDate) -> String {
+        let mut url = endpoint(TRANSACTIONS_ENDPOINT);
+        {
+            let mut query = url.query_pairs_mut();
+            match scope {
+                Scope::Team(id) => query.append_pair("teamId", &id.to_string()),
+                Scope::Sport(id) => query.append_pair("sportId", &id.to_string()),
+            };
+            query.append_pair("startDate", &start.format("%Y-%m-%d").to_string());
+            query.append_pair("endDate", &end.format("%Y-%m-%d").to_string());
+        }
+        url.to_string()
+    }
+
+    pub async fn try_from(scope: Scope, start: NaiveDate, end: NaiveDate) -> APIResult<Self> {
+        get_json(
+            &*default_transport(),
+            &Self::url(scope, start, end),
+            DEFAULT_TIMEOUT,
+        )
+        .await
+    }
+}
+
+/// Looks up the given scope's transactions from start through end once, handing them back over
+/// the returned channel. See fetch_once for what becomes of a failure.
+pub fn fetch(
+    scope: Scope,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> crossbeam_channel::Receiver<Vec<Transaction>> {
+    let lookup = async move {
+        let transactions = Transactions::try_from(scope, start, end).await?;
+        Ok(transactions.transactions)
+    };
+    fetch_once(
+        lookup,
+        info_span!("transactions", ?scope),
+        "the transactions",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url() {
+        let (start, end) = (
+            NaiveDate::from_ymd_opt(2019, 9, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2019, 9, 23).unwrap(),
+        );
+        assert_eq!(
+            Transactions::url(Scope::Team(111), start, end),
+            "http://statsapi.mlb.com/api/v1/transactions\
+             ?teamId=111&startDate=2019-09-01&endDate=2019-09-23"
+        );
+        assert!(Transactions::url(Scope::Sport(1), start, end).contains("?sportId=1&"));
+    }
+
+    #[test]
+    fn transactions() {
+        let transactions: Transactions = serde_json::from_str(
+            r#"{"copyright": "", "transactions": [
+                {
+                    "id": 412345,
+                    "person": {"id": 543135, "fullName": "Nathan Eovaldi"},
+                    "toTeam": {"id": 111, "name": "Boston Red Sox"},
+                    "date": "2019-09-20",
+                    "typeCode": "SC",
+                    "typeDesc": "Status Change",
+                    "description": "Boston Red Sox activated RHP Nathan Eovaldi."
+                },
+                {"id": 412346, "date": "2019-09-21", "typeDesc": "Trade"}
+            ]}"#,
+        )
+        .unwrap();
+        let first = &transactions.transactions[0];
+        assert_eq!(first.person.as_ref().unwrap().full_name, "Nathan Eovaldi");
+        assert_eq!(first.type_desc.as_deref(), Some("Status Change"));
+        assert!(transactions.transactions[1].description.is_none());
+    }
+}
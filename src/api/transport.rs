@@ -0,0 +1,155 @@
+use super::{snippet, APIError, APIResult, ErrorContext};
+use hyper::client::HttpConnector;
+use hyper_tls::HttpsConnector;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// What a Transport hands back from a GET, which is to say, eventually the whole body.
+pub type Download<'a> = Pin<Box<dyn Future<Output = APIResult<Vec<u8>>> + Send + 'a>>;
+
+/// Whatever it is that actually goes out and gets things off of the network.
+///
+/// Everything in this application is a plain GET (the schedule, the live feeds, and the
+/// photos), so that's all that there is to it. Hyper is what does it for real, but anything
+/// that can hand back some bytes will do. E.G. a canned response in a test, or a recording
+/// of a game day that you'd like to replay in the dead of winter.
+///
+/// There is no such thing as an async fn in a trait (yet), so implementors have to box up
+/// their own futures. Box::pin(async move { ... }) does the trick.
+pub trait Transport: Send + Sync {
+    /// GETs the given source, resolving to the body of a successful (2xx) response. Anything
+    /// else is an error, with the status and a snippet of the body as its context.
+    fn get<'a>(&'a self, src: &'a str) -> Download<'a>;
+}
+
+lazy_static! {
+    // The schedule, the live feeds, and the photos all share this one transport (and therefore
+    // the one connection pool) unless they're told otherwise.
+    static ref DEFAULT: Arc<dyn Transport> = Arc::new(Hyper::new());
+}
+
+/// The transport that everything uses unless it is given another.
+pub fn default_transport() -> Arc<dyn Transport> {
+    DEFAULT.clone()
+}
+
+/// The real deal.
+///
+/// hyper::Client::default() only speaks plain old http, which was fine right up until somebody
+/// pointed it at https://statsapi.mlb.com, so this uses a connector that happily does both.
+pub struct Hyper {
+    client: hyper::Client<HttpsConnector<HttpConnector>>,
+}
+
+impl Hyper {
+    // How many redirects a request may bounce through before we decide that it's going in circles.
+    const MAX_REDIRECTS: usize = 5;
+
+    pub fn new() -> Hyper {
+        Hyper {
+            client: hyper::Client::builder().build(HttpsConnector::new()),
+        }
+    }
+
+    // hyper doesn't follow redirects on its own, and the image CDNs are rather fond of them.
+    async fn download(&self, src: &str) -> APIResult<Vec<u8>> {
+        let mut current = src.to_string();
+        for _ in 0..=Self::MAX_REDIRECTS {
+            let err = |context, original: String| APIError {
+                src: current.clone(),
+                context,
+                original,
+            };
+            let target = current
+                .parse::<hyper::Uri>()
+                .map_err(|e| err(ErrorContext::URIParsing, e.to_string()))?;
+            let resp = self
+                .client
+                .get(target)
+                .await
+                .map_err(|e| err(ErrorContext::ConnectionEstablishment, e.to_string()))?;
+            let status = resp.status();
+            if status.is_redirection() {
+                let location = resp
+                    .headers()
+                    .get(hyper::header::LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .and_then(|location| redirect(&current, location));
+                match location {
+                    Some(next) => {
+                        current = next;
+                        continue;
+                    }
+                    None => {
+                        return Err(err(
+                            ErrorContext::HttpStatus(status.as_u16()),
+                            "redirected to nowhere in particular".to_string(),
+                        ))
+                    }
+                }
+            }
+            let buf = hyper::body::to_bytes(resp)
+                .await
+                .map_err(|e| err(ErrorContext::Downloading, e.to_string()))?;
+            // An error page is never going to be what anybody wanted, and "failed to deserialize"
+            // (or "failed to decode") is a rather misleading way of saying that the server had
+            // a bad day.
+            if !status.is_success() {
+                return Err(err(
+                    ErrorContext::HttpStatus(status.as_u16()),
+                    snippet(&buf),
+                ));
+            }
+            return Ok(buf.to_vec());
+        }
+        Err(APIError {
+            src: src.to_string(),
+            context: ErrorContext::Downloading,
+            original: format!("redirected more than {} times", Self::MAX_REDIRECTS),
+        })
+    }
+}
+
+impl Default for Hyper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for Hyper {
+    fn get<'a>(&'a self, src: &'a str) -> Download<'a> {
+        Box::pin(self.download(src))
+    }
+}
+
+// Where a redirect from the given URL to the given Location header points. Locations are
+// allowed to be relative, in which case they are relative to wherever we were redirected from.
+fn redirect(from: &str, location: &str) -> Option<String> {
+    url::Url::parse(from)
+        .and_then(|from| from.join(location))
+        .map(|to| to.to_string())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redirects() {
+        let from = "https://img.mlbstatic.com/mlb-photos/image/upload/cut.jpg";
+        assert_eq!(
+            redirect(from, "https://cdn.example.com/cut.jpg").unwrap(),
+            "https://cdn.example.com/cut.jpg"
+        );
+        assert_eq!(
+            redirect(from, "/elsewhere/cut.jpg").unwrap(),
+            "https://img.mlbstatic.com/elsewhere/cut.jpg"
+        );
+        assert_eq!(
+            redirect(from, "sibling.jpg").unwrap(),
+            "https://img.mlbstatic.com/mlb-photos/image/upload/sibling.jpg"
+        );
+    }
+}
@@ -0,0 +1,510 @@
+use crate::banner::Banners;
+use crate::config::{Config, DEFAULT_RESOLUTION};
+use crate::input;
+use crate::layout;
+use crate::metrics::Metrics;
+use crate::render;
+use crate::screen::{Carousel, Error, Screen, Transition};
+use crate::session::Session;
+use crate::{build_window, window_size, Assets};
+use chrono::NaiveDate;
+use mlb::lineup::{self, Lineup, Progress, Row, Sort};
+use piston_window::{
+    AdvancedWindow, EventLoop, FocusEvent, ImageSize, ResizeEvent, Transformed, Window,
+};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+use tracing::{error, warn};
+
+thread_local! {
+    // What the most recent panic on this thread had to say for itself, as told by the hook.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Takes over reporting panics from the standard library, which would otherwise print them
+/// to stderr where nobody watching the TV is ever going to see them. Panics are logged, and
+/// what they had to say is held on to for the App to put on the error screen.
+pub fn install_panic_hook() {
+    panic::set_hook(Box::new(|info| {
+        let payload = info.payload();
+        let message = match (
+            payload.downcast_ref::<&str>(),
+            payload.downcast_ref::<String>(),
+        ) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => "something went wrong".to_string(),
+        };
+        let text = match info.location() {
+            Some(at) => format!("{} (at {}:{})", message, at.file(), at.line()),
+            None => message,
+        };
+        error!(panic = %text, "Panicked");
+        PANIC.with(|last| *last.borrow_mut() = Some(text));
+    }));
+}
+
+/// Everything that the screens have in common, which is to say everything that outlives any
+/// one of them.
+pub struct State {
+    pub config: Config,
+    pub layout: layout::Layout,
+    /// Everything that has to be uploaded to the window before anything can be drawn.
+    pub assets: Assets,
+    /// None until the very first schedule comes in.
+    pub schedule: Option<Lineup>,
+    /// Kept out here rather than only in the lineup, since the lineup is thrown out and
+    /// replaced every time that the user walks the calendar.
+    pub spoiler_free: bool,
+    /// The teams that the carousel is restricted to, if any. Kept out here for the same reason.
+    pub filter: Option<Vec<String>>,
+    /// Which games are kept off of the carousel by how far along they are, which is kept out
+    /// here for the same reason again.
+    pub progress: Progress,
+    /// How the games on the carousel are put in order, which starts out as the config says
+    /// and is kept out here for the same reason as well.
+    pub sort: Sort,
+    /// A day, and maybe a game on it, that a screen further up the stack would like the
+    /// carousel to go to (E.G. one picked out of the week view).
+    pub jump: Option<(NaiveDate, Option<u64>)>,
+    /// Background failures that the user is being told about.
+    pub banners: Banners,
+    /// Whether or not anything has changed since the last time that the window was drawn.
+    pub dirty: bool,
+    /// How things are running, for the debug overlay.
+    pub metrics: Metrics,
+    /// Where the last run left off, and where this one is going to have left off.
+    pub session: Session,
+}
+
+impl State {
+    /// The row that the user is scrolling through, if there is one.
+    pub fn current(&mut self) -> Option<&mut Row> {
+        self.schedule.as_mut().and_then(Lineup::current)
+    }
+
+    /// How a lineup goes about things, as the config says and as of the window's current size.
+    /// A HiDPI screen draws more pixels than the window says it's got, so that's what the photos
+    /// are scaled to. Pages are as long as the window has room for, unless the user said how
+    /// long.
+    pub fn settings(&self) -> lineup::Settings {
+        let config = &self.config;
+        lineup::Settings {
+            favorites: config.favorites.clone(),
+            timezone: config.timezone,
+            budget: config.image_budget,
+            photos: true,
+            reduced_motion: config.reduced_motion,
+            page_size: config.page_size.unwrap_or_else(|| self.layout.page_size()),
+            scale: self.layout.scale * self.layout.density,
+            max_downloads: config.max_downloads,
+        }
+    }
+
+    // Lets the lineup in on the window's new size, which changes how big its photos are and
+    // how many games fit on a page.
+    fn resized(&mut self) {
+        let settings = self.settings();
+        if let Some(lineup) = &mut self.schedule {
+            lineup.set_scale(settings.scale);
+            lineup.set_page_size(settings.page_size);
+        }
+    }
+}
+
+/// The window, and the stack of screens that are in it.
+///
+/// The app turns whatever comes out of the window into commands, hands them to whichever
+/// screen is on top, and then does whatever that screen says to do with the stack. The
+/// carousel is always at the bottom, unless it has been replaced by an error.
+pub struct App {
+    title: String,
+    fullscreen: bool,
+    window: piston_window::PistonWindow,
+    screens: Vec<Box<dyn Screen>>,
+    state: State,
+    controls: input::Controls,
+    // Controllers, for those who'd rather run this on the TV. None if the platform has no
+    // way of getting at them, in which case the keyboard will have to do.
+    gamepads: Option<input::gamepad::Gamepads>,
+    status: i32,
+    // The frame rate that the window is capped at right now.
+    fps: u64,
+    // How many passes of the event loop in a row have panicked.
+    panics: u32,
+}
+
+// Lays everything out for a window of the given size. A HiDPI screen draws more pixels than the
+// window says it's got, which is why the width that is actually drawn to comes into it.
+fn relayout(size: [f64; 2], drawn: f64, config: &Config) -> layout::Layout {
+    let density = match size[0] > 0.0 && drawn > 0.0 {
+        true => drawn / size[0],
+        false => 1.0,
+    };
+    layout::Layout::new(size)
+        .with_density(density)
+        .with_padding(config.theme.padding)
+}
+
+impl App {
+    pub fn new(title: &str, config: Config, mut session: Session) -> App {
+        let fullscreen = config.fullscreen;
+        // A size on the command line beats whatever size the window was last closed at.
+        let size = config
+            .resolution
+            .or(session.size)
+            .unwrap_or(DEFAULT_RESOLUTION);
+        session.size = Some(size);
+        let mut window = build_window(title, &config, size, fullscreen);
+        let assets = Assets::new(&mut window, &config);
+        let layout = relayout(window_size(&window), window.draw_size().width, &config);
+        let (spoiler_free, sort) = (config.spoiler_free, config.sort);
+        let fps = config.fps(false);
+        let state = State {
+            config,
+            layout,
+            assets,
+            schedule: None,
+            spoiler_free,
+            filter: None,
+            progress: Progress::All,
+            sort,
+            jump: None,
+            banners: Banners::new(),
+            dirty: true,
+            metrics: Metrics::new(),
+            session,
+        };
+        let mut app = App {
+            title: title.to_string(),
+            fullscreen,
+            screens: vec![Box::new(Carousel::new(&state))],
+            window,
+            state,
+            controls: input::Controls::new(),
+            gamepads: input::gamepad::Gamepads::new(),
+            status: 0,
+            fps,
+            panics: 0,
+        };
+        app.place();
+        app
+    }
+
+    /// Runs until the window is closed, returning the status that the process ought to exit with.
+    ///
+    /// A panic on any pass of the event loop doesn't take the window down with it. The panic
+    /// is put up on the error screen instead, which is a whole lot more helpful to somebody
+    /// on the couch than the window up and vanishing.
+    pub fn run(mut self) -> i32 {
+        while let Some(e) = self.window.next() {
+            let pass = panic::catch_unwind(AssertUnwindSafe(|| {
+                self.handle(&e);
+                self.update();
+                self.pace();
+                if self.state.dirty {
+                    self.draw(&e);
+                }
+            }));
+            match pass {
+                Ok(_) => self.panics = 0,
+                Err(_) => self.recover(),
+            }
+        }
+        self.save_session();
+        self.status
+    }
+
+    // Caps the frame rate high for as long as there's anything left to draw after a pass, which
+    // is to say while something is animating, and low again once everything has settled down.
+    fn pace(&mut self) {
+        let fps = self.state.config.fps(self.state.dirty);
+        if fps != self.fps {
+            self.window.set_max_fps(fps);
+            self.fps = fps;
+        }
+    }
+
+    // Makes note of where the user left off for next time. Whatever was loaded at startup is
+    // kept as is should there be no schedule to go by, E.G. if we're closing on the error screen.
+    fn save_session(&mut self) {
+        self.remember_window();
+        let State {
+            schedule, session, ..
+        } = &mut self.state;
+        if let Some(row) = schedule.as_mut().and_then(Lineup::current) {
+            session.date = Some(row.date.clone());
+            session.cursor = row.schedule.games.cursor();
+        }
+        if let Some(path) = Session::user() {
+            if let Err(err) = session.save(&path) {
+                warn!(path = %path.display(), error = %err, "Failed to save the session");
+            }
+        }
+    }
+
+    // Whatever was going on when the panic hit is in no shape to carry on, so the whole stack
+    // is thrown out in favor of the error screen. Should the error screen itself keep on
+    // panicking though, then there's nothing left to fall back on but closing the window.
+    fn recover(&mut self) {
+        const MOST: u32 = 3;
+        self.panics += 1;
+        if self.panics >= MOST {
+            self.status = 101;
+            self.window.set_should_close(true);
+            return;
+        }
+        let message = PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| "something went wrong".to_string());
+        self.screens = vec![Box::new(Error::panicked(message))];
+        self.state.dirty = true;
+    }
+
+    fn handle(&mut self, e: &piston_window::Event) {
+        if e.resize_args().is_some() || e.focus_args().is_some() {
+            self.state.dirty = true;
+        }
+        if let Some(args) = e.resize_args() {
+            self.state.layout = relayout(
+                args.window_size,
+                args.draw_size[0] as f64,
+                &self.state.config,
+            );
+            self.state.resized();
+        }
+        // The keyboard, the mouse, and the controllers alike boil down to the same handful of
+        // commands, so the screens don't care which one it was.
+        let mut inputs = input::window::inputs(e, &self.state.config.keymap);
+        if let Some(gamepads) = &mut self.gamepads {
+            inputs.extend(gamepads.poll());
+        }
+        let now = Instant::now();
+        let mut commands = vec![];
+        for input in inputs {
+            // Merely moving the mouse around doesn't change anything on screen.
+            match input {
+                input::Input::Cursor(_) => (),
+                _ => self.state.dirty = true,
+            }
+            commands.extend(self.controls.interpret(input, now));
+        }
+        let repeats = self.controls.repeats(now);
+        self.state.dirty |= !repeats.is_empty();
+        for command in repeats.into_iter().chain(commands) {
+            self.dispatch(command);
+        }
+    }
+
+    // Hands the command to the screen on top, and on down the stack for as long as each screen
+    // passes on it. Whatever makes it all the way through is the app's to deal with.
+    fn dispatch(&mut self, command: input::Command) {
+        for index in (0..self.screens.len()).rev() {
+            match self.screens[index].handle_input(command.clone(), &mut self.state) {
+                Transition::Pass => continue,
+                transition => return self.apply(transition),
+            }
+        }
+        match command {
+            input::Command::Action(input::Action::Fullscreen) => self.toggle_fullscreen(),
+            input::Command::Action(input::Action::Debug) => {
+                self.state.metrics.shown = !self.state.metrics.shown;
+                self.state.dirty = true;
+            }
+            _ => (),
+        }
+    }
+
+    fn update(&mut self) {
+        let mut index = 0;
+        while index < self.screens.len() {
+            let transition = self.screens[index].update(&mut self.state);
+            self.apply(transition);
+            index += 1;
+        }
+        let now = Instant::now();
+        self.state.dirty |= self.state.banners.expire(now);
+        self.state.dirty |= self.state.metrics.stale(now);
+        self.upload();
+    }
+
+    // Gets whichever photos showed up on this pass onto the GPU now, rather than leaving it to
+    // the first frame that happens to draw them. A whole page of photos tends to show up all at
+    // once, so they're spread out over a few passes instead of hitching a single one.
+    fn upload(&mut self) {
+        const MOST: usize = 4;
+        let State {
+            schedule, assets, ..
+        } = &mut self.state;
+        if let Some(lineup) = schedule {
+            for (id, rgba) in lineup.take_fresh(MOST) {
+                assets.textures.upload(id, rgba);
+            }
+        }
+    }
+
+    fn apply(&mut self, transition: Transition) {
+        match transition {
+            Transition::Stay | Transition::Pass => return,
+            Transition::Push(screen) => self.screens.push(screen),
+            Transition::Pop => {
+                self.screens.pop();
+            }
+            Transition::Replace(screen) => self.screens = vec![screen],
+            Transition::Exit(status) => {
+                self.status = status;
+                self.window.set_should_close(true);
+            }
+        }
+        self.state.dirty = true;
+    }
+
+    // Piston has no way of flipping an existing window in and out of fullscreen, so this builds
+    // a whole new one. The new window starts off with nothing uploaded to it, but the schedule
+    // and all of its photos are ours rather than the window's, so nothing gets re-downloaded.
+    fn toggle_fullscreen(&mut self) {
+        self.remember_window();
+        self.fullscreen = !self.fullscreen;
+        let size = self.state.session.size.unwrap_or(DEFAULT_RESOLUTION);
+        self.window = build_window(&self.title, &self.state.config, size, self.fullscreen);
+        self.fps = self.state.config.fps(false);
+        self.place();
+        self.state.assets = Assets::new(&mut self.window, &self.state.config);
+        self.state.layout = relayout(
+            window_size(&self.window),
+            self.window.draw_size().width,
+            &self.state.config,
+        );
+        self.state.resized();
+        self.state.dirty = true;
+    }
+
+    // Makes note of how big the window is and where it is on the screen, for whenever it next
+    // comes out of fullscreen, be it later on in this run or at the start of the next one.
+    // A fullscreen window has nothing to say about either.
+    fn remember_window(&mut self) {
+        if self.fullscreen {
+            return;
+        }
+        let size = self.window.size();
+        self.state.session.size = Some([size.width.round() as u32, size.height.round() as u32]);
+        if let Some(position) = self.window.get_position() {
+            self.state.session.position = Some([position.x, position.y]);
+        }
+    }
+
+    // Puts the window back wherever it was last remembered to be, if it isn't fullscreen.
+    fn place(&mut self) {
+        if let (false, Some([x, y])) = (self.fullscreen, self.state.session.position) {
+            self.window.set_position(piston_window::Position { x, y });
+        }
+    }
+
+    fn draw(&mut self, e: &piston_window::Event) {
+        let App {
+            window,
+            screens,
+            state,
+            ..
+        } = self;
+        // Overlays are drawn over top of whatever is under them, so drawing starts from the
+        // topmost screen that isn't one.
+        let bottom = screens
+            .iter()
+            .rposition(|screen| !screen.is_overlay())
+            .unwrap_or(0);
+        let start = Instant::now();
+        let drawn = window.draw_2d(e, |c, g, device| {
+            // This is the main rendering loop as per piston convention.
+            //
+            // Where everything goes on screen is the layout's business. It was all originally
+            // eyeballed against a 1920x1080 screen, and the layout scales that to the window.
+            // The theme's color (if it has one) takes the stadium's place, but a photo of the
+            // user's own goes over top of it, showing through wherever that is letterboxed.
+            let config = &state.config;
+            piston_window::clear(config.theme.background.unwrap_or([0.0, 0.0, 0.0, 1.0]), g);
+            if config.background.is_some() || config.theme.background.is_none() {
+                let (width, height) = state.assets.background.get_size();
+                let size = [width as f64, height as f64];
+                graphics::image::Image::new()
+                    .rect(state.layout.background(size, config.background_fit))
+                    .draw(
+                        &state.assets.background,
+                        &graphics::DrawState::default(),
+                        c.transform,
+                        g,
+                    );
+            }
+            for screen in screens[bottom..].iter_mut() {
+                screen.draw(state, c, g);
+            }
+            // Banners go over top of everything, whichever screen it is, as do the numbers.
+            draw_banners(state, c, g);
+            if state.metrics.shown {
+                draw_metrics(state, c, g);
+            }
+            // And I guess we have to...flush the font encoder with the given device?
+            // This object graph doesn't make much sense to me, but that just
+            // might be because I don't know anything about graphics.
+            for glyphs in state.assets.glyphs.caches() {
+                glyphs.factory.encoder.flush(device);
+            }
+        });
+        // draw_2d only actually draws on render events.
+        if drawn.is_some() {
+            window.swap_buffers();
+            state.dirty = false;
+            let now = Instant::now();
+            state.metrics.frame(now - start, now);
+        }
+    }
+}
+
+// Each banner is a single line, and anything that won't fit on it just runs off of the end.
+// Those that need the whole story have the log.
+fn draw_banners(state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+    let (layout, banners, glyphs) = (&state.layout, &state.banners, &mut state.assets.glyphs);
+    let theme = &state.config.theme;
+    for (index, text) in banners.texts().enumerate() {
+        let banner = layout.banner(index);
+        piston_window::rectangle(theme.alert, banner, c.transform, g);
+        let [x, y] = layout.banner_text(banner);
+        render::text(
+            theme.text,
+            layout.font_size(20),
+            text,
+            glyphs,
+            c.transform.trans(x, y),
+            layout.density,
+            g,
+        );
+    }
+}
+
+// The debug overlay, in a box of its own so that it can be read over top of the photos.
+fn draw_metrics(state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+    let photos = state.schedule.as_mut().map(Lineup::stats);
+    let textures = &state.assets.textures;
+    let lines = state.metrics.lines(
+        photos,
+        (textures.count(), textures.hit_rate()),
+        Instant::now(),
+    );
+    let (layout, glyphs) = (&state.layout, &mut state.assets.glyphs);
+    let theme = &state.config.theme;
+    let metrics = layout.metrics(lines.len());
+    piston_window::rectangle(theme.scrim, metrics, c.transform, g);
+    for (index, line) in lines.iter().enumerate() {
+        let [x, y] = layout.metrics_text(metrics, index);
+        render::text(
+            theme.text,
+            layout.font_size(18),
+            line,
+            glyphs,
+            c.transform.trans(x, y),
+            layout.density,
+            g,
+        );
+    }
+}
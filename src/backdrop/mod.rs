@@ -0,0 +1,106 @@
+use std::time::{Duration, Instant};
+
+/// Keeps track of which game's photo is blurred in behind the carousel, and of the one that it
+/// took over from for as long as the two are crossfading. Moving the cursor shouldn't snap the
+/// whole window over to another photo, that's a lot of screen to change all at once.
+///
+/// Backdrops go by the same ids that they are drawn under, so that whoever draws them can go
+/// and find their textures.
+pub struct Backdrop {
+    current: Option<String>,
+    previous: Option<String>,
+    changed: Instant,
+    // How long the crossfade takes, which is no time at all with reduced motion.
+    fade: Duration,
+}
+
+impl Backdrop {
+    // A touch slower than photos fade in, since this covers the whole window.
+    const FADE: Duration = Duration::from_millis(400);
+
+    /// With reduced motion, each backdrop simply takes the place of the last one.
+    pub fn new(reduced_motion: bool) -> Backdrop {
+        Backdrop {
+            current: None,
+            previous: None,
+            changed: Instant::now(),
+            fade: match reduced_motion {
+                true => Duration::from_secs(0),
+                false => Self::FADE,
+            },
+        }
+    }
+
+    /// Moves over to the given backdrop (or to none at all, which fades back to the usual
+    /// background), returning whether or not that's a change. Moving on in the middle of a
+    /// crossfade leaves whichever backdrop was on its way out behind for good.
+    pub fn show(&mut self, id: Option<&str>, now: Instant) -> bool {
+        if self.current.as_deref() == id {
+            return false;
+        }
+        self.previous = std::mem::replace(&mut self.current, id.map(str::to_string));
+        self.changed = now;
+        true
+    }
+
+    /// The backdrop that is on its way out, if it isn't all the way gone yet.
+    pub fn previous(&self, now: Instant) -> Option<&str> {
+        match self.fading(now) {
+            true => self.previous.as_deref(),
+            false => None,
+        }
+    }
+
+    /// How far along the crossfade is as of the given moment, from 0 (the previous backdrop)
+    /// to 1 (the current one).
+    pub fn opacity(&self, now: Instant) -> f32 {
+        if self.fade == Duration::from_secs(0) {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(self.changed);
+        (elapsed.as_secs_f32() / self.fade.as_secs_f32()).min(1.0)
+    }
+
+    /// Whether or not the crossfade is still going, and so looks a little different every frame.
+    pub fn fading(&self, now: Instant) -> bool {
+        self.opacity(now) < 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossfades() {
+        let start = Instant::now();
+        let mut backdrop = Backdrop::new(false);
+        assert!(backdrop.show(Some("first"), start));
+        assert!(!backdrop.show(Some("first"), start + Duration::from_millis(100)));
+        let later = start + Duration::from_secs(1);
+        assert!(!backdrop.fading(later));
+        assert!(backdrop.show(Some("second"), later));
+        let halfway = later + Duration::from_millis(200);
+        assert!(backdrop.fading(halfway));
+        assert_eq!(backdrop.opacity(halfway), 0.5);
+        assert_eq!(backdrop.previous(halfway), Some("first"));
+        assert_eq!(backdrop.previous(later + Duration::from_secs(1)), None);
+        // Fading back out to nothing.
+        assert!(backdrop.show(None, later + Duration::from_secs(1)));
+        assert_eq!(
+            backdrop.previous(later + Duration::from_millis(1100)),
+            Some("second")
+        );
+    }
+
+    #[test]
+    fn snaps_with_reduced_motion() {
+        let start = Instant::now();
+        let mut backdrop = Backdrop::new(true);
+        assert!(backdrop.show(Some("first"), start));
+        assert!(backdrop.show(Some("second"), start));
+        assert!(!backdrop.fading(start));
+        assert_eq!(backdrop.opacity(start), 1.0);
+        assert_eq!(backdrop.previous(start), None);
+    }
+}
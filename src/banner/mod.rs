@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+/// Things that went wrong in the background that the user ought to know about, but that
+/// aren't worth stopping everything over. A photo that wouldn't download or a refresh that
+/// timed out, say. Each one is shown along the top of the screen for a few seconds and then
+/// goes away on its own.
+///
+/// Everything here is also logged by whoever ran into it. This is just the part
+/// that somebody sitting on the couch can actually see.
+pub struct Banners {
+    shown: Vec<Banner>,
+}
+
+struct Banner {
+    text: String,
+    since: Instant,
+}
+
+impl Banners {
+    // Long enough to read, not so long that it overstays its welcome.
+    const LINGER: Duration = Duration::from_secs(6);
+    // When things go wrong they tend to all go wrong at once, and there's only so much room
+    // up there before they start covering up the games.
+    const MOST: usize = 3;
+
+    pub fn new() -> Banners {
+        Banners { shown: vec![] }
+    }
+
+    /// Puts up a banner with the given text. If that very same banner is already up then it
+    /// just sticks around for longer, since a network that is down will keep on saying so.
+    pub fn push<S: Into<String>>(&mut self, text: S, now: Instant) {
+        let text = text.into();
+        self.shown.retain(|banner| banner.text != text);
+        self.shown.push(Banner { text, since: now });
+        if self.shown.len() > Self::MOST {
+            self.shown.remove(0);
+        }
+    }
+
+    /// Takes down whichever banners have been up long enough, returning whether or not any were.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.shown.len();
+        self.shown
+            .retain(|banner| now.saturating_duration_since(banner.since) < Self::LINGER);
+        self.shown.len() != before
+    }
+
+    /// What is up right now, oldest first.
+    pub fn texts(&self) -> impl Iterator<Item = &str> {
+        self.shown.iter().map(|banner| banner.text.as_str())
+    }
+}
+
+impl Default for Banners {
+    fn default() -> Self {
+        Banners::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn banners_go_away_on_their_own() {
+        let start = Instant::now();
+        let mut banners = Banners::new();
+        banners.push("one", start);
+        banners.push("two", start + Duration::from_secs(4));
+        assert!(!banners.expire(start + Duration::from_secs(5)));
+        assert!(banners.expire(start + Duration::from_secs(7)));
+        assert_eq!(banners.texts().collect::<Vec<_>>(), vec!["two"]);
+        assert!(banners.expire(start + Duration::from_secs(11)));
+        assert_eq!(banners.texts().count(), 0);
+    }
+
+    #[test]
+    fn repeats_stick_around() {
+        let start = Instant::now();
+        let mut banners = Banners::new();
+        banners.push("offline", start);
+        banners.push("other", start);
+        banners.push("offline", start + Duration::from_secs(5));
+        assert_eq!(
+            banners.texts().collect::<Vec<_>>(),
+            vec!["other", "offline"]
+        );
+        banners.expire(start + Duration::from_secs(7));
+        assert_eq!(banners.texts().collect::<Vec<_>>(), vec!["offline"]);
+        for text in &["a", "b", "c"] {
+            banners.push(*text, start);
+        }
+        assert_eq!(banners.texts().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    }
+}
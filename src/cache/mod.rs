@@ -0,0 +1,113 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::warn;
+
+/// A dead simple on disk cache of raw bytes, keyed by whatever string the caller likes (E.G. the
+/// URL that the bytes came from).
+///
+/// Each entry is just a file in the cache directory. An entry that is older than the TTL is
+/// treated as though it isn't there at all, and gets overwritten the next time that it is put.
+///
+/// Nothing that goes wrong in here is worth stopping the show over. The worst case of a cache
+/// that can't read or write is that we download things again, which is exactly what we did
+/// before there was a cache. So failures are logged and otherwise treated as a miss.
+pub struct Cache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl Cache {
+    pub fn new(dir: PathBuf, ttl: Duration) -> Cache {
+        Cache { dir, ttl }
+    }
+
+    /// A cache living in the given subdirectory of the user's cache directory. That is,
+    /// $XDG_CACHE_HOME/mlb/name, falling back to ~/.cache/mlb/name per the XDG spec, and
+    /// finally to the system's temp directory if we don't even know where home is.
+    pub fn user(name: &str, ttl: Duration) -> Cache {
+        let root = std::env::var_os("XDG_CACHE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .unwrap_or_else(std::env::temp_dir);
+        Cache::new(root.join("mlb").join(name), ttl)
+    }
+
+    /// The bytes stored under the given key, if there are any and they haven't gone stale.
+    pub async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let path = self.path(key);
+        let modified = tokio::fs::metadata(&path).await.ok()?.modified().ok()?;
+        match modified.elapsed() {
+            Ok(age) if age < self.ttl => (),
+            // An Err here means that the file is from the future, which we'll call stale too.
+            _ => return None,
+        }
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Some(bytes),
+            Err(err) => {
+                warn!(path = %path.display(), error = %err, "Failed to read from the cache");
+                None
+            }
+        }
+    }
+
+    /// Stores the given bytes under the given key, replacing whatever was there.
+    pub async fn put(&self, key: &str, bytes: &[u8]) {
+        if let Err(err) = tokio::fs::create_dir_all(&self.dir).await {
+            warn!(dir = %self.dir.display(), error = %err, "Failed to create the cache directory");
+            return;
+        }
+        let path = self.path(key);
+        if let Err(err) = tokio::fs::write(&path, bytes).await {
+            warn!(path = %path.display(), error = %err, "Failed to write to the cache");
+        }
+    }
+
+    // Keys are usually URLs, which are chock full of characters that filesystems hate, so
+    // entries are named after a hash of the key instead. If a new compiler ever changes
+    // what DefaultHasher spits out then the worst that happens is a round of cache misses.
+    fn path(&self, key: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        self.dir.join(format!("{:016x}", hasher.finish()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(name: &str, ttl: Duration) -> Cache {
+        let dir =
+            std::env::temp_dir().join(format!("mlb-cache-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        Cache::new(dir, ttl)
+    }
+
+    #[test]
+    fn round_trip() {
+        let cache = cache("round_trip", Duration::from_secs(60));
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            assert_eq!(cache.get("http://example.com/a.jpg").await, None);
+            cache.put("http://example.com/a.jpg", b"pixels").await;
+            assert_eq!(
+                cache.get("http://example.com/a.jpg").await,
+                Some(b"pixels".to_vec())
+            );
+            assert_eq!(cache.get("http://example.com/b.jpg").await, None);
+        });
+        let _ = std::fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn stale_entries_miss() {
+        let cache = cache("stale_entries_miss", Duration::from_secs(0));
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            cache.put("http://example.com/a.jpg", b"pixels").await;
+            assert_eq!(cache.get("http://example.com/a.jpg").await, None);
+        });
+        let _ = std::fs::remove_dir_all(&cache.dir);
+    }
+}
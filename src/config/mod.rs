@@ -0,0 +1,871 @@
+use crate::font::Typeface;
+use crate::input::Keymap;
+use crate::layout::Fit;
+use crate::locale::Language;
+use crate::logging;
+use crate::theme::{self, Theme};
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+use clap::{App, Arg, ArgMatches};
+use image::imageops::FilterType;
+use image::{GenericImageView, RgbaImage};
+use mlb::api::{Season, Sport};
+use mlb::lineup::Sort;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::warn;
+
+/// The size of the window the first time that it's opened, which is what everything was
+/// originally laid out for.
+pub const DEFAULT_RESOLUTION: [u32; 2] = [1920, 1080];
+
+/// Everything that the user gets a say in at launch time.
+pub struct Config {
+    /// None means that the user would like to see whatever is on today.
+    pub date: Option<NaiveDate>,
+    /// The past season that "on this day" goes back to, if the user has one in mind. When
+    /// given at launch, date has already been moved back into it.
+    pub on_this_day: Option<Season>,
+    /// How many days, starting at date, to show at once. Each day gets a row of its own.
+    pub days: u32,
+    pub sport_id: u32,
+    /// The size of the window. None means whatever size it was when it was last closed, or
+    /// DEFAULT_RESOLUTION if it has never been opened before.
+    pub resolution: Option<[u32; 2]>,
+    /// Whether or not the window starts out fullscreen. F11 flips it either way at runtime.
+    pub fullscreen: bool,
+    /// Whether or not the window goes without a title bar and borders when it isn't fullscreen.
+    pub borderless: bool,
+    /// The most frames per second to draw while something on screen is on the move (E.G. a
+    /// photo fading in).
+    pub max_fps: u64,
+    /// The most frames per second to draw while nothing is, which is most of the time.
+    pub idle_fps: u64,
+    /// Whether or not to wait on the display's refresh before swapping frames.
+    pub vsync: bool,
+    /// How often the schedule on screen is re-downloaded to pick up new scores and such.
+    pub refresh: Duration,
+    /// How long to wait on the schedule API before giving up on it.
+    pub timeout: Duration,
+    /// How many photos may be downloading at once.
+    pub max_downloads: usize,
+    /// How many bytes' worth of decoded photos to keep around at once.
+    pub image_budget: usize,
+    /// Whether or not to start out wherever the last run left off, short of a date being given.
+    pub restore: bool,
+    /// How often to move on to the next game on its own, for screens that nobody is driving
+    /// (E.G. in a bar). None means that the cursor stays put unless it is moved.
+    pub kiosk: Option<Duration>,
+    /// How many games make up a page of the carousel. None means as many as fit the window.
+    pub page_size: Option<usize>,
+    /// Where to write the schedule out to, instead of opening up a window at all.
+    pub export: Option<PathBuf>,
+    /// Where to write a calendar of the schedule's games out to, likewise.
+    pub export_ics: Option<PathBuf>,
+    /// Which key does what.
+    pub keymap: Keymap,
+    /// The colors and spacing that everything is drawn with.
+    pub theme: Theme,
+    /// A photo of the user's own to put behind everything, instead of the stadium.
+    pub background: Option<RgbaImage>,
+    /// How the background photo is fit to windows that aren't the same shape as it is.
+    pub background_fit: Fit,
+    /// Whether or not the focused game's photo is blurred in behind the carousel.
+    pub backdrop: bool,
+    /// Whether or not photos and backdrops snap into place rather than fading in.
+    pub reduced_motion: bool,
+    /// The font that everything is written in.
+    pub font: Typeface,
+    /// The font for whatever characters the first one doesn't have, if there is one.
+    pub fallback_font: Option<Typeface>,
+    /// The language that everything is written in.
+    pub language: Language,
+    /// Whether or not to start out keeping the scores and recaps of games under wraps until
+    /// they are revealed. S flips it either way at runtime.
+    pub spoiler_free: bool,
+    /// The teams whose games go first, by id, name, or abbreviation.
+    pub favorites: Vec<String>,
+    /// How the games on the carousel are put in order to start out with. A flips through the
+    /// others at runtime.
+    pub sort: Sort,
+    /// The team whose trades, signings, and roster moves the transactions screen lists, by id.
+    /// None means every team in the sport.
+    pub transactions_team: Option<u32>,
+    /// The timezone to give start times in. None means whatever the machine is set to.
+    pub timezone: Option<Tz>,
+}
+
+impl Config {
+    /// Parses the process arguments. Bad input is reported by clap, which
+    /// exits the process with usage information.
+    ///
+    /// This also starts up logging, which is done here rather than by whoever called this
+    /// so that it is up and running before the keymap is loaded (and maybe complained about).
+    pub fn from_args() -> Config {
+        let matches = Self::app().get_matches();
+        logging::init(matches.value_of("log-file").map(Path::new));
+        Self::from_matches(&matches).unwrap_or_else(|err| err.exit())
+    }
+
+    fn app() -> App<'static, 'static> {
+        App::new("mlb")
+            .about("Yeah buddy, let's watch us some sports ball!")
+            .arg(
+                Arg::with_name("date")
+                    .long("date")
+                    .takes_value(true)
+                    .value_name("YYYY-MM-DD")
+                    .validator(|date| parse_date(&date).map(|_| ()))
+                    .help("The date of the schedule to open up to [default: today]"),
+            )
+            .arg(
+                Arg::with_name("on-this-day")
+                    .long("on-this-day")
+                    .takes_value(true)
+                    .value_name("YEAR|random")
+                    .validator(|season| Season::parse(&season).map(|_| ()))
+                    .help("Open up to the same day (--date, or today) in a past season, which H goes back to as well [default for H: a random one]"),
+            )
+            .arg(
+                Arg::with_name("days")
+                    .long("days")
+                    .takes_value(true)
+                    .value_name("COUNT")
+                    .default_value("1")
+                    .validator(|days| parse_days(&days).map(|_| ()))
+                    .help("How many days to show at once, one row per day, up to 31"),
+            )
+            .arg(
+                Arg::with_name("sport-id")
+                    .long("sport-id")
+                    .takes_value(true)
+                    .value_name("ID")
+                    .default_value("1")
+                    .validator(|id| Sport::parse(&id).map(|_| ()))
+                    .help(
+                        "The statsapi sportId to query (1 is MLB proper), or a level by name: \
+                         mlb, aaa, aa, high-a, single-a, or rookie (flip between them with L)",
+                    ),
+            )
+            .arg(
+                Arg::with_name("resolution")
+                    .long("resolution")
+                    .takes_value(true)
+                    .value_name("WIDTHxHEIGHT")
+                    .validator(|res| parse_resolution(&res).map(|_| ()))
+                    .help("The size of the window [default: its size when it was last closed, or 1920x1080]"),
+            )
+            .arg(
+                Arg::with_name("fullscreen")
+                    .long("fullscreen")
+                    .help("Start in fullscreen mode (toggle with F11)"),
+            )
+            .arg(
+                Arg::with_name("borderless")
+                    .long("borderless")
+                    .help("Leave the title bar and borders off of the window"),
+            )
+            .arg(
+                Arg::with_name("max-fps")
+                    .long("max-fps")
+                    .takes_value(true)
+                    .value_name("FPS")
+                    .default_value("60")
+                    .validator(|fps| parse_fps(&fps).map(|_| ()))
+                    .help("The frame rate cap while anything is animating"),
+            )
+            .arg(
+                Arg::with_name("idle-fps")
+                    .long("idle-fps")
+                    .takes_value(true)
+                    .value_name("FPS")
+                    .default_value("10")
+                    .validator(|fps| parse_fps(&fps).map(|_| ()))
+                    .help("The frame rate cap while nothing is, to go easy on the CPU"),
+            )
+            .arg(
+                Arg::with_name("vsync")
+                    .long("vsync")
+                    .help("Sync frames up with the display's refresh, to keep animations from tearing"),
+            )
+            .arg(
+                Arg::with_name("refresh-minutes")
+                    .long("refresh-minutes")
+                    .takes_value(true)
+                    .value_name("MINUTES")
+                    .default_value("5")
+                    .validator(|minutes| parse_minutes(&minutes).map(|_| ()))
+                    .help("How often to refresh the schedule"),
+            )
+            .arg(
+                Arg::with_name("timeout-seconds")
+                    .long("timeout-seconds")
+                    .takes_value(true)
+                    .value_name("SECONDS")
+                    .default_value("10")
+                    .validator(|seconds| parse_seconds(&seconds).map(|_| ()))
+                    .help("How long to wait on the schedule before giving up (F5 tries again)"),
+            )
+            .arg(
+                Arg::with_name("max-downloads")
+                    .long("max-downloads")
+                    .takes_value(true)
+                    .value_name("COUNT")
+                    .default_value("4")
+                    .validator(|count| parse_count(&count).map(|_| ()))
+                    .help("How many photos to download at once"),
+            )
+            .arg(
+                Arg::with_name("image-budget-mb")
+                    .long("image-budget-mb")
+                    .takes_value(true)
+                    .value_name("MB")
+                    .default_value("128")
+                    .validator(|mb| parse_budget(&mb).map(|_| ()))
+                    .help("How much memory decoded photos may take up before off screen ones are let go"),
+            )
+            .arg(
+                Arg::with_name("no-restore")
+                    .long("no-restore")
+                    .help("Start out on today (or --date) rather than wherever the last run left off"),
+            )
+            .arg(
+                Arg::with_name("kiosk")
+                    .long("kiosk")
+                    .takes_value(true)
+                    .value_name("SECONDS")
+                    .validator(|seconds| parse_seconds(&seconds).map(|_| ()))
+                    .help("Move on to the next game every SECONDS, wrapping around at the end"),
+            )
+            .arg(
+                Arg::with_name("page-size")
+                    .long("page-size")
+                    .takes_value(true)
+                    .value_name("COUNT")
+                    .validator(|count| parse_count(&count).map(|_| ()))
+                    .help("How many games to show at once [default: as many as fit, 5 on most windows]"),
+            )
+            .arg(
+                Arg::with_name("export")
+                    .long("export")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .help("Write the schedule to FILE (CSV if it ends in .csv, JSON otherwise) and quit"),
+            )
+            .arg(
+                Arg::with_name("export-ics")
+                    .long("export-ics")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .help("Write the schedule's games to FILE as an iCalendar file and quit"),
+            )
+            .arg(
+                Arg::with_name("spoiler-free")
+                    .long("spoiler-free")
+                    .help("Hide scores and recaps until a game is revealed (toggle with S)"),
+            )
+            .arg(
+                Arg::with_name("favorite")
+                    .long("favorite")
+                    .takes_value(true)
+                    .multiple(true)
+                    .number_of_values(1)
+                    .value_name("TEAM")
+                    .help("A team (by id, name, or abbreviation) whose games go first"),
+            )
+            .arg(
+                Arg::with_name("sort")
+                    .long("sort")
+                    .takes_value(true)
+                    .possible_values(&["favorites", "time", "status"])
+                    .default_value("favorites")
+                    .help("Whether games go favorites first, by start time, or the ones being played first (cycle with A)"),
+            )
+            .arg(
+                Arg::with_name("transactions-team")
+                    .long("transactions-team")
+                    .takes_value(true)
+                    .value_name("ID")
+                    .validator(|id| parse_team_id(&id).map(|_| ()))
+                    .help("The team whose transactions to list (X) [default: the whole league]"),
+            )
+            .arg(
+                Arg::with_name("timezone")
+                    .long("timezone")
+                    .takes_value(true)
+                    .value_name("ZONE")
+                    .validator(|zone| zone.parse::<Tz>().map(|_| ()))
+                    .help("The timezone for start times, E.G. America/New_York [default: local]"),
+            )
+            .arg(
+                Arg::with_name("keymap")
+                    .long("keymap")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .help("A file of KEY = ACTION bindings [default: ~/.config/mlb/keymap]"),
+            )
+            .arg(
+                Arg::with_name("theme")
+                    .long("theme")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .help("A TOML file of colors and padding [default: ~/.config/mlb/theme.toml]"),
+            )
+            .arg(
+                Arg::with_name("background")
+                    .long("background")
+                    .takes_value(true)
+                    .value_name("FILE|#RRGGBB")
+                    .help("A photo (PNG, JPEG, and so on) or a solid color to put behind everything [default: the stadium]"),
+            )
+            .arg(
+                Arg::with_name("background-fit")
+                    .long("background-fit")
+                    .takes_value(true)
+                    .possible_values(&["cover", "letterbox"])
+                    .default_value("cover")
+                    .help("Whether the background photo is cropped to cover the window, or shrunk to fit inside of it"),
+            )
+            .arg(
+                Arg::with_name("no-backdrop")
+                    .long("no-backdrop")
+                    .help("Keep the background as it is rather than blurring the focused game's photo in behind the carousel"),
+            )
+            .arg(
+                Arg::with_name("reduced-motion")
+                    .long("reduced-motion")
+                    .help("Snap photos and backdrops into place rather than fading them in"),
+            )
+            .arg(
+                Arg::with_name("font")
+                    .long("font")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .validator(|path| Typeface::check(Path::new(&path)))
+                    .help("A TrueType or OpenType font to write everything in [default: the built in OpenSans]"),
+            )
+            .arg(
+                Arg::with_name("fallback-font")
+                    .long("fallback-font")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .validator(|path| Typeface::check(Path::new(&path)))
+                    .help("A font for the characters that the first one is missing, E.G. one with CJK"),
+            )
+            .arg(
+                Arg::with_name("language")
+                    .long("language")
+                    .takes_value(true)
+                    .value_name("LANGUAGE")
+                    .validator(|language| parse_language(&language).map(|_| ()))
+                    .help("The language to write everything in, either en or es [default: the system's, or en]"),
+            )
+            .arg(
+                Arg::with_name("log-file")
+                    .long("log-file")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .help("Log to this file instead of stderr. Set MLB_LOG=debug for more detail"),
+            )
+    }
+
+    // The validators have already had their crack at most values by the time we get here,
+    // so unwrapping those is just restating what clap already promised us. The files (the
+    // keymap, the theme, and the background) are read here instead, just the once, since
+    // reading them over again for a validator would be a waste (and a photo isn't cheap).
+    fn from_matches(matches: &ArgMatches) -> Result<Config, clap::Error> {
+        let mut theme = match matches.value_of("theme") {
+            Some(path) => Theme::load(Path::new(path)).map_err(|err| invalid("theme", err))?,
+            None => user_theme(),
+        };
+        let keymap = match matches.value_of("keymap") {
+            Some(path) => Keymap::load(Path::new(path)).map_err(|err| invalid("keymap", err))?,
+            None => user_keymap(),
+        };
+        // A color on the command line is just the theme's background color, which it beats.
+        let background = match matches.value_of("background") {
+            Some(background) => {
+                Some(parse_background(background).map_err(|err| invalid("background", err))?)
+            }
+            None => None,
+        };
+        let background = match background {
+            Some(Background::Color(color)) => {
+                theme.background = Some(color);
+                None
+            }
+            Some(Background::Photo(photo)) => Some(photo),
+            None => None,
+        };
+        let on_this_day = matches
+            .value_of("on-this-day")
+            .map(|season| Season::parse(season).unwrap());
+        let date = matches
+            .value_of("date")
+            .map(|date| parse_date(date).unwrap());
+        Ok(Config {
+            date: match on_this_day {
+                Some(season) => {
+                    Some(season.same_day(
+                        date.unwrap_or_else(|| chrono::Local::now().naive_local().date()),
+                    ))
+                }
+                None => date,
+            },
+            on_this_day,
+            days: parse_days(matches.value_of("days").unwrap()).unwrap(),
+            sport_id: Sport::parse(matches.value_of("sport-id").unwrap()).unwrap(),
+            resolution: matches
+                .value_of("resolution")
+                .map(|res| parse_resolution(res).unwrap()),
+            fullscreen: matches.is_present("fullscreen"),
+            borderless: matches.is_present("borderless"),
+            max_fps: parse_fps(matches.value_of("max-fps").unwrap()).unwrap(),
+            idle_fps: parse_fps(matches.value_of("idle-fps").unwrap()).unwrap(),
+            vsync: matches.is_present("vsync"),
+            refresh: parse_minutes(matches.value_of("refresh-minutes").unwrap()).unwrap(),
+            timeout: parse_seconds(matches.value_of("timeout-seconds").unwrap()).unwrap(),
+            max_downloads: parse_count(matches.value_of("max-downloads").unwrap()).unwrap(),
+            image_budget: parse_budget(matches.value_of("image-budget-mb").unwrap()).unwrap(),
+            restore: !matches.is_present("no-restore"),
+            kiosk: matches
+                .value_of("kiosk")
+                .map(|seconds| parse_seconds(seconds).unwrap()),
+            page_size: matches
+                .value_of("page-size")
+                .map(|count| parse_count(count).unwrap()),
+            export: matches.value_of("export").map(PathBuf::from),
+            export_ics: matches.value_of("export-ics").map(PathBuf::from),
+            keymap,
+            theme,
+            background,
+            background_fit: match matches.value_of("background-fit") {
+                Some("letterbox") => Fit::Letterbox,
+                _ => Fit::Cover,
+            },
+            backdrop: !matches.is_present("no-backdrop"),
+            reduced_motion: matches.is_present("reduced-motion"),
+            font: match matches.value_of("font") {
+                Some(path) => Typeface::load(Path::new(path)).unwrap(),
+                None => Typeface::embedded(),
+            },
+            fallback_font: matches
+                .value_of("fallback-font")
+                .map(|path| Typeface::load(Path::new(path)).unwrap()),
+            language: match matches.value_of("language") {
+                Some(language) => parse_language(language).unwrap(),
+                None => Language::system(),
+            },
+            spoiler_free: matches.is_present("spoiler-free"),
+            favorites: matches
+                .values_of("favorite")
+                .map(|teams| teams.map(str::to_string).collect())
+                .unwrap_or_default(),
+            sort: match matches.value_of("sort") {
+                Some("time") => Sort::Time,
+                Some("status") => Sort::Status,
+                _ => Sort::Favorites,
+            },
+            transactions_team: matches
+                .value_of("transactions-team")
+                .map(|id| parse_team_id(id).unwrap()),
+            timezone: matches
+                .value_of("timezone")
+                .map(|zone| zone.parse().unwrap()),
+        })
+    }
+
+    /// What to cap the frame rate at, given whether or not anything on screen is animating.
+    /// Idling is never any faster than animating, whatever the two were set to.
+    pub fn fps(&self, animating: bool) -> u64 {
+        match animating {
+            true => self.max_fps,
+            false => self.idle_fps.min(self.max_fps),
+        }
+    }
+}
+
+// Nobody has to have a keymap file, but a broken one is worth mentioning rather than
+// silently ignoring. It isn't worth refusing to start over, though.
+fn user_keymap() -> Keymap {
+    match Keymap::user() {
+        Some(path) if path.exists() => Keymap::load(&path).unwrap_or_else(|err| {
+            warn!(path = %path.display(), error = %err, "Ignoring the keymap");
+            Keymap::default()
+        }),
+        _ => Keymap::default(),
+    }
+}
+
+// Likewise for the theme.
+fn user_theme() -> Theme {
+    match Theme::user() {
+        Some(path) if path.exists() => Theme::load(&path).unwrap_or_else(|err| {
+            warn!(path = %path.display(), error = %err, "Ignoring the theme");
+            Theme::default()
+        }),
+        _ => Theme::default(),
+    }
+}
+
+fn parse_language(language: &str) -> Result<Language, String> {
+    Language::parse(language).ok_or_else(|| {
+        format!(
+            "{} isn't a language that there's a translation for, try en or es",
+            language
+        )
+    })
+}
+
+// The same error that clap gives for a value that its validator turned down.
+fn invalid(arg: &str, err: String) -> clap::Error {
+    clap::Error::with_description(
+        &format!("Invalid value for '--{}': {}", arg, err),
+        clap::ErrorKind::InvalidValue,
+    )
+}
+
+// What --background can be: a color to fill the window with, or a photo to cover it with.
+enum Background {
+    Color([f32; 4]),
+    Photo(RgbaImage),
+}
+
+fn parse_background(background: &str) -> Result<Background, String> {
+    if background.starts_with('#') {
+        return theme::parse_color(background).map(Background::Color);
+    }
+    // Nobody's screen needs more than this, and a photo straight off of a camera can be big
+    // enough that the graphics card won't take it as a texture.
+    const LARGEST: u32 = 4096;
+    let photo = image::open(background)
+        .map_err(|err| format!("failed to read {} ({})", background, err))?;
+    let photo = match photo.width() > LARGEST || photo.height() > LARGEST {
+        true => photo.resize(LARGEST, LARGEST, FilterType::Triangle),
+        false => photo,
+    };
+    Ok(Background::Photo(photo.to_rgba()))
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|err| format!("{} is not a valid YYYY-MM-DD date ({})", date, err))
+}
+
+fn parse_team_id(id: &str) -> Result<u32, String> {
+    id.parse::<u32>()
+        .map_err(|err| format!("{} is not a valid team ID ({})", id, err))
+}
+
+fn parse_minutes(minutes: &str) -> Result<Duration, String> {
+    match minutes.parse::<u64>() {
+        Ok(minutes) if minutes > 0 => Ok(Duration::from_secs(minutes * 60)),
+        _ => Err(format!("{} is not a valid number of minutes", minutes)),
+    }
+}
+
+fn parse_seconds(seconds: &str) -> Result<Duration, String> {
+    match seconds.parse::<u64>() {
+        Ok(seconds) if seconds > 0 => Ok(Duration::from_secs(seconds)),
+        _ => Err(format!("{} is not a valid number of seconds", seconds)),
+    }
+}
+
+fn parse_fps(fps: &str) -> Result<u64, String> {
+    match fps.parse::<u64>() {
+        Ok(fps) if fps > 0 => Ok(fps),
+        _ => Err(format!(
+            "{} is not a valid frame rate, it must be at least 1",
+            fps
+        )),
+    }
+}
+
+fn parse_count(count: &str) -> Result<usize, String> {
+    match count.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(format!(
+            "{} is not a valid count, it must be at least 1",
+            count
+        )),
+    }
+}
+
+// More than a month at once is more rows than anybody could make sense of (and enough of
+// them to run the date math off the end of the calendar).
+const MAX_DAYS: u32 = 31;
+
+fn parse_days(days: &str) -> Result<u32, String> {
+    match u32::try_from(parse_count(days)?) {
+        Ok(days) if days <= MAX_DAYS => Ok(days),
+        _ => Err(format!(
+            "{} is too many days, it can be at most {}",
+            days, MAX_DAYS
+        )),
+    }
+}
+
+fn parse_budget(mb: &str) -> Result<usize, String> {
+    parse_count(mb)?
+        .checked_mul(1024 * 1024)
+        .ok_or_else(|| format!("{} MB is more memory than there is", mb))
+}
+
+fn parse_resolution(res: &str) -> Result<[u32; 2], String> {
+    let err = || {
+        format!(
+            "{} is not a valid resolution, try something like 1280x720",
+            res
+        )
+    };
+    let mut dimensions = res.splitn(2, ['x', 'X']);
+    let width = dimensions.next().ok_or_else(err)?;
+    let height = dimensions.next().ok_or_else(err)?;
+    match (width.trim().parse::<u32>(), height.trim().parse::<u32>()) {
+        (Ok(width), Ok(height)) if width > 0 && height > 0 => Ok([width, height]),
+        _ => Err(err()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        let config = Config::from_matches(&Config::app().get_matches_from(vec!["mlb"])).unwrap();
+        assert_eq!(config.date, None);
+        assert_eq!(config.on_this_day, None);
+        assert_eq!(config.days, 1);
+        assert_eq!(config.sport_id, 1);
+        assert_eq!(config.resolution, None);
+        assert!(!config.fullscreen);
+        assert!(!config.borderless);
+        assert_eq!(config.max_fps, 60);
+        assert_eq!(config.idle_fps, 10);
+        assert!(!config.vsync);
+        assert_eq!(config.fps(true), 60);
+        assert_eq!(config.fps(false), 10);
+        assert_eq!(config.refresh, Duration::from_secs(300));
+        assert_eq!(config.timeout, Duration::from_secs(10));
+        assert_eq!(config.max_downloads, 4);
+        assert_eq!(config.image_budget, 128 * 1024 * 1024);
+        assert!(config.restore);
+        assert_eq!(config.kiosk, None);
+        assert_eq!(config.page_size, None);
+        assert_eq!(config.font.bytes.len(), Typeface::embedded().bytes.len());
+        assert!(config.fallback_font.is_none());
+        assert!(config.background.is_none());
+        assert_eq!(config.background_fit, Fit::Cover);
+        assert!(config.backdrop);
+        assert!(!config.reduced_motion);
+        assert_eq!(config.export, None);
+        assert_eq!(config.export_ics, None);
+        assert!(!config.spoiler_free);
+        assert!(config.favorites.is_empty());
+        assert_eq!(config.sort, Sort::Favorites);
+        assert_eq!(config.transactions_team, None);
+        assert_eq!(config.timezone, None);
+    }
+
+    #[test]
+    fn on_this_day() {
+        let config = Config::from_matches(&Config::app().get_matches_from(vec![
+            "mlb",
+            "--date",
+            "2019-07-04",
+            "--on-this-day",
+            "2004",
+        ]))
+        .unwrap();
+        assert_eq!(config.on_this_day, Some(Season::Year(2004)));
+        assert_eq!(
+            config.date,
+            Some(NaiveDate::from_ymd_opt(2004, 7, 4).unwrap())
+        );
+    }
+
+    #[test]
+    fn everything() {
+        let config = Config::from_matches(&Config::app().get_matches_from(vec![
+            "mlb",
+            "--date",
+            "2019-07-04",
+            "--days",
+            "3",
+            "--sport-id",
+            "AAA",
+            "--resolution",
+            "1280x720",
+            "--fullscreen",
+            "--borderless",
+            "--max-fps",
+            "30",
+            "--idle-fps",
+            "45",
+            "--vsync",
+            "--reduced-motion",
+            "--language",
+            "es_MX",
+            "--spoiler-free",
+            "--timezone",
+            "America/Chicago",
+            "--favorite",
+            "BOS",
+            "--favorite",
+            "Chicago Cubs",
+            "--sort",
+            "status",
+            "--transactions-team",
+            "111",
+            "--max-downloads",
+            "8",
+            "--image-budget-mb",
+            "64",
+            "--no-restore",
+            "--kiosk",
+            "15",
+            "--page-size",
+            "3",
+            "--export",
+            "games.csv",
+            "--export-ics",
+            "games.ics",
+            "--timeout-seconds",
+            "30",
+        ]))
+        .unwrap();
+        assert_eq!(
+            config.date,
+            Some(NaiveDate::from_ymd_opt(2019, 7, 4).unwrap())
+        );
+        assert_eq!(config.days, 3);
+        assert_eq!(config.sport_id, 11);
+        assert_eq!(config.resolution, Some([1280, 720]));
+        assert!(config.fullscreen);
+        assert!(config.borderless);
+        assert_eq!(config.max_fps, 30);
+        assert!(config.vsync);
+        assert_eq!(config.fps(false), 30);
+        assert!(config.reduced_motion);
+        assert_eq!(config.language, Language::Spanish);
+        assert!(config.spoiler_free);
+        assert_eq!(config.favorites, ["BOS", "Chicago Cubs"]);
+        assert_eq!(config.sort, Sort::Status);
+        assert_eq!(config.transactions_team, Some(111));
+        assert_eq!(config.timezone, Some(chrono_tz::America::Chicago));
+        assert_eq!(config.max_downloads, 8);
+        assert_eq!(config.image_budget, 64 * 1024 * 1024);
+        assert!(!config.restore);
+        assert_eq!(config.kiosk, Some(Duration::from_secs(15)));
+        assert_eq!(config.page_size, Some(3));
+        assert_eq!(config.export, Some(PathBuf::from("games.csv")));
+        assert_eq!(config.export_ics, Some(PathBuf::from("games.ics")));
+        assert_eq!(config.timeout, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn keymaps() {
+        let path = std::env::temp_dir().join(format!("mlb-keymap-{}", std::process::id()));
+        std::fs::write(&path, "h = left\n").unwrap();
+        let config = Config::from_matches(&Config::app().get_matches_from(vec![
+            "mlb",
+            "--keymap",
+            path.to_str().unwrap(),
+        ]))
+        .unwrap();
+        assert_eq!(config.keymap.action("H"), Some(crate::input::Action::Left));
+        std::fs::write(&path, "h = sideways\n").unwrap();
+        let bad = Config::app()
+            .get_matches_from_safe(vec!["mlb", "--keymap", path.to_str().unwrap()])
+            .and_then(|matches| Config::from_matches(&matches));
+        assert!(bad.is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn themes() {
+        let path = std::env::temp_dir().join(format!("mlb-theme-{}.toml", std::process::id()));
+        std::fs::write(&path, "accent = \"#bd3039\"\n").unwrap();
+        let config = Config::from_matches(&Config::app().get_matches_from(vec![
+            "mlb",
+            "--theme",
+            path.to_str().unwrap(),
+        ]))
+        .unwrap();
+        assert_eq!(config.theme.accent[0], 189.0 / 255.0);
+        std::fs::write(&path, "accent = 7\n").unwrap();
+        let bad = Config::app()
+            .get_matches_from_safe(vec!["mlb", "--theme", path.to_str().unwrap()])
+            .and_then(|matches| Config::from_matches(&matches));
+        assert!(bad.is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn backgrounds() {
+        let path = std::env::temp_dir().join(format!("mlb-background-{}.png", std::process::id()));
+        RgbaImage::new(5000, 100).save(&path).unwrap();
+        let config = Config::from_matches(&Config::app().get_matches_from(vec![
+            "mlb",
+            "--background",
+            path.to_str().unwrap(),
+            "--background-fit",
+            "letterbox",
+            "--no-backdrop",
+        ]))
+        .unwrap();
+        // Shrunk down to a size that any graphics card can take, and no more.
+        assert_eq!(config.background.unwrap().dimensions(), (4096, 81));
+        assert_eq!(config.background_fit, Fit::Letterbox);
+        assert!(!config.backdrop);
+        std::fs::remove_file(&path).unwrap();
+        let config = Config::from_matches(&Config::app().get_matches_from(vec![
+            "mlb",
+            "--background",
+            "#000000",
+        ]))
+        .unwrap();
+        assert_eq!(config.theme.background, Some([0.0, 0.0, 0.0, 1.0]));
+        assert!(config.background.is_none());
+        let missing = Config::app()
+            .get_matches_from_safe(vec!["mlb", "--background", "nope.jpg"])
+            .and_then(|matches| Config::from_matches(&matches));
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn bad_days() {
+        assert_eq!(parse_days("31"), Ok(31));
+        assert!(parse_days("32").is_err());
+        assert!(parse_days("4294967297").is_err());
+        assert!(Config::app()
+            .get_matches_from_safe(vec!["mlb", "--days", "0"])
+            .is_err());
+    }
+
+    #[test]
+    fn bad_budgets() {
+        assert!(parse_budget(&usize::MAX.to_string()).is_err());
+        assert!(Config::app()
+            .get_matches_from_safe(vec!["mlb", "--image-budget-mb", "0"])
+            .is_err());
+    }
+
+    #[test]
+    fn bad_resolutions() {
+        assert!(parse_resolution("1280").is_err());
+        assert!(parse_resolution("1280x").is_err());
+        assert!(parse_resolution("0x720").is_err());
+        assert!(parse_resolution("widexhigh").is_err());
+    }
+
+    #[test]
+    fn bad_languages() {
+        assert!(parse_language("fr").is_err());
+        assert!(Config::app()
+            .get_matches_from_safe(vec!["mlb", "--language", "klingon"])
+            .is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::api;
+use chrono::{DateTime, Utc};
+
+// The API says when a game starts but not when it ends, and a calendar wants both. Three
+// hours covers most nine inning games, if not the extra inning marathons.
+const LENGTH: i64 = 3;
+
+/// The given schedule as an iCalendar (RFC 5545) file, with an event for every game that
+/// has a start time: the matchup as its summary and the ballpark as its location.
+///
+/// Start times go in as UTC, which every calendar shows in whatever timezone it is set to.
+/// So they come out local to whoever imports them, without the file having to spell out
+/// the rules of any timezone itself. The given time is when the file was made, which every
+/// event has to say.
+pub fn calendar(schedule: &api::Schedule, now: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//mlb//schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    let games = schedule
+        .dates
+        .iter()
+        .flat_map(|date| date.games.iter().map(move |game| (&date.date, game)));
+    for (date, game) in games {
+        let start = match game
+            .game_date
+            .as_deref()
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+        {
+            Some(start) => start.with_timezone(&Utc),
+            None => continue,
+        };
+        let end = start + chrono::Duration::hours(LENGTH);
+        lines.push("BEGIN:VEVENT".to_string());
+        // A postponed game keeps its primary key when it's made up on some other date, and
+        // the schedule lists it on both. Each of them gets an event of its own, so the date
+        // goes into the UID too, or else a calendar would take the one for the other.
+        lines.push(format!(
+            "UID:{}-{}@statsapi.mlb.com",
+            game.game_pk,
+            date.replace('-', "")
+        ));
+        lines.push(format!("DTSTAMP:{}", timestamp(now)));
+        lines.push(format!("DTSTART:{}", timestamp(start)));
+        lines.push(format!("DTEND:{}", timestamp(end)));
+        lines.push(format!(
+            "SUMMARY:{}",
+            escape(&format!(
+                "{} at {}",
+                game.teams.away.team.name, game.teams.home.team.name
+            ))
+        ));
+        if let Some(venue) = &game.venue {
+            lines.push(format!("LOCATION:{}", escape(&venue.name)));
+        }
+        // A game that isn't going to happen on this date (or at all) is still worth knowing
+        // about, but it oughtn't to look like it's on.
+        let status = game.status.detailed_state.as_str();
+        if status.starts_with("Postponed") || status.starts_with("Cancelled") {
+            lines.push("STATUS:CANCELLED".to_string());
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+// E.G. 20180610T170500Z.
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+// Text values can't have their commas, semicolons, backslashes, or line breaks bare.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// Lines are meant to be no longer than 75 bytes, with anything past that carried on to the
+// next line after a leading space. Every line ends in CRLF, folded or not.
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_DATA: &str = include_str!("../api/test.json");
+
+    #[test]
+    fn one_event_per_game() {
+        let schedule: api::Schedule = serde_json::from_str(TEST_DATA).unwrap();
+        let now = DateTime::parse_from_rfc3339("2018-06-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let ics = calendar(&schedule, now);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(
+            ics.matches("BEGIN:VEVENT").count(),
+            schedule.dates[0].games.len()
+        );
+        assert!(ics.contains("DTSTART:20180610T170500Z\r\nDTEND:20180610T200500Z\r\n"));
+        assert!(ics.contains("LOCATION:Fenway Park\r\n"));
+        assert!(ics.contains("DTSTAMP:20180601T120000Z\r\n"));
+    }
+
+    #[test]
+    fn postponed_games_keep_both_events() {
+        let schedule: api::Schedule = serde_json::from_str(
+            r#"{"copyright": "", "dates": [
+                {"date": "2019-09-25", "games": [{
+                    "gamePk": 2,
+                    "gameDate": "2019-09-25T23:10:00Z",
+                    "status": {"abstractGameState": "Preview", "detailedState": "Postponed"},
+                    "teams": {
+                        "away": {"team": {"id": 147, "name": "New York Yankees"}},
+                        "home": {"team": {"id": 111, "name": "Boston Red Sox"}}
+                    }
+                }]},
+                {"date": "2019-09-26", "games": [{
+                    "gamePk": 2,
+                    "gameDate": "2019-09-26T17:05:00Z",
+                    "status": {"abstractGameState": "Preview", "detailedState": "Scheduled"},
+                    "teams": {
+                        "away": {"team": {"id": 147, "name": "New York Yankees"}},
+                        "home": {"team": {"id": 111, "name": "Boston Red Sox"}}
+                    }
+                }]}
+            ]}"#,
+        )
+        .unwrap();
+        let ics = calendar(&schedule, Utc::now());
+        assert!(ics.contains("UID:2-20190925@statsapi.mlb.com\r\nDTSTAMP"));
+        assert!(ics.contains("UID:2-20190926@statsapi.mlb.com\r\nDTSTAMP"));
+        assert_eq!(ics.matches("STATUS:CANCELLED").count(), 1);
+    }
+
+    #[test]
+    fn escaping_and_folding() {
+        assert_eq!(escape("Hits, runs; errors\\"), "Hits\\, runs\\; errors\\\\");
+        let long = "x".repeat(100);
+        let folded = fold(&long);
+        let lines: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(lines[0].len(), 75);
+        assert_eq!(lines[1], format!(" {}", "x".repeat(25)));
+        assert_eq!(fold("short"), "short\r\n");
+    }
+}
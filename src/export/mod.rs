@@ -0,0 +1,223 @@
+use crate::api;
+use serde::Serialize;
+use std::path::Path;
+
+pub mod ics;
+
+/// One game's worth of a schedule, flattened out for whatever else wants a crack at it
+/// (a spreadsheet, a notebook, jq...). Everything here is as the API gave it, so the start
+/// time is in UTC and nothing is kept under wraps for the sake of spoilers.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Entry {
+    /// E.G. "2018-06-10".
+    pub date: String,
+    pub game_pk: u64,
+    /// When the first pitch is (or was) scheduled for, E.G. "2018-06-10T17:05:00Z".
+    pub start: Option<String>,
+    /// E.G. "Final" or "Postponed".
+    pub status: String,
+    pub away: String,
+    pub away_abbreviation: Option<String>,
+    pub away_score: Option<u32>,
+    pub home: String,
+    pub home_abbreviation: Option<String>,
+    pub home_score: Option<u32>,
+    pub winner: Option<String>,
+    pub loser: Option<String>,
+    pub headline: Option<String>,
+    pub subhead: Option<String>,
+    pub large_photo: Option<String>,
+    pub small_photo: Option<String>,
+}
+
+impl Entry {
+    // The header of a CSV export, in the same order as fields().
+    const COLUMNS: [&'static str; 16] = [
+        "date",
+        "game_pk",
+        "start",
+        "status",
+        "away",
+        "away_abbreviation",
+        "away_score",
+        "home",
+        "home_abbreviation",
+        "home_score",
+        "winner",
+        "loser",
+        "headline",
+        "subhead",
+        "large_photo",
+        "small_photo",
+    ];
+
+    pub fn new(date: &str, game: &api::Game) -> Entry {
+        let article = game.article();
+        let cuts = article
+            .and_then(|article| article.photo.as_ref())
+            .map(|photo| &photo.cuts);
+        let decisions = game.decisions.as_ref();
+        let (away, home) = (&game.teams.away, &game.teams.home);
+        Entry {
+            date: date.to_string(),
+            game_pk: game.game_pk,
+            start: game.game_date.clone(),
+            status: game.status.detailed_state.clone(),
+            away: away.team.name.clone(),
+            away_abbreviation: away.team.abbreviation.clone(),
+            away_score: away.score,
+            home: home.team.name.clone(),
+            home_abbreviation: home.team.abbreviation.clone(),
+            home_score: home.score,
+            winner: decisions
+                .and_then(|d| d.winner.as_ref())
+                .map(|p| p.full_name.clone()),
+            loser: decisions
+                .and_then(|d| d.loser.as_ref())
+                .map(|p| p.full_name.clone()),
+            headline: article.map(|article| article.headline.clone()),
+            subhead: article
+                .map(|article| article.subhead.clone())
+                .filter(|subhead| !subhead.is_empty()),
+            large_photo: cuts
+                .and_then(|cuts| cuts.large.as_ref())
+                .map(|photo| photo.src.clone()),
+            small_photo: cuts
+                .and_then(|cuts| cuts.small.as_ref())
+                .map(|photo| photo.src.clone()),
+        }
+    }
+
+    // Every field as text, with anything missing left blank.
+    fn fields(&self) -> Vec<String> {
+        let text = |field: &Option<String>| field.clone().unwrap_or_default();
+        let number = |field: Option<u32>| field.map(|n| n.to_string()).unwrap_or_default();
+        vec![
+            self.date.clone(),
+            self.game_pk.to_string(),
+            text(&self.start),
+            self.status.clone(),
+            self.away.clone(),
+            text(&self.away_abbreviation),
+            number(self.away_score),
+            self.home.clone(),
+            text(&self.home_abbreviation),
+            number(self.home_score),
+            text(&self.winner),
+            text(&self.loser),
+            text(&self.headline),
+            text(&self.subhead),
+            text(&self.large_photo),
+            text(&self.small_photo),
+        ]
+    }
+}
+
+/// Every game in the given schedule, date by date.
+pub fn entries(schedule: &api::Schedule) -> Vec<Entry> {
+    schedule
+        .dates
+        .iter()
+        .flat_map(|date| {
+            date.games
+                .iter()
+                .map(move |game| Entry::new(&date.date, game))
+        })
+        .collect()
+}
+
+/// What an export gets written out as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// An array of objects, one per game, with the same names as the fields of Entry.
+    Json,
+    /// A header and then one line per game, in the same order as the fields of Entry.
+    Csv,
+}
+
+impl Format {
+    /// Goes by the extension of the given path. Anything that isn't ".csv" gets JSON.
+    pub fn of(path: &Path) -> Format {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("csv") => Format::Csv,
+            _ => Format::Json,
+        }
+    }
+
+    /// The given entries, written out in this format.
+    pub fn render(self, entries: &[Entry]) -> String {
+        match self {
+            // There is nothing in an Entry that can fail to serialize.
+            Format::Json => serde_json::to_string_pretty(entries).unwrap() + "\n",
+            Format::Csv => {
+                let mut csv = csv_line(Entry::COLUMNS.iter().map(|column| column.to_string()));
+                for entry in entries {
+                    csv.push_str(&csv_line(entry.fields().into_iter()));
+                }
+                csv
+            }
+        }
+    }
+}
+
+/// Writes the given entries to the given path, in whichever format its extension calls for.
+pub fn write(entries: &[Entry], path: &Path) -> std::io::Result<()> {
+    std::fs::write(path, Format::of(path).render(entries))
+}
+
+// One line of CSV, per RFC 4180. Headlines are full of commas and the odd quotation, so
+// anything with either (or a line break) is quoted, with its quotes doubled up.
+fn csv_line<I: Iterator<Item = String>>(fields: I) -> String {
+    let quoted: Vec<String> = fields
+        .map(|field| match field.contains(&[',', '"', '\n', '\r'][..]) {
+            true => format!("\"{}\"", field.replace('"', "\"\"")),
+            false => field,
+        })
+        .collect();
+    quoted.join(",") + "\r\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_DATA: &str = include_str!("../api/test.json");
+
+    #[test]
+    fn entries_from_the_schedule() {
+        let schedule: api::Schedule = serde_json::from_str(TEST_DATA).unwrap();
+        let entries = entries(&schedule);
+        assert_eq!(entries.len(), schedule.dates[0].games.len());
+        let entry = &entries[0];
+        assert_eq!(entry.date, schedule.dates[0].date);
+        assert_eq!(entry.game_pk, schedule.dates[0].games[0].game_pk);
+        assert!(entry.headline.is_some());
+        assert!(entry.large_photo.is_some());
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Format::of(Path::new("games.CSV")), Format::Csv);
+        assert_eq!(Format::of(Path::new("games.json")), Format::Json);
+        assert_eq!(Format::of(Path::new("games")), Format::Json);
+        let schedule: api::Schedule = serde_json::from_str(TEST_DATA).unwrap();
+        let entries = entries(&schedule);
+        let json: Vec<serde_json::Value> =
+            serde_json::from_str(&Format::Json.render(&entries)).unwrap();
+        assert_eq!(json.len(), entries.len());
+        assert_eq!(json[0]["game_pk"], entries[0].game_pk);
+        let csv = Format::Csv.render(&entries);
+        assert!(csv.starts_with("date,game_pk,start,status,"));
+        assert_eq!(csv.lines().count(), entries.len() + 1);
+    }
+
+    #[test]
+    fn csv_quoting() {
+        let line = csv_line(
+            vec!["plain", "a, b", "the \"Big Hurt\""]
+                .into_iter()
+                .map(str::to_string),
+        );
+        assert_eq!(line, "plain,\"a, b\",\"the \"\"Big Hurt\"\"\"\r\n");
+    }
+}
@@ -0,0 +1,77 @@
+use rusttype::Font;
+use std::path::Path;
+
+// The font that everything is written in unless somebody says otherwise.
+static OPEN_SANS: &[u8] = include_bytes!("../../assets/OpenSans-Bold.ttf");
+
+/// A font, read in once and held on to for the rest of the run. Every window that is built
+/// needs its glyphs made all over again, and that shouldn't mean going back to the disk.
+#[derive(Clone)]
+pub struct Typeface {
+    /// The font file itself, which is what the glyph caches are made from.
+    pub bytes: &'static [u8],
+    // The same, parsed, for asking which characters it has.
+    font: Font<'static>,
+}
+
+impl Typeface {
+    /// The blocky, MLB-ish sans serif that comes built in.
+    pub fn embedded() -> Typeface {
+        Self::parse(OPEN_SANS).unwrap()
+    }
+
+    /// Reads the font file (TrueType or OpenType) at the given path. Like everything else that
+    /// is read in at launch time, it's never let go of.
+    pub fn load(path: &Path) -> Result<Typeface, String> {
+        let bytes = Self::read(path)?;
+        Self::parse(Box::leak(bytes.into_boxed_slice()))
+            .map_err(|err| format!("{}, {}", path.display(), err))
+    }
+
+    /// Whether or not the font file at the given path is one that load would take, without
+    /// holding on to it.
+    pub fn check(path: &Path) -> Result<(), String> {
+        let bytes = Self::read(path)?;
+        Font::from_bytes(bytes)
+            .map(|_| ())
+            .map_err(|err| format!("{}, {}", path.display(), err))
+    }
+
+    /// Whether or not the font has a glyph for the given character, rather than the empty box
+    /// (".notdef") that fonts draw for everything that they don't.
+    pub fn covers(&self, ch: char) -> bool {
+        self.font.glyph(ch).id().0 != 0
+    }
+
+    fn read(path: &Path) -> Result<Vec<u8>, String> {
+        std::fs::read(path).map_err(|err| format!("failed to read {} ({})", path.display(), err))
+    }
+
+    fn parse(bytes: &'static [u8]) -> Result<Typeface, String> {
+        let font = Font::from_bytes(bytes).map_err(|err| format!("not a font ({})", err))?;
+        Ok(Typeface { bytes, font })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coverage() {
+        let open_sans = Typeface::embedded();
+        assert!(open_sans.covers('A'));
+        assert!(open_sans.covers('é'));
+        assert!(!open_sans.covers('野'));
+    }
+
+    #[test]
+    fn not_fonts() {
+        let path = std::env::temp_dir().join(format!("mlb-font-{}.ttf", std::process::id()));
+        std::fs::write(&path, "not a font").unwrap();
+        assert!(Typeface::check(&path).is_err());
+        assert!(Typeface::load(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+        assert!(Typeface::check(&path).is_err());
+    }
+}
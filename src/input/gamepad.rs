@@ -0,0 +1,59 @@
+use super::{Action, Input};
+use gilrs::{Button, EventType, Gilrs};
+use tracing::warn;
+
+/// Every controller plugged into the machine, by way of gilrs. Any one of them will do.
+pub struct Gamepads(Gilrs);
+
+impl Gamepads {
+    /// None if there is no getting at controllers on this platform (or this machine), which is
+    /// logged and then otherwise shrugged off. There's always the keyboard.
+    pub fn new() -> Option<Gamepads> {
+        match Gilrs::new() {
+            Ok(gilrs) => Some(Gamepads(gilrs)),
+            Err(err) => {
+                warn!(error = %err, "Controllers are unavailable");
+                None
+            }
+        }
+    }
+
+    /// Every press and release since the last time that this was called. Controllers have
+    /// repeat of their own, much like keyboards do, which is ignored for the same reasons.
+    pub fn poll(&mut self) -> Vec<Input> {
+        let mut inputs = vec![];
+        while let Some(event) = self.0.next_event() {
+            match event.event {
+                EventType::ButtonPressed(button, _) => {
+                    inputs.extend(action(button).map(Input::Press))
+                }
+                EventType::ButtonReleased(button, _) => {
+                    inputs.extend(action(button).map(Input::Release))
+                }
+                _ => (),
+            }
+        }
+        inputs
+    }
+}
+
+// What each button on the controller does. South, East, North, and West are A, B, Y, and X on
+// an Xbox controller (and X, circle, triangle, and square on a PlayStation one), and the
+// triggers are the bumpers up top.
+fn action(button: Button) -> Option<Action> {
+    Some(match button {
+        Button::DPadLeft => Action::Left,
+        Button::DPadRight => Action::Right,
+        Button::DPadUp => Action::Up,
+        Button::DPadDown => Action::Down,
+        Button::LeftTrigger => Action::PreviousPage,
+        Button::RightTrigger => Action::NextPage,
+        Button::South => Action::Select,
+        Button::East => Action::Back,
+        Button::North => Action::Reveal,
+        Button::West => Action::Spoilers,
+        Button::Select => Action::Today,
+        Button::Start => Action::Refresh,
+        _ => return None,
+    })
+}
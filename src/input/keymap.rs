@@ -0,0 +1,234 @@
+use super::Action;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Which key does what.
+///
+/// Keys go by the names that Piston gives them (E.G. "Left", "Return", "PageUp", "H", "F5"), and
+/// actions by the names in Action::from_str, both without regard to case. A keymap file is one
+/// binding per line on top of the defaults, like so:
+///
+/// ```text
+/// # Vim, but for baseball.
+/// h = left
+/// l = right
+/// k = up
+/// j = down
+/// # And nothing for the arrow keys.
+/// left = none
+/// right = none
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keymap(HashMap<String, Action>);
+
+impl Keymap {
+    /// The defaults with the given file's bindings laid over top of them.
+    pub fn parse(text: &str) -> Result<Keymap, String> {
+        let mut keymap = Keymap::default();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut binding = line.splitn(2, '=').map(str::trim);
+            match (binding.next(), binding.next()) {
+                (Some(key), Some(action)) if !key.is_empty() => {
+                    let key = key.to_lowercase();
+                    if action.eq_ignore_ascii_case("none") {
+                        keymap.0.remove(&key);
+                        continue;
+                    }
+                    let action = action
+                        .parse()
+                        .map_err(|err| format!("line {}: {}", number + 1, err))?;
+                    keymap.0.insert(key, action);
+                }
+                _ => {
+                    return Err(format!(
+                        "line {}: expected KEY = ACTION, but found {}",
+                        number + 1,
+                        line
+                    ))
+                }
+            }
+        }
+        Ok(keymap)
+    }
+
+    /// Reads and parses the keymap file at the given path.
+    pub fn load(path: &Path) -> Result<Keymap, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {} ({})", path.display(), err))?;
+        Self::parse(&text).map_err(|err| format!("{}, {}", path.display(), err))
+    }
+
+    /// Where the keymap lives when nobody says otherwise, E.G. ~/.config/mlb/keymap.
+    pub fn user() -> Option<PathBuf> {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .map(|root| root.join("mlb").join("keymap"))
+    }
+
+    /// What the key with the given name does, if anything.
+    pub fn action(&self, key: &str) -> Option<Action> {
+        self.0.get(&key.to_lowercase()).copied()
+    }
+
+    /// The name of a key that does the given action, for telling the user which one to press.
+    /// Should more than one do it then the one that comes first alphabetically wins, so that
+    /// it's the same one every time.
+    pub fn key(&self, action: Action) -> Option<String> {
+        let key = self
+            .0
+            .iter()
+            .filter(|(_, bound)| **bound == action)
+            .map(|(key, _)| key)
+            .min()?;
+        let mut chars = key.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = [
+            ("left", Action::Left),
+            ("right", Action::Right),
+            ("up", Action::Up),
+            ("down", Action::Down),
+            ("pageup", Action::PreviousPage),
+            ("pagedown", Action::NextPage),
+            ("home", Action::First),
+            ("end", Action::Last),
+            ("return", Action::Select),
+            ("escape", Action::Back),
+            ("t", Action::Today),
+            ("h", Action::History),
+            ("f5", Action::Refresh),
+            ("f11", Action::Fullscreen),
+            ("s", Action::Spoilers),
+            ("r", Action::Reveal),
+            ("f", Action::Filter),
+            ("d", Action::Progress),
+            ("a", Action::Sort),
+            ("slash", Action::Search),
+            ("g", Action::GoTo),
+            ("o", Action::Story),
+            ("n", Action::Condensed),
+            ("v", Action::Recap),
+            ("b", Action::Team),
+            ("x", Action::Transactions),
+            ("l", Action::Sport),
+            ("w", Action::Week),
+            ("c", Action::Calendar),
+            ("e", Action::Export),
+            ("f3", Action::Debug),
+        ];
+        Keymap(
+            bindings
+                .iter()
+                .map(|(key, action)| (key.to_string(), *action))
+                .collect(),
+        )
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Ok(match name.to_lowercase().as_str() {
+            "left" => Action::Left,
+            "right" => Action::Right,
+            "up" => Action::Up,
+            "down" => Action::Down,
+            "previous-page" => Action::PreviousPage,
+            "next-page" => Action::NextPage,
+            "first" => Action::First,
+            "last" => Action::Last,
+            "select" => Action::Select,
+            "back" => Action::Back,
+            "today" => Action::Today,
+            "refresh" => Action::Refresh,
+            "fullscreen" => Action::Fullscreen,
+            "spoilers" => Action::Spoilers,
+            "reveal" => Action::Reveal,
+            "filter" => Action::Filter,
+            "search" => Action::Search,
+            "story" => Action::Story,
+            "condensed" => Action::Condensed,
+            "recap" => Action::Recap,
+            "team" => Action::Team,
+            "transactions" => Action::Transactions,
+            "sport" => Action::Sport,
+            "week" => Action::Week,
+            "calendar" => Action::Calendar,
+            "goto" => Action::GoTo,
+            "history" => Action::History,
+            "progress" => Action::Progress,
+            "sort" => Action::Sort,
+            "export" => Action::Export,
+            "debug" => Action::Debug,
+            _ => return Err(format!("{} is not something that a key can do", name)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.action("Left"), Some(Action::Left));
+        assert_eq!(keymap.action("PageDown"), Some(Action::NextPage));
+        assert_eq!(keymap.action("Q"), None);
+        assert_eq!(keymap.key(Action::Story), Some("O".to_string()));
+        assert_eq!(keymap.action("n"), Some(Action::Condensed));
+        assert_eq!(keymap.action("c"), Some(Action::Calendar));
+        assert_eq!(keymap.key(Action::Recap), Some("V".to_string()));
+        assert_eq!(keymap.action("b"), Some(Action::Team));
+        assert_eq!(keymap.key(Action::Transactions), Some("X".to_string()));
+        assert_eq!(keymap.action("L"), Some(Action::Sport));
+        assert_eq!(keymap.key(Action::Week), Some("W".to_string()));
+        assert_eq!(keymap.action("g"), Some(Action::GoTo));
+        assert_eq!(keymap.key(Action::History), Some("H".to_string()));
+        assert_eq!(keymap.action("D"), Some(Action::Progress));
+        assert_eq!(keymap.key(Action::Sort), Some("A".to_string()));
+        assert_eq!(keymap.key(Action::NextPage), Some("Pagedown".to_string()));
+    }
+
+    #[test]
+    fn overrides() {
+        let keymap = Keymap::parse(
+            "# Vim, but for baseball.\n\
+             h = left\n\
+             L=Right\n\
+             \n\
+             left = none\n",
+        )
+        .unwrap();
+        assert_eq!(keymap.action("H"), Some(Action::Left));
+        assert_eq!(keymap.action("l"), Some(Action::Right));
+        assert_eq!(keymap.action("Left"), None);
+        // Everything that wasn't mentioned stays put.
+        assert_eq!(keymap.action("Return"), Some(Action::Select));
+        assert_eq!(keymap.key(Action::Left), Some("H".to_string()));
+    }
+
+    #[test]
+    fn bad_lines() {
+        assert_eq!(
+            Keymap::parse("h = left\nj = sideways").unwrap_err(),
+            "line 2: sideways is not something that a key can do"
+        );
+        assert!(Keymap::parse("h left").unwrap_err().starts_with("line 1"));
+        assert!(Keymap::parse("= left").is_err());
+    }
+}
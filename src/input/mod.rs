@@ -0,0 +1,388 @@
+use std::time::{Duration, Instant};
+
+pub mod gamepad;
+mod keymap;
+pub mod window;
+
+pub use keymap::Keymap;
+
+/// Everything that the user can ask of us, no matter what they asked with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Left,
+    Right,
+    Up,
+    Down,
+    PreviousPage,
+    NextPage,
+    First,
+    Last,
+    /// Opens up the details of the focused game.
+    Select,
+    /// Backs out of whatever is on screen, which means quitting if there is nothing left.
+    Back,
+    /// Goes back to whatever is on today.
+    Today,
+    /// Goes back to today's date in a past season, the one given with --on-this-day or else a
+    /// random one.
+    History,
+    Refresh,
+    Fullscreen,
+    /// Flips spoiler-free mode on or off.
+    Spoilers,
+    /// Shows how the focused game went, spoiler-free mode or not.
+    Reveal,
+    /// Restricts the carousel to the favorite teams' games (or, without any favorites, to the
+    /// focused game's teams), or lifts the restriction if there already is one.
+    Filter,
+    /// Flips between every game, the ones that aren't over, and only the ones being played.
+    Progress,
+    /// Puts the games in a different order: favorites first, by start time, or the ones being
+    /// played first.
+    Sort,
+    /// Opens up a search box for jumping to a team's game.
+    Search,
+    /// Opens up a box for typing in a date to go to.
+    GoTo,
+    /// Opens the focused game's whole story in the browser.
+    Story,
+    /// Plays the condensed game of the game whose details are open, in the browser.
+    Condensed,
+    /// The same as Condensed, but for the recap.
+    Recap,
+    /// Opens up the page of one of the focused game's teams: their record, recent and upcoming
+    /// games, and roster.
+    Team,
+    /// Opens up the list of the last month's trades, signings, and roster moves, for the team
+    /// set with --transactions-team or else the whole league.
+    Transactions,
+    /// Flips over to the next level down of baseball (Triple-A, Double-A, and so on down to
+    /// rookie ball), and from the bottom back up to MLB.
+    Sport,
+    /// Flips between the carousel and a week's worth of games at a glance, a day to a column.
+    Week,
+    /// Opens up (or closes) a calendar of the month, for picking out a day to go to.
+    Calendar,
+    /// Writes the schedule that is loaded out to a JSON file in the working directory.
+    Export,
+    /// Shows or hides the numbers on how the app is running (frame times, downloads, caches).
+    Debug,
+}
+
+impl Action {
+    /// Whether or not holding this down does it over and over again.
+    pub fn repeats(self) -> bool {
+        self == Action::Left || self == Action::Right
+    }
+}
+
+/// Something that the user did, before anything has been made of it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Input {
+    /// A button going down.
+    Press(Action),
+    /// A button coming back up.
+    Release(Action),
+    /// Some text was typed. This comes in alongside the presses and releases of the keys that
+    /// typed it, rather than instead of them.
+    Text(String),
+    /// Backspace, which takes back the last thing typed. It gets its own input since it isn't
+    /// the sort of thing that anyone would want to bind anything else to.
+    Erase,
+    /// The mouse moved to the given point.
+    Cursor([f64; 2]),
+    /// The mouse wheel turned, sideways and then up and down.
+    Scroll([f64; 2]),
+    /// The left mouse button was let go of.
+    Click,
+    /// The window lost focus.
+    Blur,
+}
+
+/// What the screens are told that the user did. This is everything that came in from the
+/// keyboard, the mouse, and the controllers, with the repeating and such already worked out,
+/// and without a trace of which one of them it came from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Action(Action),
+    /// Some text was typed, with anything unprintable already taken out of it.
+    Text(String),
+    /// The last thing typed ought to be taken back.
+    Erase,
+    /// The mouse was clicked at the given point.
+    Click([f64; 2]),
+}
+
+/// Turns Inputs into Commands, keeping track of whatever it needs to along the way.
+///
+/// Left and right start repeating if they are held down for a bit. The OS has key repeat of its
+/// own, which shows up as a pile of extra presses, but we'd rather be in charge of how fast
+/// things go. Everything else happens on release, as does a tap of left or right. A held left
+/// or right has already done all of its moving by the time that it is let go of.
+pub struct Controls {
+    // Left or right, should either one be held down.
+    held: Option<Repeat<Action>>,
+    // Where the mouse was last seen, since clicks don't say where they happened.
+    mouse: [f64; 2],
+}
+
+impl Controls {
+    pub fn new() -> Controls {
+        Controls {
+            held: None,
+            mouse: [0.0, 0.0],
+        }
+    }
+
+    /// What the given input amounts to, if anything.
+    pub fn interpret(&mut self, input: Input, now: Instant) -> Option<Command> {
+        match input {
+            Input::Press(action) if action.repeats() => {
+                if self.held.as_ref().is_none_or(|held| held.key() != action) {
+                    self.held = Some(Repeat::new(action, now));
+                }
+                None
+            }
+            Input::Press(_) => None,
+            Input::Release(action) => match self.held.take() {
+                Some(repeat) if repeat.key() != action => {
+                    self.held = Some(repeat);
+                    Some(Command::Action(action))
+                }
+                Some(repeat) if repeat.repeated() => None,
+                _ => Some(Command::Action(action)),
+            },
+            Input::Text(text) => {
+                let typed: String = text.chars().filter(|c| !c.is_control()).collect();
+                match typed.is_empty() {
+                    true => None,
+                    false => Some(Command::Text(typed)),
+                }
+            }
+            Input::Erase => Some(Command::Erase),
+            Input::Cursor(position) => {
+                self.mouse = position;
+                None
+            }
+            // The wheel scrolls through the games one at a time, much like left and right do.
+            // Both up and left on the wheel count as left, and down and right count as right.
+            Input::Scroll([dx, dy]) => match dx - dy {
+                delta if delta < 0.0 => Some(Command::Action(Action::Left)),
+                delta if delta > 0.0 => Some(Command::Action(Action::Right)),
+                _ => None,
+            },
+            Input::Click => Some(Command::Click(self.mouse)),
+            // A key that is let go of while we aren't looking never gets a release event.
+            Input::Blur => {
+                self.held = None;
+                None
+            }
+        }
+    }
+
+    /// Whatever is being held down, once for every time that it ought to have repeated since
+    /// the last time that this was asked.
+    pub fn repeats(&mut self, now: Instant) -> Vec<Command> {
+        match &mut self.held {
+            Some(repeat) => {
+                let action = repeat.key();
+                (0..repeat.due(now))
+                    .map(|_| Command::Action(action))
+                    .collect()
+            }
+            None => vec![],
+        }
+    }
+}
+
+impl Default for Controls {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A key (or button, or whatever) that is being held down, and how many times it ought to have
+/// repeated by now.
+///
+/// Nothing repeats for a short while after the initial press, so that a quick tap is just the
+/// one tap. After that it repeats slowly at first and then faster and faster the longer that
+/// it is held, which is how scrolling to the far end of a long slate ought to feel.
+pub struct Repeat<K> {
+    key: K,
+    // When the next repeat is due.
+    next: Instant,
+    // How many times the key has repeated so far.
+    fired: u32,
+}
+
+impl<K: Copy + PartialEq> Repeat<K> {
+    // How long a key has to be held before it starts repeating.
+    const DELAY: Duration = Duration::from_millis(400);
+    // The wait between the first couple of repeats, which shrinks from there.
+    const SLOWEST: Duration = Duration::from_millis(150);
+    // And how far it is allowed to shrink.
+    const FASTEST: Duration = Duration::from_millis(30);
+    // Should the event loop stall for a while (E.G. the window being dragged around) we'd rather
+    // not come back and fling the cursor across the whole slate all at once.
+    const MAX_BURST: u32 = 5;
+
+    pub fn new(key: K, now: Instant) -> Repeat<K> {
+        Repeat {
+            key,
+            next: now + Self::DELAY,
+            fired: 0,
+        }
+    }
+
+    pub fn key(&self) -> K {
+        self.key
+    }
+
+    /// Whether or not the key has repeated at all yet. A key that never got around to
+    /// repeating was just a tap.
+    pub fn repeated(&self) -> bool {
+        self.fired > 0
+    }
+
+    /// How many times the key has repeated since the last time that this was asked.
+    pub fn due(&mut self, now: Instant) -> u32 {
+        let mut count = 0;
+        while self.next <= now && count < Self::MAX_BURST {
+            count += 1;
+            self.fired += 1;
+            self.next += self.interval();
+        }
+        // Whatever didn't make it into the burst is forgotten about rather than saved for later.
+        if self.next <= now {
+            self.next = now + self.interval();
+        }
+        count
+    }
+
+    // Each repeat comes a bit sooner than the last one did.
+    fn interval(&self) -> Duration {
+        let scale = 0.85f64.powi(self.fired.min(100) as i32);
+        Duration::from_secs_f64(Self::SLOWEST.as_secs_f64() * scale).max(Self::FASTEST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taps_do_not_repeat() {
+        let start = Instant::now();
+        let mut repeat = Repeat::new('a', start);
+        assert_eq!(repeat.due(start + Duration::from_millis(399)), 0);
+        assert!(!repeat.repeated());
+        assert_eq!(repeat.key(), 'a');
+    }
+
+    #[test]
+    fn accelerates() {
+        let start = Instant::now();
+        let mut repeat = Repeat::new('a', start);
+        assert_eq!(repeat.due(start + Duration::from_millis(400)), 1);
+        assert!(repeat.repeated());
+        // The first second of repeating is slower than the third.
+        let mut now = start + Duration::from_millis(400);
+        let mut counts = vec![];
+        for _ in 0..3 {
+            let mut count = 0;
+            for _ in 0..10 {
+                now += Duration::from_millis(100);
+                count += repeat.due(now);
+            }
+            counts.push(count);
+        }
+        assert!(counts[0] < counts[2], "{:?}", counts);
+        // And it tops out.
+        assert!(counts[2] <= 1000 / 30 + 1, "{:?}", counts);
+    }
+
+    #[test]
+    fn taps_happen_on_release() {
+        let start = Instant::now();
+        let mut controls = Controls::new();
+        assert_eq!(controls.interpret(Input::Press(Action::Left), start), None);
+        assert_eq!(
+            controls.interpret(Input::Release(Action::Left), start),
+            Some(Command::Action(Action::Left))
+        );
+        assert_eq!(
+            controls.interpret(Input::Press(Action::Select), start),
+            None
+        );
+        assert_eq!(
+            controls.interpret(Input::Release(Action::Select), start),
+            Some(Command::Action(Action::Select))
+        );
+        assert!(controls.repeats(start + Duration::from_secs(1)).is_empty());
+    }
+
+    #[test]
+    fn holds_repeat_instead() {
+        let start = Instant::now();
+        let mut controls = Controls::new();
+        controls.interpret(Input::Press(Action::Right), start);
+        assert!(controls.repeats(start).is_empty());
+        assert_eq!(
+            controls.repeats(start + Duration::from_millis(400)),
+            vec![Command::Action(Action::Right)]
+        );
+        // Everything else still goes through while right is held down.
+        assert_eq!(
+            controls.interpret(Input::Release(Action::Up), start),
+            Some(Command::Action(Action::Up))
+        );
+        assert_eq!(
+            controls.interpret(Input::Release(Action::Right), start),
+            None
+        );
+        // And losing focus lets go of whatever was held.
+        controls.interpret(Input::Press(Action::Left), start);
+        controls.interpret(Input::Blur, start);
+        assert!(controls.repeats(start + Duration::from_secs(1)).is_empty());
+    }
+
+    #[test]
+    fn the_mouse() {
+        let now = Instant::now();
+        let mut controls = Controls::new();
+        assert_eq!(controls.interpret(Input::Cursor([10.0, 20.0]), now), None);
+        assert_eq!(
+            controls.interpret(Input::Click, now),
+            Some(Command::Click([10.0, 20.0]))
+        );
+        assert_eq!(
+            controls.interpret(Input::Scroll([0.0, 1.0]), now),
+            Some(Command::Action(Action::Left))
+        );
+        assert_eq!(
+            controls.interpret(Input::Scroll([1.0, 0.0]), now),
+            Some(Command::Action(Action::Right))
+        );
+        assert_eq!(controls.interpret(Input::Scroll([0.0, 0.0]), now), None);
+    }
+
+    #[test]
+    fn typing() {
+        let now = Instant::now();
+        let mut controls = Controls::new();
+        assert_eq!(
+            controls.interpret(Input::Text("ny\u{8}".to_string()), now),
+            Some(Command::Text("ny".to_string()))
+        );
+        assert_eq!(controls.interpret(Input::Text("\r".to_string()), now), None);
+        assert_eq!(controls.interpret(Input::Erase, now), Some(Command::Erase));
+    }
+
+    #[test]
+    fn stalls_do_not_fling() {
+        let start = Instant::now();
+        let mut repeat = Repeat::new('a', start);
+        assert_eq!(repeat.due(start + Duration::from_secs(60)), 5);
+        assert_eq!(repeat.due(start + Duration::from_secs(60)), 0);
+    }
+}
@@ -0,0 +1,43 @@
+use super::{Action, Input, Keymap};
+use piston_window::{
+    Button, FocusEvent, Key, MouseButton, MouseCursorEvent, MouseScrollEvent, PressEvent,
+    ReleaseEvent, TextEvent,
+};
+
+/// Everything that the window has to say about what the user got up to. Keys go through the
+/// given keymap, and any that aren't bound to anything don't come out the other end at all.
+///
+/// This is the one and only place that Piston's events are picked apart. Everything past here
+/// deals in Inputs, which don't care whether or not there is a window.
+pub fn inputs(e: &piston_window::Event, keymap: &Keymap) -> Vec<Input> {
+    let mut inputs = vec![];
+    if let Some(Button::Keyboard(key)) = e.press_args() {
+        if key == Key::Backspace {
+            inputs.push(Input::Erase);
+        }
+        inputs.extend(action(keymap, key).map(Input::Press));
+    }
+    match e.release_args() {
+        Some(Button::Keyboard(key)) => inputs.extend(action(keymap, key).map(Input::Release)),
+        Some(Button::Mouse(MouseButton::Left)) => inputs.push(Input::Click),
+        _ => (),
+    }
+    if let Some(text) = e.text_args() {
+        inputs.push(Input::Text(text));
+    }
+    if let Some(position) = e.mouse_cursor_args() {
+        inputs.push(Input::Cursor(position));
+    }
+    if let Some(scroll) = e.mouse_scroll_args() {
+        inputs.push(Input::Scroll(scroll));
+    }
+    if let Some(false) = e.focus_args() {
+        inputs.push(Input::Blur);
+    }
+    inputs
+}
+
+// What the given key does, going by the keymap. Keys go by their Piston names, E.G. "PageUp".
+fn action(keymap: &Keymap, key: Key) -> Option<Action> {
+    keymap.action(&format!("{:?}", key))
+}
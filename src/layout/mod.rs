@@ -0,0 +1,589 @@
+/// The room between the games on the carousel and around the edges of the screen, when the
+/// theme doesn't say otherwise.
+pub const DEFAULT_PADDING: f64 = 27.5;
+
+/// How a background image is fit to a window that isn't the same shape as it is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fit {
+    /// Scaled up (or down) to cover the whole window, with whatever doesn't fit hanging off of
+    /// the edges.
+    Cover,
+    /// Scaled to fit entirely within the window, with the background color in the bars to
+    /// either side of it.
+    Letterbox,
+}
+
+/// All of the positions and sizes on screen, worked out for a particular window size.
+///
+/// Everything was originally eyeballed against a 1920x1080 screen. Rather than reinventing the
+/// design for every possible window, that design is scaled uniformly until it fits the window
+/// and then centered within whatever room is left over. So a 1280x720 laptop window gets
+/// everything at two thirds the size, a 4K TV gets everything at double, and an ultra-wide
+/// monitor gets some extra background on either side.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layout {
+    pub width: f64,
+    pub height: f64,
+    /// How much bigger (or smaller) everything is than it is on the 1920x1080 design.
+    pub scale: f64,
+    /// How many of the screen's pixels there are to each of the window's points. That's 2 on a
+    /// Retina screen (and most 4K laptops), and 1 just about everywhere else.
+    pub density: f64,
+    /// The padding between onscreen game snippets, on the 1920x1080 design.
+    pub padding: f64,
+    // Where the top left corner of the scaled design lands within the window.
+    origin: [f64; 2],
+}
+
+impl Layout {
+    const DESIGN: [f64; 2] = [1920.0, 1080.0];
+    const LARGE: [f64; 2] = [480.0, 270.0];
+    const SMALL: [f64; 2] = [320.0, 180.0];
+    const ARROW: f64 = 256.0;
+    const THUMBNAIL: [f64; 2] = [160.0, 90.0];
+
+    pub fn new(size: [f64; 2]) -> Layout {
+        let scale = (size[0] / Self::DESIGN[0]).min(size[1] / Self::DESIGN[1]);
+        Layout {
+            width: size[0],
+            height: size[1],
+            scale,
+            density: 1.0,
+            padding: DEFAULT_PADDING,
+            origin: [
+                (size[0] - Self::DESIGN[0] * scale) / 2.0,
+                (size[1] - Self::DESIGN[1] * scale) / 2.0,
+            ],
+        }
+    }
+
+    /// The same layout, on a screen with the given density. Positions and sizes are all in
+    /// points no matter the density, so this only changes how finely things are rendered.
+    pub fn with_density(mut self, density: f64) -> Layout {
+        self.density = density;
+        self
+    }
+
+    /// The same layout, with the given padding between and around things.
+    pub fn with_padding(mut self, padding: f64) -> Layout {
+        self.padding = padding;
+        self
+    }
+
+    /// Translates a point on the 1920x1080 design into a point within the window.
+    pub fn point(&self, x: f64, y: f64) -> [f64; 2] {
+        [
+            self.origin[0] + x * self.scale,
+            self.origin[1] + y * self.scale,
+        ]
+    }
+
+    /// Translates a length on the 1920x1080 design into a length within the window.
+    pub fn length(&self, length: f64) -> f64 {
+        length * self.scale
+    }
+
+    /// Translates a font size on the 1920x1080 design into a font size for this window.
+    /// Fonts only come in whole points, and never smaller than one.
+    pub fn font_size(&self, size: u32) -> u32 {
+        ((size as f64 * self.scale).round() as u32).max(1)
+    }
+
+    /// The size of a large photo, E.G. the one in a game's details.
+    pub fn large(&self) -> [f64; 2] {
+        [self.length(Self::LARGE[0]), self.length(Self::LARGE[1])]
+    }
+
+    /// The whole window.
+    pub fn fullscreen(&self) -> [f64; 4] {
+        [0.0, 0.0, self.width, self.height]
+    }
+
+    /// Where a background image of the given size goes. It is never stretched, so on windows
+    /// that aren't the same shape as it is, either a bit of it hangs off of the edges or there
+    /// are bars to either side of it, depending on the fit.
+    pub fn background(&self, image: [f64; 2], fit: Fit) -> [f64; 4] {
+        let (across, down) = (self.width / image[0], self.height / image[1]);
+        let scale = match fit {
+            Fit::Cover => across.max(down),
+            Fit::Letterbox => across.min(down),
+        };
+        let width = image[0] * scale;
+        let height = image[1] * scale;
+        [
+            (self.width - width) / 2.0,
+            (self.height - height) / 2.0,
+            width,
+            height,
+        ]
+    }
+
+    /// How many games make up a page on a window of this size. Five is what the design was made
+    /// for, and windows with room to spare on either side (E.G. ultra-wide monitors) get seven.
+    pub fn page_size(&self) -> usize {
+        let room = self.width / self.scale;
+        match self.row_width(7) + self.padding * 2.0 <= room {
+            true => 7,
+            false => 5,
+        }
+    }
+
+    // How wide a page of the given number of snippets is on the design, padding in between
+    // included but not the padding on either end.
+    fn row_width(&self, count: usize) -> f64 {
+        match count {
+            0 => 0.0,
+            count => Self::LARGE[0] + (count - 1) as f64 * (Self::SMALL[0] + self.padding),
+        }
+    }
+
+    /// The rectangles ([x, y, width, height]) of each snippet on a page of the given number of
+    /// snippets, where the snippet at index focus is the large one.
+    ///
+    /// Pages that fit on the design start at its left edge, as they always have. Those that
+    /// don't are centered on the window instead, spilling out into whatever room there is on
+    /// either side of the design, and if even that isn't enough then they're shrunk to fit.
+    pub fn snippets(&self, count: usize, focus: usize) -> Vec<[f64; 4]> {
+        let width = self.row_width(count);
+        let (mut left_edge, shrink) = if self.padding + width <= Self::DESIGN[0] {
+            // The first item is padded from the left most wall of the screen.
+            (self.padding, 1.0)
+        } else {
+            let room = self.width / self.scale - self.padding * 2.0;
+            let shrink = (room / width).min(1.0);
+            ((Self::DESIGN[0] - width * shrink) / 2.0, shrink)
+        };
+        let mut rects = Vec::with_capacity(count);
+        for index in 0..count {
+            let (size, top) = if index == focus {
+                (Self::LARGE, 540.0)
+            } else {
+                (Self::SMALL, 578.5)
+            };
+            let [x, y] = self.point(left_edge, top);
+            let size = [size[0] * shrink, size[1] * shrink];
+            rects.push([x, y, self.length(size[0]), self.length(size[1])]);
+            // This is computing the small padding in-between snippets.
+            left_edge += size[0] + self.padding * shrink;
+        }
+        rects
+    }
+
+    /// Where the headline goes, relative to the large snippet's rectangle.
+    pub fn heading(&self, large: [f64; 4]) -> [f64; 2] {
+        [large[0] + self.length(40.0), large[1] - self.length(40.0)]
+    }
+
+    /// Where both clubs' records go, relative to the large snippet's rectangle. They're in small
+    /// print, tucked in between the photo and the subhead.
+    pub fn records(&self, large: [f64; 4]) -> [f64; 2] {
+        [large[0], large[1] + self.length(292.0)]
+    }
+
+    /// Where the subhead goes, relative to the large snippet's rectangle.
+    pub fn subheading(&self, large: [f64; 4]) -> [f64; 2] {
+        [large[0], large[1] + self.length(315.0)]
+    }
+
+    /// Where the live state of a game goes, relative to the large snippet's rectangle.
+    pub fn live(&self, large: [f64; 4]) -> [f64; 2] {
+        [large[0], large[1] + self.length(345.0)]
+    }
+
+    /// Where the hint for reading the whole story goes, relative to the large snippet's rectangle.
+    pub fn story_hint(&self, large: [f64; 4]) -> [f64; 2] {
+        [large[0], large[1] + self.length(375.0)]
+    }
+
+    /// The band along the bottom of a snippet that its score goes in.
+    pub fn badge(&self, snippet: [f64; 4]) -> [f64; 4] {
+        let height = self.length(32.0);
+        [
+            snippet[0],
+            snippet[1] + snippet[3] - height,
+            snippet[2],
+            height,
+        ]
+    }
+
+    /// Where the away and home teams' logos go, in the snippet's top left and top right corners.
+    /// They're a fifth as tall as the snippet, so they shrink along with it.
+    pub fn logos(&self, snippet: [f64; 4]) -> [[f64; 4]; 2] {
+        let [x, y, width, height] = snippet;
+        let (size, inset) = (height / 5.0, self.length(8.0));
+        [
+            [x + inset, y + inset, size, size],
+            [x + width - inset - size, y + inset, size, size],
+        ]
+    }
+
+    /// Where the score goes within its badge.
+    pub fn badge_text(&self, badge: [f64; 4]) -> [f64; 2] {
+        [
+            badge[0] + self.length(10.0),
+            badge[1] + badge[3] - self.length(10.0),
+        ]
+    }
+
+    /// The rectangles of a strip of thumbnails for the row of games above the focused one,
+    /// when there is more than one date on screen.
+    pub fn strip_above(&self, count: usize) -> Vec<[f64; 4]> {
+        self.strip(count, 360.0)
+    }
+
+    /// The same as strip_above, but for the row below the focused one.
+    pub fn strip_below(&self, count: usize) -> Vec<[f64; 4]> {
+        self.strip(count, 960.0)
+    }
+
+    /// Where each of a game's highlights go in its details, in a row along the bottom,
+    /// underneath its photo and everything that is written about it.
+    pub fn highlights(&self, count: usize) -> Vec<[f64; 4]> {
+        (0..count)
+            .map(|index| {
+                let left = 100.0 + index as f64 * (Self::SMALL[0] + self.padding);
+                let [x, y] = self.point(left, 700.0);
+                [
+                    x,
+                    y,
+                    self.length(Self::SMALL[0]),
+                    self.length(Self::SMALL[1]),
+                ]
+            })
+            .collect()
+    }
+
+    /// Where a highlight's title goes, relative to its thumbnail. How long it runs goes on the
+    /// line after that.
+    pub fn highlight_title(&self, thumbnail: [f64; 4]) -> [f64; 2] {
+        [
+            thumbnail[0],
+            thumbnail[1] + thumbnail[3] + self.length(30.0),
+        ]
+    }
+
+    /// Where the date header goes. This is the middle of the text rather than its left end,
+    /// so that it stays centered between the arrows no matter how long the date is.
+    pub fn header(&self) -> [f64; 2] {
+        self.point(Self::DESIGN[0] / 2.0, 80.0)
+    }
+
+    /// Where the note that the carousel is filtered goes, just under the date header. Like the
+    /// header, this is the middle of the text.
+    pub fn filter_label(&self) -> [f64; 2] {
+        self.point(Self::DESIGN[0] / 2.0, 120.0)
+    }
+
+    /// The box that the search that is being typed goes in, just under the date header.
+    pub fn search_box(&self) -> [f64; 4] {
+        let [x, y] = self.point(660.0, 140.0);
+        [x, y, self.length(600.0), self.length(56.0)]
+    }
+
+    /// Where the search goes within its box.
+    pub fn search_text(&self, search_box: [f64; 4]) -> [f64; 2] {
+        [
+            search_box[0] + self.length(16.0),
+            search_box[1] + search_box[3] - self.length(18.0),
+        ]
+    }
+
+    /// Where the columns of a team's page start: the games that they've played, the ones that
+    /// are coming up, and then their roster, which takes two. This is where each one's heading
+    /// goes, with its lines underneath.
+    pub fn team_columns(&self) -> [[f64; 2]; 4] {
+        [
+            self.point(100.0, 400.0),
+            self.point(560.0, 400.0),
+            self.point(1020.0, 400.0),
+            self.point(1440.0, 400.0),
+        ]
+    }
+
+    /// Where each day of the week view starts, from left to right. This is where the day's
+    /// heading goes, with its games underneath.
+    pub fn week_columns(&self) -> [[f64; 2]; 7] {
+        let mut columns = [[0.0; 2]; 7];
+        for (index, column) in columns.iter_mut().enumerate() {
+            *column = self.point(40.0 + 264.0 * index as f64, 240.0);
+        }
+        columns
+    }
+
+    /// One day's square in the calendar, by its week of the month (counting down from the top
+    /// row) and its day of the week (counting over from Monday).
+    pub fn calendar_cell(&self, week: usize, weekday: usize) -> [f64; 4] {
+        let [x, y] = self.point(470.0 + 140.0 * weekday as f64, 280.0 + 112.0 * week as f64);
+        [x, y, self.length(132.0), self.length(104.0)]
+    }
+
+    /// Where a player's headshot goes on their page, in the top left corner at full size.
+    pub fn headshot(&self) -> [f64; 4] {
+        let [x, y] = self.point(100.0, 100.0);
+        [x, y, self.length(213.0), self.length(320.0)]
+    }
+
+    /// Where the columns of the transactions feed start: when each move was made, what sort of
+    /// move it was, and what it was, which runs the rest of the way across. This is where the
+    /// first line of each one goes, with the rest underneath.
+    pub fn transactions_columns(&self) -> [[f64; 2]; 3] {
+        [
+            self.point(100.0, 280.0),
+            self.point(200.0, 280.0),
+            self.point(560.0, 280.0),
+        ]
+    }
+
+    /// Where the given banner (counting down from the top one) goes. They stack up along the
+    /// top of the screen, between the arrows and over top of the date header.
+    pub fn banner(&self, index: usize) -> [f64; 4] {
+        let [x, y] = self.point(560.0, 16.0 + 56.0 * index as f64);
+        [x, y, self.length(800.0), self.length(48.0)]
+    }
+
+    /// Where a banner's text goes within it.
+    pub fn banner_text(&self, banner: [f64; 4]) -> [f64; 2] {
+        [
+            banner[0] + self.length(16.0),
+            banner[1] + banner[3] - self.length(16.0),
+        ]
+    }
+
+    /// The box that the debug overlay goes in, with room for the given number of lines. It sits
+    /// in the top left corner, just under the left arrow.
+    pub fn metrics(&self, lines: usize) -> [f64; 4] {
+        let [x, y] = self.point(self.padding, 272.0);
+        [
+            x,
+            y,
+            self.length(520.0),
+            self.length(16.0 + 28.0 * lines as f64),
+        ]
+    }
+
+    /// Where the given line of the debug overlay goes within its box.
+    pub fn metrics_text(&self, metrics: [f64; 4], line: usize) -> [f64; 2] {
+        [
+            metrics[0] + self.length(12.0),
+            metrics[1] + self.length(34.0 + 28.0 * line as f64),
+        ]
+    }
+
+    /// The thin bar across the bottom of the screen, just over the footer, that the scores of
+    /// the games in progress take turns in.
+    pub fn ticker(&self) -> [f64; 4] {
+        let [x, y] = self.point(0.0, 1016.0);
+        [x, y, self.length(Self::DESIGN[0]), self.length(32.0)]
+    }
+
+    /// Where the ticker's text starts within its bar.
+    pub fn ticker_text(&self, ticker: [f64; 4]) -> [f64; 2] {
+        [
+            ticker[0] + self.length(self.padding),
+            ticker[1] + self.length(23.0),
+        ]
+    }
+
+    /// The sliver along the bottom of the screen where the API's copyright notice goes. It is
+    /// tucked in underneath the strip below, which ends just above it.
+    pub fn footer(&self) -> [f64; 4] {
+        let [x, y] = self.point(self.padding, 1052.0);
+        [
+            x,
+            y,
+            self.length(Self::DESIGN[0] - self.padding * 2.0),
+            self.length(28.0),
+        ]
+    }
+
+    /// Where a strip's label (its date) goes, relative to the rectangle of its first thumbnail.
+    pub fn strip_label(&self, first: [f64; 4]) -> [f64; 2] {
+        [first[0], first[1] - self.length(10.0)]
+    }
+
+    fn strip(&self, count: usize, top: f64) -> Vec<[f64; 4]> {
+        (0..count)
+            .map(|index| {
+                let left = self.padding + index as f64 * (Self::THUMBNAIL[0] + self.padding);
+                let [x, y] = self.point(left, top);
+                [
+                    x,
+                    y,
+                    self.length(Self::THUMBNAIL[0]),
+                    self.length(Self::THUMBNAIL[1]),
+                ]
+            })
+            .collect()
+    }
+
+    /// The arrow in the top left corner of the window that says that there are more games
+    /// to the left.
+    pub fn left_arrow(&self) -> [f64; 4] {
+        [0.0, 0.0, self.length(Self::ARROW), self.length(Self::ARROW)]
+    }
+
+    /// The same as left_arrow, but on the right.
+    pub fn right_arrow(&self) -> [f64; 4] {
+        let size = self.length(Self::ARROW);
+        [self.width - size, 0.0, size, size]
+    }
+}
+
+/// Whether or not the given point is within the given rectangle, E.G. for working out what a
+/// click landed on. The left and top edges count as inside, and the right and bottom don't.
+pub fn contains(rect: [f64; 4], point: [f64; 2]) -> bool {
+    let [x, y, width, height] = rect;
+    point[0] >= x && point[0] < x + width && point[1] >= y && point[1] < y + height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn design_resolution_is_untouched() {
+        let layout = Layout::new([1920.0, 1080.0]);
+        assert_eq!(layout.scale, 1.0);
+        assert_eq!(layout.density, 1.0);
+        assert_eq!(layout.font_size(16), 16);
+        assert_eq!(layout.large(), [480.0, 270.0]);
+        let snippets = layout.snippets(5, 1);
+        assert_eq!(snippets[0], [27.5, 578.5, 320.0, 180.0]);
+        assert_eq!(snippets[1], [375.0, 540.0, 480.0, 270.0]);
+        assert_eq!(snippets[2], [882.5, 578.5, 320.0, 180.0]);
+        assert_eq!(layout.heading(snippets[1]), [415.0, 500.0]);
+        assert_eq!(layout.records(snippets[1]), [375.0, 832.0]);
+        assert_eq!(layout.subheading(snippets[1]), [375.0, 855.0]);
+        assert_eq!(layout.right_arrow(), [1664.0, 0.0, 256.0, 256.0]);
+        assert_eq!(layout.header(), [960.0, 80.0]);
+        assert_eq!(layout.banner(1), [560.0, 72.0, 800.0, 48.0]);
+        assert_eq!(layout.filter_label(), [960.0, 120.0]);
+        let search_box = layout.search_box();
+        assert_eq!(search_box, [660.0, 140.0, 600.0, 56.0]);
+        assert_eq!(layout.search_text(search_box), [676.0, 178.0]);
+        assert_eq!(layout.team_columns()[3], [1440.0, 400.0]);
+        assert_eq!(layout.headshot(), [100.0, 100.0, 213.0, 320.0]);
+        assert_eq!(layout.transactions_columns()[2], [560.0, 280.0]);
+        assert_eq!(layout.week_columns()[6], [1624.0, 240.0]);
+        assert_eq!(layout.calendar_cell(5, 6), [1310.0, 840.0, 132.0, 104.0]);
+        assert_eq!(layout.ticker(), [0.0, 1016.0, 1920.0, 32.0]);
+        let badge = layout.badge(snippets[1]);
+        assert_eq!(badge, [375.0, 778.0, 480.0, 32.0]);
+        assert_eq!(layout.badge_text(badge), [385.0, 800.0]);
+        let [away, home] = layout.logos(snippets[1]);
+        assert_eq!(away, [383.0, 548.0, 54.0, 54.0]);
+        assert_eq!(home, [793.0, 548.0, 54.0, 54.0]);
+        assert_eq!(layout.logos(snippets[0])[0][2], 36.0);
+        assert_eq!(layout.footer(), [27.5, 1052.0, 1865.0, 28.0]);
+        let metrics = layout.metrics(2);
+        assert_eq!(metrics, [27.5, 272.0, 520.0, 72.0]);
+        assert_eq!(layout.metrics_text(metrics, 1), [39.5, 334.0]);
+    }
+
+    #[test]
+    fn scales_down() {
+        let layout = Layout::new([1280.0, 720.0]);
+        assert!((layout.scale - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(layout.font_size(24), 16);
+        let snippets = layout.snippets(5, 0);
+        assert!((snippets[0][2] - 320.0).abs() < 1e-9);
+        assert!((snippets[0][1] - 360.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn centers_on_wide_windows() {
+        let layout = Layout::new([3840.0, 1080.0]);
+        assert_eq!(layout.scale, 1.0);
+        assert_eq!(layout.point(0.0, 0.0), [960.0, 0.0]);
+        let photo = [1920.0, 1080.0];
+        assert_eq!(
+            layout.background(photo, Fit::Cover),
+            [0.0, -540.0, 3840.0, 2160.0]
+        );
+        assert_eq!(
+            layout.background(photo, Fit::Letterbox),
+            [960.0, 0.0, 1920.0, 1080.0]
+        );
+        // A tall photo on a wide window, E.G. one off of a phone.
+        assert_eq!(
+            layout.background([1000.0, 2000.0], Fit::Letterbox),
+            [1650.0, 0.0, 540.0, 1080.0]
+        );
+    }
+
+    #[test]
+    fn wide_windows_get_longer_pages() {
+        assert_eq!(Layout::new([1280.0, 720.0]).page_size(), 5);
+        assert_eq!(Layout::new([1920.0, 1080.0]).page_size(), 5);
+        assert_eq!(Layout::new([2560.0, 1080.0]).page_size(), 5);
+        let layout = Layout::new([3840.0, 1080.0]);
+        assert_eq!(layout.page_size(), 7);
+        let snippets = layout.snippets(7, 3);
+        assert_eq!(snippets[0], [637.5, 578.5, 320.0, 180.0]);
+        assert_eq!(snippets[3], [1680.0, 540.0, 480.0, 270.0]);
+        assert_eq!(snippets[6][0] + snippets[6][2], 3202.5);
+        // A page that is too long even for the window shrinks until it fits.
+        let snippets = Layout::new([1920.0, 1080.0]).snippets(7, 0);
+        assert!((snippets[0][0] - 27.5).abs() < 1e-9);
+        assert!((snippets[6][0] + snippets[6][2] - 1892.5).abs() < 1e-9);
+        assert!(snippets[0][2] < 480.0);
+    }
+
+    #[test]
+    fn strips() {
+        let layout = Layout::new([960.0, 540.0]);
+        let above = layout.strip_above(2);
+        assert_eq!(above[0], [13.75, 180.0, 80.0, 45.0]);
+        assert_eq!(above[1], [107.5, 180.0, 80.0, 45.0]);
+        assert_eq!(layout.strip_label(above[0]), [13.75, 175.0]);
+        assert_eq!(layout.strip_below(1)[0][1], 480.0);
+    }
+
+    #[test]
+    fn highlights() {
+        let layout = Layout::new([1920.0, 1080.0]);
+        let highlights = layout.highlights(5);
+        assert_eq!(highlights[0], [100.0, 700.0, 320.0, 180.0]);
+        assert_eq!(highlights[1][0], 447.5);
+        // A whole page of them fits across the window, margins and all.
+        assert_eq!(highlights[4][0] + highlights[4][2], 1810.0);
+        assert_eq!(layout.highlight_title(highlights[0]), [100.0, 910.0]);
+    }
+
+    #[test]
+    fn hit_testing() {
+        let layout = Layout::new([1920.0, 1080.0]);
+        let snippets = layout.snippets(5, 1);
+        let hit = |point| snippets.iter().position(|rect| contains(*rect, point));
+        assert_eq!(hit([27.5, 578.5]), Some(0));
+        assert_eq!(hit([600.0, 700.0]), Some(1));
+        // The padding between snippets belongs to nobody.
+        assert_eq!(hit([360.0, 600.0]), None);
+        assert_eq!(hit([347.5, 600.0]), None);
+        assert!(contains(layout.right_arrow(), [1900.0, 10.0]));
+        assert!(!contains(layout.left_arrow(), [256.0, 10.0]));
+    }
+
+    #[test]
+    fn density_leaves_the_layout_be() {
+        let layout = Layout::new([1440.0, 900.0]);
+        let retina = layout.with_density(2.0);
+        assert_eq!(retina.density, 2.0);
+        assert_eq!(retina.snippets(5, 2), layout.snippets(5, 2));
+        assert_eq!(retina.font_size(16), layout.font_size(16));
+    }
+
+    #[test]
+    fn padding_spreads_things_out() {
+        let layout = Layout::new([1920.0, 1080.0]).with_padding(40.0);
+        let snippets = layout.snippets(3, 0);
+        assert_eq!(snippets[0], [40.0, 540.0, 480.0, 270.0]);
+        assert_eq!(snippets[1][0], 560.0);
+        assert_eq!(layout.footer(), [40.0, 1052.0, 1840.0, 28.0]);
+    }
+
+    #[test]
+    fn fonts_never_vanish() {
+        assert_eq!(Layout::new([10.0, 10.0]).font_size(16), 1);
+    }
+}
@@ -0,0 +1,18 @@
+//! The statsapi client and the lineup that the mlb binary is built on top of, minus the UI.
+//!
+//! `api` is a thin, typed layer over http://statsapi.mlb.com (the schedule and the live feeds),
+//! and `lineup` turns what comes back into something that is ready to be put on screen: games
+//! with headlines and photos that download themselves in the background. Neither one knows
+//! or cares what is doing the drawing, so building with `--no-default-features` leaves Piston
+//! (and the rest of the graphics stack) out of the picture entirely.
+#[macro_use]
+extern crate lazy_static;
+
+pub mod api;
+pub mod cache;
+pub mod export;
+pub mod lineup;
+pub mod paginator;
+
+pub use api::{APIError, APIResult, ErrorContext, Schedule, ScheduleRequest, Transport};
+pub use lineup::{Lineup, Photo};
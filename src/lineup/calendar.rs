@@ -0,0 +1,215 @@
+use super::{poll, Polled};
+use crate::api::days::{self, Days};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// A month laid out as a grid, Monday through Sunday, with a cursor on one of its days, for
+/// picking out a day to go to. Which days have games on them is looked up in the background, a
+/// month at a time, whenever the cursor lands in a month that it wasn't in before.
+pub struct Calendar {
+    /// The day that the cursor is on.
+    pub cursor: NaiveDate,
+    sport_id: u32,
+    // Whether or not each day of the cursor's month has any games, once they've come in.
+    game_days: Option<Vec<bool>>,
+    // The month that is still on its way, if it is.
+    channel: Option<crossbeam_channel::Receiver<Days>>,
+}
+
+impl Calendar {
+    /// Opens up to the given day's month, in the given sport.
+    pub fn new(cursor: NaiveDate, sport_id: u32) -> Calendar {
+        let mut calendar = Calendar {
+            cursor,
+            sport_id,
+            game_days: None,
+            channel: None,
+        };
+        calendar.send_for_month();
+        calendar
+    }
+
+    /// Moves the cursor by the given number of days, which is a week at a time going up and
+    /// down the grid.
+    pub fn move_days(&mut self, days: i64) {
+        self.go_to(self.cursor + Duration::days(days));
+    }
+
+    /// Moves the cursor by the given number of months, to the same day of the month where
+    /// there is one and to the month's last day where there isn't (E.G. January 31st goes to
+    /// the end of February).
+    pub fn move_months(&mut self, months: i32) {
+        let index = self.cursor.year() * 12 + self.cursor.month0() as i32 + months;
+        let first =
+            NaiveDate::from_ymd_opt(index.div_euclid(12), index.rem_euclid(12) as u32 + 1, 1)
+                .unwrap();
+        let day = self.cursor.day().min(month_length(first));
+        self.go_to(first.with_day(day).unwrap_or(first));
+    }
+
+    /// The first of the cursor's month.
+    pub fn first(&self) -> NaiveDate {
+        first_of_month(self.cursor)
+    }
+
+    /// The cursor's month a week to a row, Monday through Sunday. The days before the first
+    /// and after the last are left blank.
+    pub fn weeks(&self) -> Vec<[Option<NaiveDate>; 7]> {
+        let first = self.first();
+        let mut weeks = vec![[None; 7]];
+        for date in (0..month_length(first) as i64).map(|offset| first + Duration::days(offset)) {
+            let weekday = date.weekday().num_days_from_monday() as usize;
+            if weekday == 0 && date != first {
+                weeks.push([None; 7]);
+            }
+            if let Some(week) = weeks.last_mut() {
+                week[weekday] = Some(date);
+            }
+        }
+        weeks
+    }
+
+    /// Whether or not there are any games on the given day of the cursor's month. None when
+    /// that isn't known, which is until the month comes in, and forever for any other month.
+    pub fn has_games(&self, date: NaiveDate) -> Option<bool> {
+        if first_of_month(date) != self.first() {
+            return None;
+        }
+        self.game_days
+            .as_ref()
+            .and_then(|days| days.get(date.day0() as usize).copied())
+    }
+
+    /// Whether or not the month is still on its way.
+    pub fn loading(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    /// Whether or not the month couldn't be had, which has already been logged. The days can
+    /// still be picked, they just aren't marked.
+    pub fn failed(&self) -> bool {
+        !self.loading() && self.game_days.is_none()
+    }
+
+    /// Checks in on the month, returning true if it has just come in (or just given up).
+    pub fn poll(&mut self) -> bool {
+        match poll(&mut self.channel) {
+            Polled::Arrived(days) => {
+                self.game_days = Some(game_days(&days, self.first()));
+                true
+            }
+            Polled::Failed => true,
+            Polled::Waiting => false,
+        }
+    }
+
+    // Puts the cursor on the given day, sending for its month should it be a different one.
+    fn go_to(&mut self, date: NaiveDate) {
+        let moved = first_of_month(date) != self.first();
+        self.cursor = date;
+        if moved {
+            self.send_for_month();
+        }
+    }
+
+    // Whatever was known (or on its way) was for some other month, so it's let go of.
+    fn send_for_month(&mut self) {
+        let first = self.first();
+        let last = first + Duration::days(month_length(first) as i64 - 1);
+        self.game_days = None;
+        self.channel = Some(days::fetch(first, last, self.sport_id));
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap()
+}
+
+// How many days there are in the month that starts on the given day.
+fn month_length(first: NaiveDate) -> u32 {
+    let next = match first.month() {
+        12 => NaiveDate::from_ymd_opt(first.year() + 1, 1, 1).unwrap(),
+        month => NaiveDate::from_ymd_opt(first.year(), month + 1, 1).unwrap(),
+    };
+    (next - first).num_days() as u32
+}
+
+// Whether or not each day of the month that starts on the given day has any games.
+fn game_days(days: &Days, first: NaiveDate) -> Vec<bool> {
+    (0..month_length(first) as i64)
+        .map(|offset| first + Duration::days(offset))
+        .map(|date| {
+            days.schedule(date, 1)
+                .map(|schedule| schedule.dates.iter().any(|day| !day.games.is_empty()))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Schedule;
+
+    #[test]
+    fn grid() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            // The 1st of September 2019 was a Sunday.
+            let mut calendar = Calendar::new(NaiveDate::from_ymd_opt(2019, 9, 24).unwrap(), 1);
+            let weeks = calendar.weeks();
+            assert_eq!(weeks.len(), 6);
+            assert_eq!(weeks[0][5], None);
+            assert_eq!(
+                weeks[0][6],
+                Some(NaiveDate::from_ymd_opt(2019, 9, 1).unwrap())
+            );
+            assert_eq!(
+                weeks[5][0],
+                Some(NaiveDate::from_ymd_opt(2019, 9, 30).unwrap())
+            );
+            assert_eq!(weeks[5][1], None);
+            calendar.move_days(7);
+            assert_eq!(
+                calendar.cursor,
+                NaiveDate::from_ymd_opt(2019, 10, 1).unwrap()
+            );
+            calendar.cursor = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+            calendar.move_months(1);
+            assert_eq!(
+                calendar.cursor,
+                NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()
+            );
+            calendar.move_months(-2);
+            assert_eq!(
+                calendar.cursor,
+                NaiveDate::from_ymd_opt(2019, 12, 29).unwrap()
+            );
+        });
+    }
+
+    #[test]
+    fn marks_game_days() {
+        let schedule: Schedule = serde_json::from_str(
+            r#"{"copyright": "", "dates": [
+                {"date": "2019-09-02", "games": [{
+                    "gamePk": 1,
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"team": {"id": 145, "name": "Chicago White Sox"}},
+                        "home": {"team": {"id": 111, "name": "Boston Red Sox"}}
+                    }
+                }]},
+                {"date": "2019-09-03", "games": []}
+            ]}"#,
+        )
+        .unwrap();
+        let first = NaiveDate::from_ymd_opt(2019, 9, 1).unwrap();
+        let days = Days::new(
+            schedule,
+            first,
+            NaiveDate::from_ymd_opt(2019, 9, 30).unwrap(),
+        );
+        let marked = game_days(&days, first);
+        assert_eq!(marked.len(), 30);
+        assert_eq!(&marked[..3], &[false, true, false]);
+    }
+}
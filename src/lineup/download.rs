@@ -0,0 +1,401 @@
+use crate::api::{self, Transport};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use tracing::{debug, error, warn};
+
+/// How many photos may be downloading at once when nobody says otherwise.
+pub const DEFAULT_LIMIT: usize = 4;
+
+/// How badly a photo is wanted. Downloads are handed out in this order, and first come
+/// first served amongst photos that are wanted just as badly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    /// The large photo of the game that the cursor is sitting on.
+    Focused,
+    /// The small photos of the rest of the games on the current page.
+    Visible,
+    /// Everything else, which nobody is looking at just yet.
+    Offscreen,
+}
+
+/// A place in line in a Queue. Whoever holds on to this can move their download up or down
+/// the line at any time, right up until it actually starts. Dropping every copy of the ticket
+/// gives up the place in line entirely, so photos that nobody wants anymore (E.G. those for a
+/// date that has been scrolled away from) never get downloaded at all.
+#[derive(Clone, Debug)]
+pub struct Ticket(Arc<AtomicUsize>);
+
+impl Ticket {
+    pub fn new(priority: Priority) -> Ticket {
+        Ticket(Arc::new(AtomicUsize::new(priority as usize)))
+    }
+
+    pub fn set(&self, priority: Priority) {
+        self.0.store(priority as usize, Ordering::SeqCst);
+    }
+
+    fn priority(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Whether or not whoever is holding this is anywhere on screen.
+    pub fn is_offscreen(&self) -> bool {
+        self.priority() >= Priority::Offscreen as usize
+    }
+
+    // The queue holds on to one copy of every ticket in line, so if that is the only copy
+    // left then there isn't anyone waiting on the other end anymore.
+    fn abandoned(&self) -> bool {
+        Arc::strong_count(&self.0) == 1
+    }
+}
+
+struct Job {
+    src: String,
+    ticket: Ticket,
+    // The ticket comes back along with the result so that a failure can get back in line.
+    tx: oneshot::Sender<(Ticket, Option<Vec<u8>>)>,
+}
+
+// Running counts of what a queue has been up to, shared by every copy of it.
+#[derive(Debug, Default)]
+struct Metrics {
+    downloading: AtomicUsize,
+    downloaded: AtomicUsize,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+/// How a queue (and the photos that go through it) is getting along, as of the moment that it
+/// was asked. None of this is needed for anything to work, it's for keeping an eye on things.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    /// Photos waiting in line for their turn.
+    pub queued: usize,
+    /// Photos coming down right this moment.
+    pub downloading: usize,
+    /// Photos that have come down off of the network, ever.
+    pub downloaded: usize,
+    /// Photos that were found on disk rather than downloaded.
+    pub hits: usize,
+    /// Photos that weren't found on disk, and so got in line.
+    pub misses: usize,
+    /// How many photos are decoded and sitting in memory, and how many bytes that adds up to.
+    /// The queue has no idea, so these are left for the lineup to fill in.
+    pub decoded: usize,
+    pub bytes: usize,
+}
+
+impl Stats {
+    /// The share of photos that were found on disk, if any photos have been looked for at all.
+    pub fn hit_rate(&self) -> Option<f64> {
+        match self.hits + self.misses {
+            0 => None,
+            total => Some(self.hits as f64 / total as f64),
+        }
+    }
+}
+
+/// A line of photos waiting to be downloaded.
+///
+/// Rather than handing permits out to whichever download happened to ask first, a dispatcher
+/// waits for a permit to free up and only then decides who gets it, based on how badly each
+/// photo is wanted at that moment. The dispatcher keeps running on the runtime that the queue
+/// was made on for as long as any copy of the queue is still around.
+///
+/// Every queue draws on the same permits, so a queue with nothing in line doesn't ask for one.
+/// Otherwise each idle queue would sit on a permit that some other queue could be using.
+#[derive(Clone)]
+pub struct Queue {
+    jobs: Arc<Mutex<Vec<Job>>>,
+    wake: mpsc::UnboundedSender<()>,
+    metrics: Arc<Metrics>,
+}
+
+impl Queue {
+    // How many times a photo is tried before it is given up on. Most failures are a blip on
+    // the network (or the CDN) that sorts itself out shortly, but not all of them.
+    const ATTEMPTS: u32 = 5;
+    // The wait before the first retry, which doubles on each one thereafter.
+    const BACKOFF: Duration = Duration::from_millis(500);
+
+    /// A queue that downloads over the default transport, no more than the given number of
+    /// photos at a time.
+    pub fn new(limit: usize) -> Queue {
+        Self::with_transport(api::default_transport(), limit)
+    }
+
+    /// A queue that downloads over the given transport, no more than the given number of
+    /// photos at a time.
+    ///
+    /// Opening the app kicks off a download for every photo on the slate all at once. Left to
+    /// their own devices they all fight over the same pipe and every one of them shows up at
+    /// the very end. Letting only a few through at a time means that they trickle in one
+    /// after another instead, which looks (and is) a whole lot snappier.
+    pub fn with_transport(transport: Arc<dyn Transport>, limit: usize) -> Queue {
+        Self::with_permits(transport, Arc::new(Semaphore::new(limit.max(1))))
+    }
+
+    // A queue that downloads over the given transport, whenever it can get one of the given
+    // permits.
+    fn with_permits(transport: Arc<dyn Transport>, permits: Arc<Semaphore>) -> Queue {
+        let jobs = Arc::new(Mutex::new(Vec::new()));
+        let (wake, mut woken) = mpsc::unbounded_channel();
+        let pending = jobs.clone();
+        let metrics = Arc::new(Metrics::default());
+        let counts = metrics.clone();
+        api::spawn(async move {
+            loop {
+                while !waiting(&pending) {
+                    // Every copy of the queue is gone, so there is nobody left to wake us up.
+                    if woken.recv().await.is_none() {
+                        return;
+                    }
+                }
+                let permit = permits.clone().acquire_owned().await;
+                // Whatever was in line may have been abandoned while we waited on the permit,
+                // in which case the permit goes back and we wait for something else to show up.
+                let job = match next(&pending) {
+                    Some(job) => job,
+                    None => continue,
+                };
+                let transport = transport.clone();
+                let counts = counts.clone();
+                api::spawn(async move {
+                    // The permit is held until the whole body has come down, not just the headers.
+                    let _permit = permit;
+                    counts.downloading.fetch_add(1, Ordering::SeqCst);
+                    let bytes = fetch(&*transport, &job.src).await;
+                    counts.downloading.fetch_sub(1, Ordering::SeqCst);
+                    if bytes.is_some() {
+                        counts.downloaded.fetch_add(1, Ordering::SeqCst);
+                    }
+                    let _ = job.tx.send((job.ticket, bytes));
+                });
+            }
+        });
+        Queue {
+            jobs,
+            wake,
+            metrics,
+        }
+    }
+
+    /// How things stand with this queue (and every copy of it).
+    pub fn stats(&self) -> Stats {
+        let metrics = &self.metrics;
+        Stats {
+            queued: self
+                .jobs
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|job| !job.ticket.abandoned())
+                .count(),
+            downloading: metrics.downloading.load(Ordering::SeqCst),
+            downloaded: metrics.downloaded.load(Ordering::SeqCst),
+            hits: metrics.hits.load(Ordering::SeqCst),
+            misses: metrics.misses.load(Ordering::SeqCst),
+            ..Stats::default()
+        }
+    }
+
+    /// Counts a photo as having been looked for on disk before getting in line, and whether
+    /// or not it was there.
+    pub fn looked_up(&self, hit: bool) {
+        let counter = match hit {
+            true => &self.metrics.hits,
+            false => &self.metrics.misses,
+        };
+        counter.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Gets in line to download the photo at the given source, resolving to its raw bytes once
+    /// the download has had its turn. Failed downloads go to the back of the line again after
+    /// a backoff, up to a point. Resolves to None if every attempt failed (which is logged)
+    /// or if the ticket was abandoned before the download succeeded (which is not).
+    pub async fn photo(&self, src: String, mut ticket: Ticket) -> Option<Vec<u8>> {
+        for attempt in 0..Self::ATTEMPTS {
+            if attempt > 0 {
+                let wait = backoff(Self::BACKOFF, attempt);
+                debug!(attempt, ?wait, "Retrying");
+                tokio::time::delay_for(wait).await;
+                // We're holding the ticket while we wait, so the queue can't notice for us.
+                if ticket.abandoned() {
+                    return None;
+                }
+            }
+            let (tx, rx) = oneshot::channel();
+            self.jobs.lock().unwrap().push(Job {
+                src: src.clone(),
+                ticket,
+                tx,
+            });
+            let _ = self.wake.send(());
+            match rx.await {
+                Ok((_, Some(bytes))) => return Some(bytes),
+                Ok((returned, None)) => ticket = returned,
+                // The job was thrown out of line, which only happens to abandoned tickets.
+                Err(_) => return None,
+            }
+        }
+        error!(attempts = Self::ATTEMPTS, "Giving up on the photo");
+        None
+    }
+}
+
+impl Default for Queue {
+    fn default() -> Self {
+        Self::new(DEFAULT_LIMIT)
+    }
+}
+
+/// How long to wait before the given retry (the first retry being attempt 1). The wait doubles
+/// with each attempt, and is then jittered down by up to half so that a pile of photos that all
+/// failed at the same moment don't all come back at the same moment to fail all over again.
+pub fn backoff(base: Duration, attempt: u32) -> Duration {
+    let full = base * 2u32.pow(attempt.saturating_sub(1).min(16));
+    // RandomState is seeded randomly by the standard library, which is more than random
+    // enough for this and saves us pulling in a whole crate for one number.
+    let jitter = RandomState::new().build_hasher().finish() % 1000;
+    full / 2 + full / 2 * jitter as u32 / 1000
+}
+
+// Whether or not there's anybody in line, throwing out any abandoned jobs along the way.
+fn waiting(jobs: &Mutex<Vec<Job>>) -> bool {
+    let mut jobs = jobs.lock().unwrap();
+    jobs.retain(|job| !job.ticket.abandoned());
+    !jobs.is_empty()
+}
+
+// Takes the most wanted job out of line, throwing out any abandoned ones along the way.
+fn next(jobs: &Mutex<Vec<Job>>) -> Option<Job> {
+    let mut jobs = jobs.lock().unwrap();
+    jobs.retain(|job| !job.ticket.abandoned());
+    // min_by_key hands back the first of any ties, which keeps things first come first served.
+    let index = jobs
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, job)| job.ticket.priority())
+        .map(|(index, _)| index)?;
+    Some(jobs.remove(index))
+}
+
+// Pulls down the raw bytes of the photo at the given source, logging (and giving up on)
+// any failures. The caller is the one in charge of waiting for a permit.
+async fn fetch(transport: &dyn Transport, src: &str) -> Option<Vec<u8>> {
+    match transport.get(src).await {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            warn!(error = %err, "Failed to download the photo");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(src: &str, ticket: &Ticket) -> Job {
+        let (tx, _) = oneshot::channel();
+        Job {
+            src: src.to_string(),
+            ticket: ticket.clone(),
+            tx,
+        }
+    }
+
+    #[test]
+    fn most_wanted_first() {
+        let (first, second, third) = (
+            Ticket::new(Priority::Offscreen),
+            Ticket::new(Priority::Visible),
+            Ticket::new(Priority::Visible),
+        );
+        let jobs = Mutex::new(vec![
+            job("first", &first),
+            job("second", &second),
+            job("third", &third),
+        ]);
+        assert_eq!(next(&jobs).unwrap().src, "second");
+        first.set(Priority::Focused);
+        assert_eq!(next(&jobs).unwrap().src, "first");
+        assert_eq!(next(&jobs).unwrap().src, "third");
+        assert!(next(&jobs).is_none());
+    }
+
+    // Hands back the source of whatever is asked for as the photo itself.
+    struct Echo;
+
+    impl Transport for Echo {
+        fn get<'a>(&'a self, src: &'a str) -> api::Download<'a> {
+            Box::pin(async move { Ok(src.as_bytes().to_vec()) })
+        }
+    }
+
+    #[test]
+    fn downloads_over_the_given_transport() {
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            let queue = Queue::with_transport(Arc::new(Echo), DEFAULT_LIMIT);
+            // Somebody has to be holding on to the ticket, or nobody wants the photo.
+            let ticket = Ticket::new(Priority::Focused);
+            let photo = queue.photo("cut.jpg".to_string(), ticket.clone()).await;
+            assert_eq!(photo.unwrap(), b"cut.jpg".to_vec());
+            let stats = queue.stats();
+            assert_eq!(
+                (stats.queued, stats.downloading, stats.downloaded),
+                (0, 0, 1)
+            );
+        });
+    }
+
+    #[test]
+    fn idle_queues_leave_the_permits_alone() {
+        tokio::runtime::Runtime::new().unwrap().block_on(async {
+            // The same as a limit of one, only without changing it for every other test.
+            let permits = Arc::new(Semaphore::new(1));
+            let idle = Queue::with_permits(Arc::new(Echo), permits.clone());
+            let busy = Queue::with_permits(Arc::new(Echo), permits);
+            // Give the idle queue's dispatcher every chance to take the only permit.
+            tokio::time::delay_for(Duration::from_millis(50)).await;
+            let ticket = Ticket::new(Priority::Focused);
+            for queue in [&busy, &idle].iter() {
+                let photo = queue.photo("cut.jpg".to_string(), ticket.clone());
+                let photo = tokio::time::timeout(Duration::from_secs(5), photo).await;
+                assert_eq!(photo.unwrap().unwrap(), b"cut.jpg".to_vec());
+            }
+        });
+    }
+
+    #[test]
+    fn backoff_doubles_with_jitter() {
+        let base = Duration::from_millis(500);
+        for attempt in 1..5 {
+            let full = base * 2u32.pow(attempt - 1);
+            let wait = backoff(base, attempt);
+            assert!(
+                wait >= full / 2 && wait <= full,
+                "{:?} for {}",
+                wait,
+                attempt
+            );
+        }
+    }
+
+    #[test]
+    fn abandoned_tickets_are_skipped() {
+        let (kept, dropped) = (
+            Ticket::new(Priority::Offscreen),
+            Ticket::new(Priority::Focused),
+        );
+        let jobs = Mutex::new(vec![job("dropped", &dropped), job("kept", &kept)]);
+        drop(dropped);
+        assert_eq!(next(&jobs).unwrap().src, "kept");
+        assert!(jobs.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,133 @@
+use super::{Image, Photo, Priority, Queue, Settings, Ticket};
+use image::RgbaImage;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A handful of small pictures that turn up again and again across a schedule, such as team
+/// logos and player headshots, all of them one size.
+///
+/// There are only ever 30 or so logos, and every game has two, so each picture is downloaded (or
+/// read back out of the photo cache) the once and then shared by everything that shows it.
+pub struct Gallery {
+    pictures: HashMap<String, Picture>,
+    // How many pixels across and down a picture is on the 1920x1080 design, at its biggest.
+    size: (f64, f64),
+    queue: Queue,
+}
+
+// One picture, or the download that will be.
+enum Picture {
+    Pending(crossbeam_channel::Receiver<RgbaImage>),
+    Ready(Rc<Decoded>),
+    // Not every team has a logo (or player a headshot), and those that do don't always come
+    // through.
+    Failed,
+}
+
+/// A picture that is ready to be put on screen. These are handed out by the Rc so that they can
+/// be held on to while the rest of the schedule is borrowed for its snippets.
+pub struct Decoded {
+    src: String,
+    rgba: RgbaImage,
+}
+
+impl Decoded {
+    pub fn image(&self) -> Image<'_> {
+        Image {
+            id: &self.src,
+            rgba: &self.rgba,
+            fade: None,
+            loading: false,
+        }
+    }
+}
+
+impl Gallery {
+    pub(super) fn new(size: (f64, f64), queue: &Queue) -> Gallery {
+        Gallery {
+            pictures: HashMap::new(),
+            size,
+            queue: queue.clone(),
+        }
+    }
+
+    /// Starts downloading the picture at the given source, unless it's already here or on its
+    /// way. Pictures get in line along with the photos that are on screen, as they're small and
+    /// only ever asked for when they're about to be shown. They're shrunk down to fit at the
+    /// scale that the given settings are at right then.
+    pub(super) fn want(&mut self, src: String, settings: &Settings) {
+        if !settings.photos || self.pictures.contains_key(&src) {
+            return;
+        }
+        let scale = settings.scale;
+        let scaled = |length: f64| ((length * scale).round() as u32).max(1);
+        let fit = (scaled(self.size.0), scaled(self.size.1));
+        let ticket = Ticket::new(Priority::Visible);
+        let channel = Photo::load(src.clone(), fit, ticket, &self.queue);
+        self.pictures.insert(src, Picture::Pending(channel));
+    }
+
+    /// Checks in on the downloads, returning true if any pictures have shown up since the last
+    /// time that this was called.
+    pub(super) fn poll(&mut self) -> bool {
+        let mut changed = false;
+        for (src, picture) in self.pictures.iter_mut() {
+            if let Picture::Pending(channel) = picture {
+                match channel.try_recv() {
+                    Ok(rgba) => {
+                        let src = src.clone();
+                        *picture = Picture::Ready(Rc::new(Decoded { src, rgba }));
+                        changed = true;
+                    }
+                    // Already logged by the download, and not worth a banner. Whatever it was
+                    // going to go on top of will have to do.
+                    Err(crossbeam_channel::TryRecvError::Disconnected) => {
+                        *picture = Picture::Failed
+                    }
+                    Err(crossbeam_channel::TryRecvError::Empty) => (),
+                }
+            }
+        }
+        changed
+    }
+
+    /// The picture at the given source, once it's here.
+    pub fn get(&self, src: &str) -> Option<Rc<Decoded>> {
+        match self.pictures.get(src) {
+            Some(Picture::Ready(decoded)) => Some(decoded.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pictures_show_up() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut gallery = Gallery::new((60.0, 60.0), &Queue::default());
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let (_, failed) = crossbeam_channel::bounded(1);
+            gallery
+                .pictures
+                .insert("145.png".to_string(), Picture::Pending(rx));
+            gallery
+                .pictures
+                .insert("111.png".to_string(), Picture::Pending(failed));
+            assert!(!gallery.poll());
+            assert!(gallery.get("145.png").is_none());
+            tx.send(RgbaImage::new(2, 2)).unwrap();
+            assert!(gallery.poll());
+            assert!(!gallery.poll());
+            let logo = gallery.get("145.png").unwrap();
+            assert_eq!(logo.image().id, "145.png");
+            assert!(gallery.get("111.png").is_none());
+            // Wanting it again doesn't start it over.
+            gallery.want("145.png".to_string(), &Settings::default());
+            assert!(gallery.get("145.png").is_some());
+            assert!(gallery.get("147.png").is_none());
+        });
+    }
+}
@@ -0,0 +1,302 @@
+use super::{poll, Cut, Image, Photo, Polled, Priority, Queue, Settings};
+use crate::api::content::{self, GameContent, Kind};
+use crate::paginator::Paginator;
+
+/// A game's highlight videos, or at least their titles, lengths, and thumbnails, for flipping
+/// through in the game's details. Along with them come the condensed game and the recap, once
+/// there are any.
+///
+/// The list of them comes from the game's content, which is fetched in the background the
+/// moment that this is made. The thumbnails then get in line for downloading along with every
+/// other photo in the schedule, so the ones on the current page come down first.
+pub struct Highlights {
+    /// The API's primary key for the game that these are the highlights of.
+    pub pk: u64,
+    clips: Paginator<Clip>,
+    condensed: Option<Video>,
+    recap: Option<Video>,
+    // The content that is still on its way, if it is.
+    channel: Option<crossbeam_channel::Receiver<GameContent>>,
+    queue: Queue,
+    // The schedule's, as of when these were asked for, for the thumbnails.
+    settings: Settings,
+}
+
+/// A video of the whole game, give or take, for watching in a browser.
+pub struct Video {
+    /// E.G. "Condensed Game: CWS@BOS - 9/23/19"
+    pub title: String,
+    /// E.G. "12:05"
+    pub duration: Option<String>,
+    pub url: String,
+}
+
+impl Video {
+    // Only videos that can actually be watched somewhere are worth having.
+    fn playable(video: &content::Video) -> Option<Video> {
+        Some(Video {
+            title: video.headline.clone(),
+            duration: video.duration(),
+            url: video.url()?.to_string(),
+        })
+    }
+}
+
+/// One highlight video.
+pub struct Clip {
+    /// E.G. "Lopez's strong start"
+    pub title: String,
+    /// E.G. "0:31"
+    pub duration: Option<String>,
+    thumbnail: Photo,
+}
+
+/// A clip that is ready to be put on screen.
+pub struct Thumbnail<'a> {
+    pub image: Image<'a>,
+    pub title: &'a str,
+    pub duration: Option<&'a str>,
+    /// Whether or not this is the one that the cursor is on.
+    pub focused: bool,
+}
+
+impl Highlights {
+    // As many thumbnails as fit across the bottom of the window at once.
+    pub const PAGE_SIZE: usize = 5;
+
+    pub(super) fn new(pk: u64, queue: &Queue, settings: &Settings) -> Highlights {
+        Highlights {
+            pk,
+            clips: Paginator::new(vec![], Self::PAGE_SIZE),
+            condensed: None,
+            recap: None,
+            channel: Some(content::fetch(pk)),
+            queue: queue.clone(),
+            settings: settings.clone(),
+        }
+    }
+
+    /// Whether or not the list of highlights is still on its way.
+    pub fn loading(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    /// Whether or not the game turned out not to have any highlights (or the content couldn't
+    /// be had, which makes no difference from here).
+    pub fn is_empty(&self) -> bool {
+        !self.loading() && self.clips.is_empty()
+    }
+
+    pub fn left(&mut self) {
+        if self.clips.left() {
+            self.prioritize();
+        }
+    }
+
+    pub fn right(&mut self) {
+        if self.clips.right() {
+            self.prioritize();
+        }
+    }
+
+    /// The game's condensed game, once the game is over and it has been put up.
+    pub fn condensed(&self) -> Option<&Video> {
+        self.condensed.as_ref()
+    }
+
+    /// The same as condensed, but for the recap.
+    pub fn recap(&self) -> Option<&Video> {
+        self.recap.as_ref()
+    }
+
+    pub fn has_less(&self) -> bool {
+        self.clips.has_less()
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.clips.has_more()
+    }
+
+    /// Checks in on the content and the thumbnails, returning true if anything has changed
+    /// since the last time that this was called. As with a Schedule, thumbnails that are
+    /// fading in or still loading on the current page always count as a change.
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        match poll(&mut self.channel) {
+            Polled::Arrived(content) => {
+                self.condensed = content.video(Kind::Condensed).and_then(Video::playable);
+                self.recap = content.video(Kind::Recap).and_then(Video::playable);
+                let clips = self.clips(content);
+                self.clips.replace(clips);
+                self.prioritize();
+                changed = true;
+            }
+            Polled::Failed => changed = true,
+            Polled::Waiting => (),
+        }
+        for clip in self.clips.iter_mut() {
+            changed |= clip.thumbnail.poll() || clip.thumbnail.fading();
+        }
+        changed |= self
+            .clips
+            .current_page()
+            .iter()
+            .any(|clip| clip.thumbnail.pending());
+        changed
+    }
+
+    /// Every clip on the current page.
+    pub fn page(&mut self) -> Vec<Thumbnail<'_>> {
+        let focus = self.clips.page_focus();
+        self.clips
+            .current_page_mut()
+            .iter_mut()
+            .enumerate()
+            .map(|(index, clip)| Thumbnail {
+                image: clip.thumbnail.image(Cut::Small),
+                title: &clip.title,
+                duration: clip.duration.as_deref(),
+                focused: index == focus,
+            })
+            .collect()
+    }
+
+    fn clips(&self, content: GameContent) -> Vec<Clip> {
+        let (width, _) = Cut::Small.dimensions();
+        content
+            .highlights()
+            .into_iter()
+            .map(|highlight| Clip {
+                thumbnail: Photo::carry_over(
+                    None,
+                    highlight.thumbnail(width).map(str::to_string),
+                    Cut::Small,
+                    &self.queue,
+                    &self.settings,
+                ),
+                duration: highlight.duration(),
+                title: highlight.headline,
+            })
+            .collect()
+    }
+
+    // Moves the thumbnails on the current page up in line, and everything else to the back.
+    fn prioritize(&self) {
+        let bounds = self.clips.bounds();
+        for (index, clip) in self.clips.iter().enumerate() {
+            clip.thumbnail.ticket.set(match bounds.contains(&index) {
+                true => Priority::Visible,
+                false => Priority::Offscreen,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_through_clips() {
+        // The queue kicks its dispatcher off onto the runtime, so we need one around.
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let mut highlights = Highlights {
+                pk: 0,
+                clips: Paginator::new(vec![], Highlights::PAGE_SIZE),
+                condensed: None,
+                recap: None,
+                channel: Some(rx),
+                queue: Queue::default(),
+                settings: Settings::default(),
+            };
+            assert!(highlights.loading());
+            assert!(!highlights.poll());
+            let items: Vec<String> = (0..7)
+                .map(|i| {
+                    format!(
+                        r#"{{"headline": "Clip {}", "duration": "00:00:3{}"}}"#,
+                        i, i
+                    )
+                })
+                .collect();
+            let json = format!(
+                r#"{{"highlights": {{"highlights": {{"items": [{}]}}}}}}"#,
+                items.join(",")
+            );
+            tx.send(serde_json::from_str(&json).unwrap()).unwrap();
+            assert!(highlights.poll());
+            assert!(!highlights.loading());
+            assert!(!highlights.is_empty());
+            // None of these clips are the whole game.
+            assert!(highlights.condensed().is_none());
+            assert!(highlights.recap().is_none());
+            let page = highlights.page();
+            assert_eq!(page.len(), Highlights::PAGE_SIZE);
+            assert_eq!(page[0].title, "Clip 0");
+            assert_eq!(page[0].duration, Some("0:30"));
+            assert!(page[0].focused);
+            // No thumbnail at all means the logo, rather than waiting forever.
+            assert!(!page[0].image.loading);
+            for _ in 0..Highlights::PAGE_SIZE {
+                highlights.right();
+            }
+            assert!(highlights.has_less());
+            let page = highlights.page();
+            assert_eq!(page.len(), 2);
+            assert!(page[0].focused);
+            assert_eq!(page[0].title, "Clip 5");
+        });
+    }
+
+    #[test]
+    fn whole_game_videos() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let mut highlights = Highlights {
+                pk: 0,
+                clips: Paginator::new(vec![], Highlights::PAGE_SIZE),
+                condensed: None,
+                recap: None,
+                channel: Some(rx),
+                queue: Queue::default(),
+                settings: Settings::default(),
+            };
+            let content = r#"{"media": {"epgAlternate": [
+                {"title": "Extended Highlights", "items": [{"headline": "Nowhere to watch it"}]},
+                {"title": "Daily Recap", "items": [{
+                    "headline": "Recap: CWS 8, BOS 1",
+                    "duration": "00:03:05",
+                    "playbacks": [{"name": "mp4Avc", "url": "recap.mp4"}]
+                }]}
+            ]}}"#;
+            tx.send(serde_json::from_str(content).unwrap()).unwrap();
+            assert!(highlights.poll());
+            assert!(highlights.condensed().is_none());
+            let recap = highlights.recap().unwrap();
+            assert_eq!(recap.title, "Recap: CWS 8, BOS 1");
+            assert_eq!(recap.duration.as_deref(), Some("3:05"));
+            assert_eq!(recap.url, "recap.mp4");
+        });
+    }
+
+    #[test]
+    fn failed_content() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let (_, rx) = crossbeam_channel::bounded(1);
+            let mut highlights = Highlights {
+                pk: 0,
+                clips: Paginator::new(vec![], Highlights::PAGE_SIZE),
+                condensed: None,
+                recap: None,
+                channel: Some(rx),
+                queue: Queue::default(),
+                settings: Settings::default(),
+            };
+            assert!(highlights.poll());
+            assert!(!highlights.loading());
+            assert!(highlights.is_empty());
+            assert!(highlights.page().is_empty());
+        });
+    }
+}
@@ -0,0 +1,148 @@
+use crate::api::live::{Linescore, LinescoreTeam};
+
+/// A linescore laid out the way that it is in the paper: a column for each inning, then the
+/// runs, hits, and errors, with the away team on top and the home team underneath. Every cell
+/// is already written out, so all that's left is lining them up.
+#[derive(Debug, PartialEq)]
+pub struct Grid {
+    /// A blank over the teams, then the innings, then R, H, and E.
+    pub header: Vec<String>,
+    pub away: Vec<String>,
+    pub home: Vec<String>,
+}
+
+impl Grid {
+    // There are always at least this many innings to fill in, played or not.
+    const INNINGS: u32 = 9;
+
+    /// Lays out the given linescore for the given teams (E.G. "CWS" and "BOS"). Once a game is
+    /// over, a half inning that never got played (the bottom of the 9th, usually) gets an X
+    /// rather than being left blank like one that simply hasn't been played yet.
+    pub fn new(linescore: &Linescore, away: &str, home: &str, over: bool) -> Grid {
+        let innings = (linescore.innings.len() as u32).max(Self::INNINGS);
+        let mut header = vec![String::new()];
+        header.extend((1..=innings).map(|inning| inning.to_string()));
+        header.extend(["R", "H", "E"].iter().map(|total| total.to_string()));
+        let half = |inning: u32, home: bool| {
+            let played = linescore.innings.iter().find(|played| played.num == inning);
+            let runs = played.and_then(|played| match home {
+                true => played.home.runs,
+                false => played.away.runs,
+            });
+            match (runs, played) {
+                (Some(runs), _) => runs.to_string(),
+                (None, Some(_)) if over => "X".to_string(),
+                _ => String::new(),
+            }
+        };
+        let row = |name: &str, team: &LinescoreTeam, home: bool| {
+            let mut row = vec![name.to_string()];
+            row.extend((1..=innings).map(|inning| half(inning, home)));
+            row.extend(
+                [team.runs, team.hits, team.errors]
+                    .iter()
+                    .map(u32::to_string),
+            );
+            row
+        };
+        Grid {
+            away: row(away, &linescore.teams.away, false),
+            home: row(home, &linescore.teams.home, true),
+            header,
+        }
+    }
+
+    /// The column that the runs, hits, and errors start at, for setting them off from the
+    /// innings.
+    pub fn totals(&self) -> usize {
+        self.header.len() - 3
+    }
+
+    /// The header and both teams, top to bottom.
+    pub fn rows(&self) -> [&[String]; 3] {
+        [&self.header, &self.away, &self.home]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linescore(innings: &[(Option<u32>, Option<u32>)]) -> Linescore {
+        let innings: Vec<String> = innings
+            .iter()
+            .enumerate()
+            .map(|(index, (away, home))| {
+                let half = |runs: &Option<u32>| match runs {
+                    Some(runs) => format!(r#"{{"runs": {}}}"#, runs),
+                    None => "{}".to_string(),
+                };
+                format!(
+                    r#"{{"num": {}, "away": {}, "home": {}}}"#,
+                    index + 1,
+                    half(away),
+                    half(home)
+                )
+            })
+            .collect();
+        serde_json::from_str(&format!(
+            r#"{{
+                "innings": [{}],
+                "teams": {{
+                    "away": {{"runs": 8, "hits": 11, "errors": 0}},
+                    "home": {{"runs": 1, "hits": 4, "errors": 2}}
+                }}
+            }}"#,
+            innings.join(",")
+        ))
+        .unwrap()
+    }
+
+    fn cells(row: &[String]) -> Vec<&str> {
+        row.iter().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn over() {
+        let mut innings = vec![(Some(0), Some(0)); 8];
+        innings[2] = (Some(5), Some(1));
+        innings.push((Some(3), None));
+        let grid = Grid::new(&linescore(&innings), "CWS", "BOS", true);
+        assert_eq!(
+            cells(&grid.header),
+            vec!["", "1", "2", "3", "4", "5", "6", "7", "8", "9", "R", "H", "E"]
+        );
+        assert_eq!(
+            cells(&grid.away),
+            vec!["CWS", "0", "0", "5", "0", "0", "0", "0", "0", "3", "8", "11", "0"]
+        );
+        assert_eq!(
+            cells(&grid.home),
+            vec!["BOS", "0", "0", "1", "0", "0", "0", "0", "0", "X", "1", "4", "2"]
+        );
+        assert_eq!(grid.totals(), 10);
+        assert_eq!(grid.rows()[2][0], "BOS");
+    }
+
+    #[test]
+    fn in_progress() {
+        let grid = Grid::new(
+            &linescore(&[(Some(1), Some(0)), (Some(0), None)]),
+            "CWS",
+            "BOS",
+            false,
+        );
+        // The rest of the game is still to come, so it's left blank.
+        assert_eq!(grid.home[2], "");
+        assert_eq!(grid.home[9], "");
+        assert_eq!(grid.header.len(), 13);
+    }
+
+    #[test]
+    fn extra_innings() {
+        let grid = Grid::new(&linescore(&[(Some(0), Some(0)); 11]), "CWS", "BOS", true);
+        assert_eq!(grid.header[11], "11");
+        assert_eq!(grid.header.len(), 15);
+        assert_eq!(grid.totals(), 12);
+    }
+}
@@ -1,16 +1,51 @@
 use crate::api;
+use crate::cache::Cache;
+use crate::paginator::Paginator;
+use chrono_tz::Tz;
+use download::{Priority, Queue, Ticket};
+use image::imageops::{self, FilterType};
 use image::{ImageFormat, RgbaImage};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info_span, Instrument};
+
+mod calendar;
+pub mod download;
+mod gallery;
+mod highlights;
+mod linescore;
+mod player;
+mod probables;
+mod rows;
+mod shimmer;
+mod team;
+mod ticker;
+mod transactions;
+mod week;
+
+pub use calendar::Calendar;
+pub use gallery::{Decoded, Gallery};
+pub use highlights::{Clip, Highlights, Thumbnail, Video};
+pub use linescore::Grid;
+pub use player::{Card, Group, Player, StatLine};
+pub use probables::Probables;
+pub use rows::{Lineup, Row};
+pub use team::{Page, Team};
+pub use ticker::{Score, Ticker};
+pub use transactions::{Move, Transactions};
+pub use week::{Day, Line, Week};
 
 // Including the bytes here can be argued. On one hand it makes the bundling of the whole
 // application just so much easier and reduces the runtime shenanigans that can occur
 // due to bad filesystem reads. On the other hand, we are totally at the mercy of the OS
 // here to eject these from memory when appropriate. That is, these are the default
-// images that show up when the initial photos are loading, so at some point these
-// are going to be totally unnecessary (until they are again). At that point we could
-// let these assets fall out of scope and let their destructors runs which gives us
-// fine grained control of when these get dumped out of memory. Now, the OS WILL evict
-// these if it deems it necessary, but it doesn't have as such deep insight into how these
-// buffers are being used so it can't be as intelligent.
+// images that stand in for photos that never showed up (or that would spoil a game), so
+// much of the time these are going to be totally unnecessary (until they are again). At
+// that point we could let these assets fall out of scope and let their destructors runs
+// which gives us fine grained control of when these get dumped out of memory. Now, the
+// OS WILL evict these if it deems it necessary, but it doesn't have as such deep insight
+// into how these buffers are being used so it can't be as intelligent.
 static MLB_LOGO_LARGE_BYTES: &[u8] = include_bytes!("../../assets/mlb_logo_large.jpg");
 static MLB_LOGO_SMALL_BYTES: &[u8] = include_bytes!("../../assets/mlb_logo_small.jpg");
 
@@ -26,72 +61,731 @@ lazy_static! {
         image::load_from_memory_with_format(MLB_LOGO_SMALL_BYTES, ImageFormat::JPEG)
             .unwrap()
             .into_rgba();
+    // The photos for a given game don't change once the recap is up, so there's no sense
+    // in downloading the same ones over again every time the app starts or a date is revisited.
+    static ref PHOTO_CACHE: Cache = Cache::user("photos", Duration::from_secs(7 * 24 * 60 * 60));
+}
+
+/// How many bytes' worth of decoded photos a Lineup holds on to when nobody says otherwise.
+pub const DEFAULT_BUDGET: usize = 128 * 1024 * 1024;
+
+/// How many games make up a page of the carousel when nobody says otherwise.
+pub const DEFAULT_PAGE_SIZE: usize = 5;
+
+/// How a Lineup (and every schedule in it) goes about things, which is up to whoever is putting
+/// it on screen. Most of this is settled once at the start, but the page size and the scale
+/// follow the window around, by way of Lineup::set_page_size and Lineup::set_scale.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// The teams whose games go to the front of every schedule. Teams go by their id, name, or
+    /// abbreviation (E.G. "111", "Boston Red Sox", or "BOS"), without regard to case.
+    pub favorites: Vec<String>,
+    /// The timezone that start times are given in, or None for whatever timezone the machine
+    /// is set to.
+    pub timezone: Option<Tz>,
+    /// How many bytes' worth of decoded photos may be held on to at once. Past that, the photos
+    /// that have gone unseen the longest are thrown out, to be read back out of the disk cache
+    /// if they're ever scrolled back to.
+    pub budget: usize,
+    /// Whether or not games get photos at all. Without them, every game goes without as if
+    /// nothing had ever been written about it, and nothing is downloaded. This is for frontends
+    /// that have no way of showing them anyways, such as a terminal.
+    pub photos: bool,
+    /// Whether or not photos snap into place as soon as they show up, rather than fading in over
+    /// top of whatever stood in for them. This is for anybody who would rather the screen didn't
+    /// move around on its own any more than it has to.
+    pub reduced_motion: bool,
+    /// How many games make up a page of every schedule's carousel.
+    pub page_size: usize,
+    /// How many pixels on the screen there are to one pixel of the 1920x1080 design, which is
+    /// what photos are shrunk down to fit as they're decoded. A 480x270 cut that is only ever
+    /// going to be drawn 320x180 on a 720p window has no business holding on to the rest.
+    pub scale: f64,
+    /// How many photos may be downloading at once.
+    pub max_downloads: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            favorites: vec![],
+            timezone: None,
+            budget: DEFAULT_BUDGET,
+            photos: true,
+            reduced_motion: false,
+            page_size: DEFAULT_PAGE_SIZE,
+            scale: 1.0,
+            max_downloads: download::DEFAULT_LIMIT,
+        }
+    }
+}
+
+// Marks every game that a favorite team is playing in and then puts the games in the given
+// order, which by default moves the favorites up front. The sorts are stable ones, so games
+// that tie stay in the order that the API gave.
+fn sort_games(games: &mut [Game], sort: Sort, favorites: &[String]) {
+    for game in games.iter_mut() {
+        game.favorite = game.away.is_one_of(favorites) || game.home.is_one_of(favorites);
+    }
+    match sort {
+        Sort::Favorites => games.sort_by_key(|game| !game.favorite),
+        // Games without a start time (which the API has been known to do for ones that
+        // haven't been scheduled yet) go at the end.
+        Sort::Time => games.sort_by_key(|game| (game.start.is_none(), game.start)),
+        Sort::Status => games.sort_by_key(|game| match game.state {
+            State::Live => 0,
+            State::Preview => 1,
+            State::Final => 2,
+        }),
+    }
+}
+
+/// How the games on the carousel are put in order.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sort {
+    /// The favorite teams' games up front, and everything else the way that the API has them.
+    Favorites,
+    /// By when the first pitch is (or was), favorites or not.
+    Time,
+    /// The games being played first, then the ones still to come, then the ones that are over.
+    Status,
+}
+
+impl Sort {
+    /// The next one along, for flipping through them, which goes back around to favorites
+    /// after status.
+    pub fn next(self) -> Sort {
+        match self {
+            Sort::Favorites => Sort::Time,
+            Sort::Time => Sort::Status,
+            Sort::Status => Sort::Favorites,
+        }
+    }
+}
+
+// What checking in on a one-off lookup (E.G. one from api::fetch_once) turned up.
+enum Polled<T> {
+    // Nothing yet, or nothing being waited on in the first place.
+    Waiting,
+    Arrived(T),
+    // The lookup hung up without sending anything, which it only does when it failed. It has
+    // already logged as much.
+    Failed,
+}
+
+// Checks in on a one-off lookup, letting go of the channel once the lookup has either come
+// through or given up.
+fn poll<T>(channel: &mut Option<crossbeam_channel::Receiver<T>>) -> Polled<T> {
+    let polled = match channel {
+        Some(channel) => channel.try_recv(),
+        None => return Polled::Waiting,
+    };
+    match polled {
+        Ok(found) => {
+            *channel = None;
+            Polled::Arrived(found)
+        }
+        Err(crossbeam_channel::TryRecvError::Disconnected) => {
+            *channel = None;
+            Polled::Failed
+        }
+        Err(crossbeam_channel::TryRecvError::Empty) => Polled::Waiting,
+    }
+}
+
+/// Which games the carousel shows, by how far along they are.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Progress {
+    /// Every game.
+    All,
+    /// Every game but the ones that are over.
+    Unfinished,
+    /// Only the games that are being played right now.
+    Live,
+}
+
+impl Progress {
+    /// The next one along, for flipping through them: every game, then the ones that aren't
+    /// over, then the ones in progress, and then back around to every game.
+    pub fn next(self) -> Progress {
+        match self {
+            Progress::All => Progress::Unfinished,
+            Progress::Unfinished => Progress::Live,
+            Progress::Live => Progress::All,
+        }
+    }
+
+    // Whether or not a game in the given state makes the cut.
+    fn shows(self, state: State) -> bool {
+        match self {
+            Progress::All => true,
+            Progress::Unfinished => state != State::Final,
+            Progress::Live => state == State::Live,
+        }
+    }
 }
 
 /// A Schedule is a scrollable listing of games from a particular date
 pub struct Schedule {
-    pub games: Vec<Game>,
-    cursor: usize,
+    pub games: Paginator<Game>,
+    // Where all of this schedule's photos wait their turn to download.
+    queue: Queue,
+    // Every team's logo, shared by all of their games.
+    logos: Gallery,
+    // The headshots of whichever players have been asked after.
+    headshots: Gallery,
+    // Whether or not games that haven't been revealed keep how they went to themselves.
+    spoiler_free: bool,
+    // The (lowercase) teams that the carousel is restricted to, if it is restricted at all.
+    filter: Option<Vec<String>>,
+    // Which games are kept off of the carousel by how far along they are, on top of the filter.
+    progress: Progress,
+    // How the games that are shown are put in order.
+    sort: Sort,
+    // The games that the filter (or progress) is keeping off of the carousel. They're held on
+    // to, photos and all, for when the filter is lifted.
+    set_aside: Vec<Game>,
+    // How many photos have given up on downloading since the last time that anybody asked.
+    failures: usize,
+    // The club that the last search turned up, for opening their page rather than the other
+    // club's.
+    searched: Option<u32>,
+    settings: Settings,
 }
 
 impl Schedule {
-    const PAGE_SIZE: usize = 5;
+    // How big a team's logo is on the design, at its biggest. The large snippet is 270 tall and
+    // its logos a fifth of that, with a little to spare.
+    const LOGO: (f64, f64) = (60.0, 60.0);
+    // And a player's headshot, which is as big as it comes on their page.
+    const HEADSHOT: (f64, f64) = (213.0, 320.0);
 
     pub fn left(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
+        if self.games.left() {
+            self.prioritize();
         }
     }
 
     pub fn right(&mut self) {
-        if self.cursor < self.games.len() - 2 {
-            self.cursor += 1;
+        if self.games.right() {
+            self.prioritize();
+        }
+    }
+
+    /// Skips a whole page of games to the right, for the days with 15 games on the slate.
+    pub fn next_page(&mut self) {
+        if self.games.next_page() {
+            self.prioritize();
+        }
+    }
+
+    /// Skips a whole page of games to the left.
+    pub fn previous_page(&mut self) {
+        if self.games.previous_page() {
+            self.prioritize();
+        }
+    }
+
+    /// Puts the cursor on the game at the given index (E.G. one that was clicked on).
+    pub fn focus(&mut self, index: usize) {
+        self.games.focus(index);
+        self.prioritize();
+    }
+
+    /// Jumps straight to the first game of the day.
+    pub fn first(&mut self) {
+        if self.games.to_first() {
+            self.prioritize();
+        }
+    }
+
+    /// Turns spoiler-free mode on or off. While it is on, any game that has gotten underway
+    /// shows only its matchup and first pitch (and the MLB logo in place of its photos) until
+    /// it is revealed.
+    pub fn set_spoiler_free(&mut self, spoiler_free: bool) {
+        self.spoiler_free = spoiler_free;
+    }
+
+    /// Restricts the carousel to the games that any of the given teams (by id, name, or
+    /// abbreviation, without regard to case) are playing in, or lifts the restriction for None.
+    /// Paging works over only the games that are left, and the focus stays on the same game
+    /// if it is still around.
+    pub fn set_filter<T: AsRef<str>>(&mut self, teams: Option<&[T]>) {
+        self.filter = teams.map(|teams| {
+            teams
+                .iter()
+                .map(|team| team.as_ref().to_lowercase())
+                .collect()
+        });
+        self.rearrange();
+    }
+
+    /// Keeps games off of the carousel by how far along they are, E.G. the ones that are over
+    /// for the evenings when only what's still to play matters. This goes along with the
+    /// filter, and like the filter the focus stays on the same game if it is still around. If
+    /// it isn't, the game that took its place gets it.
+    ///
+    /// Games come and go on their own as they start and finish, whenever the schedule is
+    /// merged.
+    pub fn set_progress(&mut self, progress: Progress) {
+        self.progress = progress;
+        self.rearrange();
+    }
+
+    /// Puts the games in a different order, with the focus staying on the same game wherever
+    /// it ends up.
+    pub fn set_sort(&mut self, sort: Sort) {
+        self.sort = sort;
+        self.rearrange();
+    }
+
+    /// Changes how many games make up a page (E.G. when the window is resized). The schedule
+    /// re-pages itself the next time that it's polled.
+    pub fn set_page_size(&mut self, size: usize) {
+        self.settings.page_size = size.max(1);
+    }
+
+    /// Changes how many pixels on the screen there are to one pixel of the design. Photos that
+    /// are on screen are read back in at the new size the next time that the schedule is
+    /// polled, so that they don't go blurry when the window grows.
+    pub fn set_scale(&mut self, scale: f64) {
+        self.settings.scale = scale;
+    }
+
+    /// Puts the cursor on the game whose teams best match what the user has typed so far,
+    /// returning whether or not anything matched at all. An abbreviation spelled out in full
+    /// beats the start of a word in a team's name, which beats the letters merely showing up
+    /// in order (E.G. "nyy", then "yank", then "ynks" for the Yankees). Ties go to whichever
+    /// game comes first.
+    pub fn search(&mut self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        let best = self
+            .games
+            .iter()
+            .enumerate()
+            .filter_map(|(index, game)| {
+                let away = game.away.search(&query).map(|rank| (rank, game.away.id));
+                let home = game.home.search(&query).map(|rank| (rank, game.home.id));
+                away.into_iter()
+                    .chain(home)
+                    .min_by_key(|(rank, _)| *rank)
+                    .map(|(rank, team)| (rank, index, team))
+            })
+            .min();
+        self.searched = best.map(|(_, _, team)| team);
+        match best {
+            Some((_, index, _)) => {
+                self.focus(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The club whose page goes with the focused game. That's whichever of the two clubs was
+    /// last searched for, if either was, and the home club otherwise.
+    pub fn team(&mut self) -> Option<u32> {
+        let searched = self.searched;
+        let game = self.focused()?;
+        Some(match searched {
+            Some(team) if team == game.away.id => team,
+            _ => game.home.id,
+        })
+    }
+
+    /// Whether or not some of this date's games are being kept off of the carousel.
+    pub fn is_filtered(&self) -> bool {
+        self.filter.is_some() || self.progress != Progress::All
+    }
+
+    /// Reveals the focused game, for when the user is all caught up on it.
+    pub fn reveal(&mut self) {
+        if let Some(game) = self.games.focused_mut() {
+            game.revealed = true;
+        }
+    }
+
+    /// Jumps straight to the last game of the day.
+    pub fn last(&mut self) {
+        if self.games.to_last() {
+            self.prioritize();
+        }
+    }
+
+    // Builds a schedule out of a single date's worth of games, whose photos go through
+    // the given queue.
+    fn with_queue(games: Vec<api::Game>, queue: Queue, mut settings: Settings) -> Schedule {
+        // The favorites are lowercased the once here, rather than for every game they're
+        // checked against.
+        settings.favorites = settings
+            .favorites
+            .iter()
+            .map(|team| team.to_lowercase())
+            .collect();
+        let games = games
+            .into_iter()
+            .enumerate()
+            .map(|(order, game)| Game::new(game, None, &queue, &settings).in_order(order))
+            .collect();
+        let mut schedule = Schedule {
+            games: Paginator::new(vec![], settings.page_size.max(1)),
+            logos: Gallery::new(Self::LOGO, &queue),
+            headshots: Gallery::new(Self::HEADSHOT, &queue),
+            queue,
+            spoiler_free: false,
+            filter: None,
+            progress: Progress::All,
+            sort: Sort::Favorites,
+            set_aside: vec![],
+            failures: 0,
+            searched: None,
+            settings,
+        };
+        schedule.arrange(games, None, 0);
+        schedule
+    }
+
+    // Lays the given games out on the carousel: in the order that the API gave them, then in
+    // whichever order was asked for, and then with the filter's rejects (and the games that
+    // progress keeps off) set aside. The focus goes back to the game that had it, or failing
+    // that stays put (or as close to put as it can).
+    fn arrange(&mut self, mut games: Vec<Game>, focused: Option<u64>, cursor: usize) {
+        for game in games.iter() {
+            self.logos
+                .want(api::Team::logo(game.away.id), &self.settings);
+            self.logos
+                .want(api::Team::logo(game.home.id), &self.settings);
+        }
+        games.sort_by_key(|game| game.order);
+        sort_games(&mut games, self.sort, &self.settings.favorites);
+        let (filter, progress) = (&self.filter, self.progress);
+        let (shown, set_aside) = games.into_iter().partition(|game: &Game| {
+            let wanted = match filter {
+                Some(teams) => game.away.is_one_of(teams) || game.home.is_one_of(teams),
+                None => true,
+            };
+            wanted && progress.shows(game.state)
+        });
+        self.set_aside = set_aside;
+        self.games.replace(shown);
+        let index = focused
+            .and_then(|pk| self.games.iter().position(|game| game.pk == pk))
+            .unwrap_or(cursor);
+        self.games.focus(index);
+        self.prioritize();
+    }
+
+    // Lays the games back out after the filter (or progress, or the sort) has changed, keeping
+    // the focus where it was.
+    fn rearrange(&mut self) {
+        let (focused, cursor) = (
+            self.games.focused().map(|game| game.pk),
+            self.games.cursor(),
+        );
+        let mut games = self.games.replace(vec![]);
+        games.append(&mut self.set_aside);
+        self.arrange(games, focused, cursor);
+    }
+
+    // Moves the photos that are on screen to the front of the download line, with the focused
+    // game's large photo at the very front, and everything else to the back.
+    fn prioritize(&mut self) {
+        self.prioritize_as(Visibility::Focused);
+    }
+
+    fn prioritize_as(&self, visibility: Visibility) {
+        let bounds = self.games.bounds();
+        let cursor = self.games.cursor();
+        for (index, game) in self.games.iter().enumerate() {
+            let visible = bounds.contains(&index);
+            game.large.ticket.set(match visibility {
+                Visibility::Focused if index == cursor => Priority::Focused,
+                _ => Priority::Offscreen,
+            });
+            game.small.ticket.set(match visibility {
+                Visibility::Focused if visible && index != cursor => Priority::Visible,
+                Visibility::Strip if visible => Priority::Visible,
+                _ => Priority::Offscreen,
+            });
+        }
+        for game in self.set_aside.iter() {
+            game.large.ticket.set(Priority::Offscreen);
+            game.small.ticket.set(Priority::Offscreen);
         }
     }
 
+    /// The small photos of every game on the current page, for when this schedule
+    /// isn't the one in focus but is still on screen.
+    pub fn strip(&mut self) -> Vec<Image<'_>> {
+        let spoiler_free = self.spoiler_free;
+        self.games
+            .current_page_mut()
+            .iter_mut()
+            .map(|game| match spoiler_free && game.spoils() {
+                true => small_logo(),
+                false => game.small.image(Cut::Small),
+            })
+            .collect()
+    }
+
+    /// The focused game's photo, blurred and darkened for going in behind everything else, once
+    /// it has shown up. Games that would spoil how they went don't get one.
+    pub fn backdrop(&mut self) -> Option<Image<'_>> {
+        let spoiler_free = self.spoiler_free;
+        self.games
+            .focused_mut()
+            .filter(|game| !(spoiler_free && game.spoils()))
+            .and_then(Game::backdrop)
+    }
+
+    /// Where the focused game's story can be read in full, unless it would spoil how the game went.
+    pub fn story(&mut self) -> Option<&str> {
+        let spoiler_free = self.spoiler_free;
+        self.games
+            .focused_mut()
+            .filter(|game| !(spoiler_free && game.spoils()))
+            .and_then(|game| game.story.as_deref())
+    }
+
+    /// The focused game's highlights, unless they would spoil how the game went. Whichever
+    /// highlights were being shown before are handed back as they are if they're for that
+    /// same game, rather than starting them all over again.
+    pub fn highlights(&mut self, previous: Option<Highlights>) -> Option<Highlights> {
+        let spoiler_free = self.spoiler_free;
+        let game = self
+            .games
+            .focused()
+            .filter(|game| !(spoiler_free && game.spoils()))?;
+        match previous {
+            Some(previous) if previous.pk == game.pk => Some(previous),
+            _ => Some(Highlights::new(game.pk, &self.queue, &self.settings)),
+        }
+    }
+
+    /// The focused game's live feed (its inning-by-inning linescore and scoring plays), for games
+    /// that are over (unless that would spoil how they went). Games in progress already have
+    /// theirs, in Game::linescore and Game::scoring_plays. As with highlights, the previous one
+    /// is handed back as is if it's for the same game.
+    pub fn feed(&mut self, previous: Option<Live>) -> Option<Live> {
+        let spoiler_free = self.spoiler_free;
+        let game = self
+            .games
+            .focused()
+            .filter(|game| game.state == State::Final && !(spoiler_free && game.spoils()))?;
+        match previous {
+            Some(previous) if previous.pk == game.pk => Some(previous),
+            _ => Some(Live::once(game.pk)),
+        }
+    }
+
+    /// Whether or not there are any games on this date at all. Off days, the All-Star break,
+    /// and the entire winter are all full of dates like this. So are dates where the filter
+    /// didn't leave anything behind.
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// The game that the cursor is currently sitting on.
+    pub fn focused(&mut self) -> Option<&mut Game> {
+        self.games.focused_mut()
+    }
+
+    /// Checks in on every background download (photos and live feeds), returning true if
+    /// anything new has shown up since the last time that this was called. That is, whether
+    /// or not what is on screen is now out of date. Photos that are still fading in, and
+    /// those on the current page that are still loading, are always out of date.
+    pub fn poll(&mut self) -> bool {
+        let mut changed = self.repage(self.settings.page_size);
+        let (queue, scale) = (&self.queue, self.settings.scale);
+        for game in self.games.iter_mut() {
+            // Anything that was thrown out to make room comes back as soon as it's on screen, as
+            // does anything that was decoded for a window of some other size.
+            for (photo, cut) in
+                [(&mut game.large, Cut::Large), (&mut game.small, Cut::Small)].iter_mut()
+            {
+                if !photo.ticket.is_offscreen() {
+                    photo.restore(*cut, scale, queue);
+                    photo.refit(*cut, scale, queue);
+                }
+            }
+            let (large, small) = (game.large.poll(), game.small.poll());
+            changed |= large || game.large.fading();
+            changed |= small || game.small.fading();
+            self.failures += (large && game.large.failed) as usize;
+            self.failures += (small && game.small.failed) as usize;
+            game.share_previews();
+            if let Some(live) = &mut game.live {
+                changed |= live.poll();
+            }
+            if let Some(probables) = &mut game.probables {
+                changed |= probables.poll();
+            }
+        }
+        changed |= self.logos.poll() | self.headshots.poll();
+        // The spinners over the photos that are still on their way are always on the move.
+        let spoiler_free = self.spoiler_free;
+        changed |= self.games.current_page().iter().any(|game| {
+            !(spoiler_free && game.spoils()) && (game.large.pending() || game.small.pending())
+        });
+        changed
+    }
+
+    // Switches over to pages of the given size, if they aren't that size already, returning
+    // whether or not they weren't. Whatever moved on or off of the page moves in line with it.
+    fn repage(&mut self, size: usize) -> bool {
+        if self.games.page_size() == size {
+            return false;
+        }
+        self.games.set_page_size(size);
+        self.prioritize();
+        true
+    }
+
+    // Every photo in the schedule, filtered out or not.
+    fn photos_mut(&mut self) -> impl Iterator<Item = &mut Photo> {
+        self.games
+            .iter_mut()
+            .chain(self.set_aside.iter_mut())
+            .flat_map(|game| vec![&mut game.large, &mut game.small])
+    }
+
+    /// How many photos have failed to download since the last time that this was called.
+    /// Those failures are already logged, this is for letting the user in on it.
+    pub fn take_failures(&mut self) -> usize {
+        std::mem::replace(&mut self.failures, 0)
+    }
+
+    /// Folds a freshly downloaded copy of this same schedule into this one.
+    ///
+    /// Scores, statuses, headlines, and so on are all taken from the fresh copy. Photos,
+    /// however, are only re-downloaded if their source has actually changed, which is the
+    /// entire point of doing this rather than just building a brand new Schedule. The cursor
+    /// stays on whichever game it was on, if that game is still around.
+    pub fn merge(&mut self, mut fresh: api::Schedule) {
+        // An empty refresh is far more likely to be a hiccup on the other end
+        // than every game on the slate vanishing, so we hold on to what we have.
+        if fresh.is_empty() {
+            return;
+        }
+        self.merge_games(fresh.dates.pop().map(|date| date.games).unwrap_or_default());
+    }
+
+    // The guts of merge, for a single date's worth of games.
+    fn merge_games(&mut self, fresh: Vec<api::Game>) {
+        let (focused, cursor) = (
+            self.games.focused().map(|game| game.pk),
+            self.games.cursor(),
+        );
+        let mut previous: HashMap<u64, Game> = self
+            .games
+            .replace(vec![])
+            .into_iter()
+            .chain(self.set_aside.drain(..))
+            .map(|game| (game.pk, game))
+            .collect();
+        let (queue, settings) = (&self.queue, &self.settings);
+        let games = fresh
+            .into_iter()
+            .enumerate()
+            .map(|(order, game)| {
+                let pk = game.game_pk;
+                Game::new(game, previous.remove(&pk), queue, settings).in_order(order)
+            })
+            .collect();
+        self.arrange(games, focused, cursor);
+    }
+
     /// Queries whether or not there is an additional page of content to the right
     /// of the current page.
     pub fn has_more(&self) -> bool {
-        self.cursor < self.games.len() - Self::PAGE_SIZE
+        self.games.has_more()
     }
 
     /// Queries whether or not there is an additional page of content to the left
     /// of the current page.
     pub fn has_less(&self) -> bool {
-        self.cursor > Self::PAGE_SIZE - 1
+        self.games.has_less()
+    }
+
+    /// The headshot of the player with the given id (E.G. one of a game's probable pitchers, or
+    /// the pitcher who got the win), once it has shown up. The first time that a player is asked
+    /// after starts the download, and after that it's kept around for as long as the schedule.
+    pub fn headshot(&mut self, person: u64) -> Option<Rc<Decoded>> {
+        let src = api::people::headshot(person);
+        let headshot = self.headshots.get(&src);
+        if headshot.is_none() {
+            self.headshots.want(src, &self.settings);
+        }
+        headshot
+    }
+
+    /// The away and home teams' logos for every game on the current page, in the same order as
+    /// page. Either one is None until it has shown up.
+    pub fn logos(&self) -> Vec<[Option<Rc<Decoded>>; 2]> {
+        self.games
+            .current_page()
+            .iter()
+            .map(|game| {
+                [
+                    self.logos.get(&api::Team::logo(game.away.id)),
+                    self.logos.get(&api::Team::logo(game.home.id)),
+                ]
+            })
+            .collect()
     }
 
-    /// Returns the list of game snippets for the current page. Each page has five games on it.
+    /// Returns the list of game snippets for the current page. Each page has as many games on it
+    /// as the page size, which is five out of the box.
     ///
     /// E.G. If, there are are 14 games and we are focusing on game index 7, then this function will
     /// return games indices 5, 6, 7, 8, and 9 with 7 being the Snippet::Large variant.
-    pub fn page(&mut self) -> Vec<Snippet> {
-        let page = self.cursor / Self::PAGE_SIZE;
-        // The left most snippet of this page.
-        let left = page * Self::PAGE_SIZE;
-        // The right end of the page can fall off if the map if we're on the last page.
-        let right = match left + Self::PAGE_SIZE {
-            right if right < self.games.len() - 1 => right,
-            _ => self.games.len() - 1,
-        };
+    pub fn page(&mut self) -> Vec<Snippet<'_>> {
         // The cursor may be 7, but the focus of this page is index 2.
-        let page_focus = self.cursor % Self::PAGE_SIZE;
-        // Sorry the extra parenthesis here, rustc thought that we were returning a &mut rather
-        // than accessing self.games as a &mut.
-        (&mut self.games)[left..right]
+        let page_focus = self.games.page_focus();
+        let spoiler_free = self.spoiler_free;
+        self.games
+            .current_page_mut()
             .iter_mut()
             .enumerate()
             .map(|(index, game)| {
-                if index == page_focus {
+                let hidden = spoiler_free && game.spoils();
+                if index == page_focus && hidden {
+                    // Recap photos and headlines are all about who won, so all that is left
+                    // to go on is who is playing and when.
+                    Snippet::Large {
+                        image: large_logo(),
+                        heading: game.teaser.as_str(),
+                        subheading: game.first_pitch.as_deref().unwrap_or(""),
+                        live: None,
+                        badge: None,
+                        records: None,
+                    }
+                } else if hidden {
+                    Snippet::Small(small_logo(), game.first_pitch.clone())
+                } else if index == page_focus {
+                    let badge = game.badge();
+                    let probables = &mut game.probables;
+                    let summary = game
+                        .live
+                        .as_mut()
+                        .and_then(Live::get)
+                        .map(api::live::Linescore::summary)
+                        .or_else(|| probables.as_mut().map(Probables::summary));
                     // If the underlying resource hasn't come in over the network yet, then this
-                    // is the point where we decide to default to the appropriate size of the MLB logo.
-                    Snippet::Large(
-                        game.large.get().unwrap_or(&*MLB_LOGO_LARGE),
-                        game.headline.as_str(),
-                        game.subhead.as_str(),
-                    )
+                    // is the point where we decide to default to the appropriate size of stand-in.
+                    // The records count this game too, once it's over.
+                    let records = game.records();
+                    Snippet::Large {
+                        image: game.large.image(Cut::Large),
+                        heading: game.headline.as_str(),
+                        subheading: game.subhead.as_str(),
+                        live: summary,
+                        badge,
+                        records,
+                    }
                 } else {
-                    Snippet::Small(game.small.get().unwrap_or(&*MLB_LOGO_SMALL))
+                    let badge = game.badge();
+                    Snippet::Small(game.small.image(Cut::Small), badge)
                 }
             })
             .collect::<Vec<Snippet>>()
@@ -100,134 +794,1713 @@ impl Schedule {
 
 impl From<api::Schedule> for Schedule {
     fn from(mut schedule: api::Schedule) -> Self {
-        let mut games = vec![];
-        for game in schedule.dates.pop().unwrap().games.into_iter() {
-            games.push(Game {
-                headline: game.content.editorial.recap.home.headline.clone(),
-                subhead: game.content.editorial.recap.home.subhead.clone(),
-                large: Photo::new(game.content.editorial.recap.home.photo.cuts.large.src),
-                small: Photo::new(game.content.editorial.recap.home.photo.cuts.small.src),
-            });
-        }
-        Schedule { games, cursor: 0 }
+        // The API leaves dates out entirely when there's nothing on them.
+        let games = schedule
+            .dates
+            .pop()
+            .map(|date| date.games)
+            .unwrap_or_default();
+        let settings = Settings::default();
+        Schedule::with_queue(games, Queue::new(settings.max_downloads), settings)
+    }
+}
+
+// How much of a schedule is on screen, which decides how badly its photos are wanted.
+#[derive(Clone, Copy)]
+enum Visibility {
+    // The schedule that the user is scrolling through.
+    Focused,
+    // A schedule that is only on screen as a strip of small photos.
+    Strip,
+    Hidden,
+}
+
+/// The large MLB logo, for standing in for a photo.
+pub fn large_logo() -> Image<'static> {
+    Image {
+        id: "mlb_logo_large",
+        rgba: &MLB_LOGO_LARGE,
+        fade: None,
+        loading: false,
+    }
+}
+
+/// The small MLB logo, for standing in for a photo.
+pub fn small_logo() -> Image<'static> {
+    Image {
+        id: "mlb_logo_small",
+        rgba: &MLB_LOGO_SMALL,
+        fade: None,
+        loading: false,
     }
 }
 
+/// What goes on screen for one game in the carousel. Each one carries a badge that is shown
+/// over the bottom of its photo, which is the score (see Game::score) for games that have
+/// gotten underway and the time of the first pitch for those that haven't.
 pub enum Snippet<'a> {
-    Small(&'a RgbaImage),
-    Large(&'a RgbaImage, &'a str, &'a str),
+    /// The photo and badge.
+    Small(Image<'a>, Option<String>),
+    /// The game that has the focus, which gets more said about it.
+    Large {
+        image: Image<'a>,
+        /// The headline, or the teaser for games that would give too much away.
+        heading: &'a str,
+        /// The subhead, or the time of the first pitch.
+        subheading: &'a str,
+        /// A summary of the live state for games in progress, or of the pitching matchup for
+        /// those that haven't started.
+        live: Option<String>,
+        badge: Option<String>,
+        /// Both clubs' records.
+        records: Option<String>,
+    },
+}
+
+/// An image that is ready to be put on screen.
+pub struct Image<'a> {
+    /// Uniquely identifies the pixels in rgba. Two Images with the same id are always the
+    /// same picture, which lets the renderer hang on to whatever it made out of them.
+    pub id: &'a str,
+    pub rgba: &'a RgbaImage,
+    /// Photos that have only just shown up fade in over top of whatever stood in for them.
+    pub fade: Option<Fade<'a>>,
+    /// Whether this is a placeholder for a photo that is still on its way, as opposed to one
+    /// that is never going to show up.
+    pub loading: bool,
+}
+
+/// What a photo that is fading in stood in for, and how far along the fade is.
+pub struct Fade<'a> {
+    /// The same as Image::id, but for the placeholder.
+    pub id: &'a str,
+    pub rgba: &'a RgbaImage,
+    /// How opaque the photo is over top of the placeholder, from 0 (not at all) to 1.
+    pub opacity: f32,
 }
 
 pub struct Game {
+    /// The API's primary key for this game.
+    pub pk: u64,
+    pub state: State,
+    /// Only games that are in progress get a live feed.
+    live: Option<Live>,
+    // Only games that haven't started yet have probable pitchers, and only once somebody has
+    // been named.
+    probables: Option<Probables>,
+    /// E.G. "Final", "In Progress", or "Postponed".
+    pub status: String,
     pub headline: String,
     pub subhead: String,
+    pub away: Club,
+    pub home: Club,
+    /// Where the whole story about this game (the same one that the headline is from) can be
+    /// read on mlb.com, if anything has been written.
+    pub story: Option<String>,
+    /// The winning and losing pitchers, once the game is decided, and the one who got the
+    /// save if anybody did.
+    pub winner: Option<Pitcher>,
+    pub loser: Option<Pitcher>,
+    pub save: Option<Pitcher>,
+    /// Who is carrying the game on TV, on the radio, and online.
+    pub broadcasts: Broadcasts,
+    /// Where the game is being played, E.G. "Fenway Park, Boston, MA", or just "Fenway Park" if
+    /// the API didn't say where that is.
+    pub venue: Option<String>,
+    /// When the first pitch is (or was) scheduled for, E.G. "1:05 PM" in local time or
+    /// "1:05 PM EDT" in whatever timezone the settings gave.
+    pub first_pitch: Option<String>,
+    // The same, for putting games in order by it.
+    start: Option<chrono::DateTime<chrono::FixedOffset>>,
+    // Who is playing who, without a word about how it went. E.G. "Chicago White Sox at
+    // Boston Red Sox".
+    teaser: String,
+    // Whether or not the user has asked to see how this game went, spoiler-free mode or not.
+    revealed: bool,
+    /// Whether or not one of the user's favorite teams is playing in this game.
+    pub favorite: bool,
+    // Where the API put this game in its schedule.
+    order: usize,
     large: Photo,
     small: Photo,
+    // The large photo, blurred and darkened for filling the window in behind the carousel.
+    // It's made the first time it's asked for, from whatever large photo there was then.
+    backdrop: Option<Preview>,
+}
+
+impl Game {
+    /// Builds a game out of its API representation. If we already had an older copy of this
+    /// same game lying around then its photos and live feed are carried over rather than
+    /// being started over from scratch.
+    fn new(game: api::Game, previous: Option<Game>, queue: &Queue, settings: &Settings) -> Game {
+        let pk = game.game_pk;
+        let state = State::from(game.status.abstract_game_state.as_str());
+        let (headline, subhead, story, large_src, small_src) = match game.article() {
+            Some(article) => {
+                let cuts = article.photo.as_ref().map(|photo| &photo.cuts);
+                (
+                    Some(article.headline.clone()),
+                    article.subhead.clone(),
+                    article.url(),
+                    cuts.and_then(|cuts| cuts.large.as_ref())
+                        .map(|photo| photo.src.clone()),
+                    cuts.and_then(|cuts| cuts.small.as_ref())
+                        .map(|photo| photo.src.clone()),
+                )
+            }
+            // Nothing has been written about this game at all, so we make do with what we
+            // know. The headline is filled in with the matchup once we have the clubs.
+            None => (None, game.status.detailed_state.clone(), None, None, None),
+        };
+        let decisions = game.decisions;
+        let (large, small, live, probables, revealed, backdrop) = match previous {
+            Some(previous) => (
+                Some(previous.large),
+                Some(previous.small),
+                previous.live,
+                previous.probables,
+                previous.revealed,
+                previous.backdrop,
+            ),
+            None => (None, None, None, None, false, None),
+        };
+        let (away_probable, home_probable) = (
+            game.teams.away.probable_pitcher.as_ref().map(Pitcher::from),
+            game.teams.home.probable_pitcher.as_ref().map(Pitcher::from),
+        );
+        // A change of plans means looking the new guy up, but otherwise the ERAs that we
+        // already have are as good as they're going to get before the game.
+        let probables = match (state, probables) {
+            (State::Preview, Some(probables)) if probables.same(&away_probable, &home_probable) => {
+                Some(probables)
+            }
+            (State::Preview, _) => Probables::new(
+                away_probable,
+                home_probable,
+                game.season.as_deref().unwrap_or_default(),
+            ),
+            _ => None,
+        };
+        let away = Club::from(game.teams.away);
+        let home = Club::from(game.teams.home);
+        let teaser = format!("{} at {}", away.name, home.name);
+        Game {
+            pk,
+            state,
+            live: match state {
+                State::Live => live.or_else(|| Some(Live::new(pk))),
+                _ => None,
+            },
+            probables,
+            status: game.status.detailed_state,
+            headline: headline.unwrap_or_else(|| teaser.clone()),
+            subhead,
+            story,
+            away,
+            home,
+            winner: decisions
+                .as_ref()
+                .and_then(|d| d.winner.as_ref())
+                .map(Pitcher::from),
+            loser: decisions
+                .as_ref()
+                .and_then(|d| d.loser.as_ref())
+                .map(Pitcher::from),
+            save: decisions
+                .as_ref()
+                .and_then(|d| d.save.as_ref())
+                .map(Pitcher::from),
+            broadcasts: Broadcasts::from(game.broadcasts),
+            venue: game.venue.as_ref().map(|venue| match venue.city() {
+                Some(city) => format!("{}, {}", venue.name, city),
+                None => venue.name.clone(),
+            }),
+            first_pitch: game
+                .game_date
+                .as_deref()
+                .and_then(|date| first_pitch(date, settings.timezone)),
+            start: game
+                .game_date
+                .as_deref()
+                .and_then(|date| chrono::DateTime::parse_from_rfc3339(date).ok()),
+            teaser,
+            revealed,
+            // Filled in by sort_games, once the whole schedule is in hand.
+            favorite: false,
+            order: 0,
+            large: Photo::carry_over(large, large_src, Cut::Large, queue, settings),
+            small: Photo::carry_over(small, small_src, Cut::Small, queue, settings),
+            backdrop,
+        }
+    }
+
+    /// The large cut of this game's photo, or something standing in for it if it isn't here.
+    pub fn large(&mut self) -> Image<'_> {
+        self.large.image(Cut::Large)
+    }
+
+    // The backdrop for this game, if its large photo has shown up. One made from a photo that
+    // has since been swapped out for another is made over again.
+    fn backdrop(&mut self) -> Option<Image<'_>> {
+        let large = &self.large;
+        let stale = match &self.backdrop {
+            Some(backdrop) => backdrop.id != Preview::backdrop_id(&large.src),
+            None => true,
+        };
+        if stale {
+            self.backdrop = large
+                .photo
+                .as_ref()
+                .map(|photo| Preview::backdrop(&large.src, photo));
+        }
+        self.backdrop.as_ref().map(|backdrop| Image {
+            id: &backdrop.id,
+            rgba: &backdrop.rgba,
+            fade: None,
+            loading: false,
+        })
+    }
+
+    // Lends whichever cut of the photo has shown up to the other one, as a preview.
+    fn share_previews(&mut self) {
+        if let Some(large) = &self.large.photo {
+            self.small.preview(large);
+        }
+        if let Some(small) = &self.small.photo {
+            self.large.preview(small);
+        }
+    }
+
+    fn in_order(mut self, order: usize) -> Game {
+        self.order = order;
+        self
+    }
+
+    /// Whether or not putting this game on screen gives away anything about how it went.
+    /// That's any game that has gotten underway, unless the user has asked to see it.
+    pub fn spoils(&self) -> bool {
+        self.state != State::Preview && !self.revealed
+    }
+
+    /// The score and where the game stands, E.G. "CWS 5 – BOS 2, Final". None for games
+    /// that haven't started yet.
+    pub fn score(&self) -> Option<String> {
+        match (self.away.score, self.home.score) {
+            (Some(away), Some(home)) => Some(format!(
+                "{} {} – {} {}, {}",
+                self.away.abbreviation, away, self.home.abbreviation, home, self.status
+            )),
+            _ => None,
+        }
+    }
+
+    /// What goes over the bottom of this game's photo. That's its score once it has started,
+    /// and when it starts until then.
+    pub fn badge(&self) -> Option<String> {
+        self.score().or_else(|| self.first_pitch.clone())
+    }
+
+    /// Where a game in progress stands, E.G. "Top 5th, 1 out". None for games that aren't in
+    /// progress, or whose live feed hasn't been heard from yet.
+    pub fn live(&mut self) -> Option<String> {
+        self.live
+            .as_mut()
+            .and_then(Live::get)
+            .map(api::live::Linescore::summary)
+    }
+
+    /// The whole linescore of a game in progress, once its live feed has been heard from.
+    pub fn linescore(&mut self) -> Option<&api::live::Linescore> {
+        self.live.as_mut().and_then(Live::get)
+    }
+
+    /// The same as linescore, but for the plays that have scored so far.
+    pub fn scoring_plays(&mut self) -> Option<&[api::live::Play]> {
+        self.live.as_mut().and_then(Live::scoring_plays)
+    }
+
+    /// What it was like out at first pitch, for a game in progress whose live feed has been
+    /// heard from.
+    pub fn weather(&mut self) -> Option<&api::live::Weather> {
+        self.live.as_mut().and_then(Live::weather)
+    }
+
+    /// Both clubs' records going into the game (or coming out of it, once it's over), E.G.
+    /// "CWS 22-41    BOS 44-22". None unless the API had both of them.
+    pub fn records(&self) -> Option<String> {
+        let record = |club: &Club| {
+            let (wins, losses) = club.record?;
+            Some(format!("{} {}-{}", club.abbreviation, wins, losses))
+        };
+        Some(format!(
+            "{}    {}",
+            record(&self.away)?,
+            record(&self.home)?
+        ))
+    }
+
+    /// Who is playing who, and nothing else. E.G. "Chicago White Sox at Boston Red Sox".
+    pub fn teaser(&self) -> &str {
+        &self.teaser
+    }
+
+    /// E.G. "Chicago White Sox 5, Boston Red Sox 2" or, if the game hasn't
+    /// started yet, "Chicago White Sox at Boston Red Sox".
+    pub fn matchup(&self) -> String {
+        match (self.away.score, self.home.score) {
+            (Some(away), Some(home)) => {
+                format!("{} {}, {} {}", self.away.name, away, self.home.name, home)
+            }
+            _ => format!("{} at {}", self.away.name, self.home.name),
+        }
+    }
+}
+
+// The API gives game times in UTC, which is nobody's wall clock. The machine's own timezone
+// goes without saying, but one that was asked for specifically is spelled out.
+fn first_pitch(game_date: &str, timezone: Option<Tz>) -> Option<String> {
+    let time = chrono::DateTime::parse_from_rfc3339(game_date).ok()?;
+    Some(match timezone {
+        Some(timezone) => time
+            .with_timezone(&timezone)
+            .format("%-I:%M %p %Z")
+            .to_string(),
+        None => time
+            .with_timezone(&chrono::Local)
+            .format("%-I:%M %p")
+            .to_string(),
+    })
+}
+
+/// Where a game is in its lifecycle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum State {
+    Preview,
+    Live,
+    Final,
+}
+
+impl From<&str> for State {
+    // The API has been known to grow new values over time. Anything that we don't
+    // recognize is treated as a game that hasn't started yet, which is the state that
+    // makes the fewest promises about scores.
+    fn from(state: &str) -> Self {
+        match state {
+            "Live" => State::Live,
+            "Final" => State::Final,
+            _ => State::Preview,
+        }
+    }
+}
+
+/// One side of a game.
+pub struct Club {
+    pub id: u32,
+    pub name: String,
+    /// E.G. "BOS", or the whole name should the API not have said.
+    pub abbreviation: String,
+    pub score: Option<u32>,
+    /// Wins and losses, in that order.
+    pub record: Option<(u32, u32)>,
+}
+
+impl Club {
+    // How well this club matches the given (lowercase) search, lower being better, if it
+    // matches at all.
+    fn search(&self, query: &str) -> Option<u32> {
+        if query.is_empty() {
+            return None;
+        }
+        let (name, abbreviation) = (self.name.to_lowercase(), self.abbreviation.to_lowercase());
+        if abbreviation == query {
+            Some(0)
+        } else if abbreviation.starts_with(query)
+            || name.split_whitespace().any(|word| word.starts_with(query))
+        {
+            Some(1)
+        } else if in_order(query, &name) {
+            Some(2)
+        } else {
+            None
+        }
+    }
+
+    // Whether or not this club goes by any of the given (lowercase) ids, names, or
+    // abbreviations.
+    fn is_one_of(&self, teams: &[String]) -> bool {
+        let id = self.id.to_string();
+        let (name, abbreviation) = (self.name.to_lowercase(), self.abbreviation.to_lowercase());
+        teams
+            .iter()
+            .any(|team| *team == id || *team == name || *team == abbreviation)
+    }
+}
+
+// Whether or not every character of query shows up in text, in the same order (but not
+// necessarily next to one another).
+fn in_order(query: &str, text: &str) -> bool {
+    let mut text = text.chars();
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|c| text.any(|t| t == c))
+}
+
+impl From<api::TeamSide> for Club {
+    fn from(side: api::TeamSide) -> Self {
+        let name = side.team.name;
+        Club {
+            id: side.team.id,
+            abbreviation: side.team.abbreviation.unwrap_or_else(|| name.clone()),
+            name,
+            score: side.score,
+            record: side
+                .league_record
+                .map(|record| (record.wins, record.losses)),
+        }
+    }
+}
+
+/// Who is carrying a game, sorted out by whose market they're for. Each outlet is written out
+/// along with what it is, E.G. "NESN (TV)" or "WEEI 850 (AM)".
+#[derive(Debug, Default, PartialEq)]
+pub struct Broadcasts {
+    /// The ones that the whole country gets, E.G. "ESPN (TV)".
+    pub national: Vec<String>,
+    pub away: Vec<String>,
+    pub home: Vec<String>,
+}
+
+impl Broadcasts {
+    pub fn is_empty(&self) -> bool {
+        self.national.is_empty() && self.away.is_empty() && self.home.is_empty()
+    }
+}
+
+impl From<Vec<api::Broadcast>> for Broadcasts {
+    fn from(broadcasts: Vec<api::Broadcast>) -> Self {
+        let mut sorted = Broadcasts::default();
+        for broadcast in broadcasts {
+            let market = match (broadcast.is_national, broadcast.home_away.as_deref()) {
+                (true, _) => &mut sorted.national,
+                (false, Some("home")) => &mut sorted.home,
+                (false, Some("away")) => &mut sorted.away,
+                // Nobody's market in particular isn't worth guessing at.
+                _ => continue,
+            };
+            let outlet = format!("{} ({})", broadcast.name, broadcast.kind);
+            // National broadcasts come once for each side, and the odd local one does too.
+            if !market.contains(&outlet) {
+                market.push(outlet);
+            }
+        }
+        sorted
+    }
+}
+
+/// A pitcher who got one of the decisions in a game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pitcher {
+    /// The API's id for them, which is what their record is filed under in the live feed.
+    pub id: u64,
+    /// E.G. "Reynaldo Lopez"
+    pub name: String,
+}
+
+impl Pitcher {
+    /// What they go by in a box score, which is everything after their first name. E.G.
+    /// "Lopez", or "McCullers Jr." for Lance McCullers Jr.
+    pub fn surname(&self) -> &str {
+        self.name
+            .split_once(' ')
+            .map(|(_, surname)| surname)
+            .unwrap_or(&self.name)
+    }
+}
+
+impl From<&api::Person> for Pitcher {
+    fn from(person: &api::Person) -> Self {
+        Pitcher {
+            id: person.id,
+            name: person.full_name.clone(),
+        }
+    }
+}
+
+/// Where a photo's download stands.
+pub enum Fetch<'a> {
+    /// Still on its way, or waiting in line to be.
+    Pending,
+    Ready(&'a RgbaImage),
+    /// The photo is never going to show up. Either every attempt at downloading it failed
+    /// or what came down wasn't an image that could be decoded.
+    Failed,
+}
+
+// The two sizes that photos come in, which is also what decides the size of whatever
+// stands in for one.
+#[derive(Clone, Copy)]
+enum Cut {
+    Large,
+    Small,
+}
+
+impl Cut {
+    fn logo(self) -> Image<'static> {
+        match self {
+            Cut::Large => large_logo(),
+            Cut::Small => small_logo(),
+        }
+    }
+
+    // The size that the API cuts the photo down to.
+    fn dimensions(self) -> (u32, u32) {
+        match self {
+            Cut::Large => (480, 270),
+            Cut::Small => (320, 180),
+        }
+    }
+
+    // The size that the photo actually takes up on screen at the given scale (see
+    // Settings::scale).
+    fn pixels(self, scale: f64) -> (u32, u32) {
+        let (width, height) = self.dimensions();
+        let scaled = |length: u32| ((length as f64 * scale).round() as u32).max(1);
+        (scaled(width), scaled(height))
+    }
+
+    fn shimmer(self) -> (&'static str, &'static RgbaImage) {
+        match self {
+            Cut::Large => shimmer::large(),
+            Cut::Small => shimmer::small(),
+        }
+    }
 }
 
 pub struct Photo {
+    src: String,
     photo: Option<RgbaImage>,
+    // When the photo showed up, for fading it in.
+    arrived: Option<Instant>,
+    // A blurry copy of the game's other cut, for standing in while this one is on its way.
+    preview: Option<Preview>,
+    // When the photo was last drawn, for deciding what to throw out when memory runs short.
+    used: Instant,
+    // Whether the decoded photo was thrown out to make room, and is waiting to be wanted again.
+    evicted: bool,
+    // The size that the photo was asked to be shrunk down to when it was decoded.
+    fit: (u32, u32),
+    // Whether there's been a photo or preview since the renderer last took a look.
+    fresh: bool,
+    failed: bool,
+    // Whether the photo snaps into place rather than fading in (see Settings::reduced_motion).
+    reduced_motion: bool,
     channel: crossbeam_channel::Receiver<RgbaImage>,
+    // Our place in the download line. Photos start out at the back until told otherwise.
+    ticket: Ticket,
 }
 
 impl Photo {
+    // How long a photo takes to fade in once it has shown up. Popping in all at once is
+    // jarring, but anything much longer than this just looks slow.
+    const FADE: Duration = Duration::from_millis(300);
+
     /// Constructs a new photo from the given source url.
     ///
     /// The function returns immediately, however the physical photo has been fired off
     /// as an ansynchronous download. Any attempts to the acquire with underlying RGBa will
-    /// return None until the media is ready.
+    /// return Fetch::Pending until the media is ready.
     ///
-    /// If the download fails then this photo will return None indefinitely and an entry will
-    /// be logged to stderr.
-    pub fn new(src: String) -> Photo {
+    /// Photos that have been downloaded recently enough are read back off of disk instead.
+    ///
+    /// Failed downloads are retried a few times over. If they all fail then this photo will
+    /// report Fetch::Failed from then on and the failure will be logged.
+    ///
+    /// The photo is shrunk down to however big the given cut is on screen along the way.
+    fn new(src: String, cut: Cut, queue: &Queue, settings: &Settings) -> Photo {
+        let ticket = Ticket::new(Priority::Offscreen);
+        let fit = cut.pixels(settings.scale);
+        Photo {
+            channel: Self::load(src.clone(), fit, ticket.clone(), queue),
+            src,
+            photo: None,
+            arrived: None,
+            preview: None,
+            used: Instant::now(),
+            evicted: false,
+            fit,
+            fresh: false,
+            failed: false,
+            reduced_motion: settings.reduced_motion,
+            ticket,
+        }
+    }
+
+    // Kicks off getting the photo at the given source, out of the cache if it's there and
+    // off of the network if it isn't, handing it over the returned channel once it's decoded
+    // and shrunk down to fit within the given size.
+    fn load(
+        src: String,
+        fit: (u32, u32),
+        place: Ticket,
+        queue: &Queue,
+    ) -> crossbeam_channel::Receiver<RgbaImage> {
         let (tx, rx) = crossbeam_channel::bounded(1);
-        tokio::task::spawn(async move {
-            let url: hyper::Uri = match src.parse() {
-                Ok(uri) => uri,
-                Err(err) => {
-                    eprintln!("Failed to parse {} as a URL", src);
-                    eprintln!("Error: {}", err);
-                    return;
-                }
-            };
-            let https = hyper_tls::HttpsConnector::new();
-            let resp = match hyper::Client::builder()
-                .build::<_, hyper::Body>(https)
-                .get(url)
-                .await
-            {
-                Ok(resp) => resp,
-                Err(err) => {
-                    eprintln!("Failed to establish connection to {}", src);
-                    eprintln!("Error: {}", err);
-                    return;
+        let queue = queue.clone();
+        let span = info_span!("photo", src = %src);
+        let download = async move {
+            let (buf, cached) = match PHOTO_CACHE.get(&src).await {
+                Some(bytes) => {
+                    debug!("Read from the cache");
+                    queue.looked_up(true);
+                    (bytes, true)
                 }
-            };
-            let buf = match hyper::body::to_bytes(resp).await {
-                Ok(bytes) => bytes,
-                Err(err) => {
-                    eprintln!("Failed to download photo from {}", src);
-                    eprintln!("Error: {}", err);
-                    return;
+                None => {
+                    queue.looked_up(false);
+                    match queue.photo(src.clone(), place).await {
+                        Some(bytes) => (bytes, false),
+                        None => return,
+                    }
                 }
             };
-            let img = match image::load_from_memory_with_format(&buf, ImageFormat::JPEG) {
-                Ok(image) => image.into_rgba(),
+            let img = match decode(&buf) {
+                Ok(image) => shrink(image, fit),
                 Err(err) => {
-                    eprintln!("Image retrieved from {} failed to parse as a JPEG", src);
-                    eprintln!("Error: {}", err);
+                    error!(error = %err, "The photo failed to parse");
                     return;
                 }
             };
+            // Only photos that actually turned out to be photos get cached.
+            if !cached {
+                PHOTO_CACHE.put(&src, &buf).await;
+            }
             match tx.send(img) {
                 Ok(_) => (),
                 Err(err) => {
-                    eprintln!(
-                        "Failed to send the downloaded contents of {} to the main thread",
-                        src
-                    );
-                    eprintln!("Error: {}", err);
-                    return;
+                    // Nobody is waiting on this photo anymore, which is no big deal.
+                    debug!(error = %err, "Failed to send the photo to the main thread");
                 }
             }
-        });
+        };
+        api::spawn(download.instrument(span));
+        rx
+    }
+
+    /// A photo for a game that doesn't have one. It simply never shows up.
+    pub fn missing() -> Photo {
+        let (_, rx) = crossbeam_channel::bounded(1);
         Photo {
+            src: String::new(),
             photo: None,
+            arrived: None,
+            preview: None,
+            used: Instant::now(),
+            evicted: false,
+            fit: (0, 0),
+            fresh: false,
+            failed: true,
+            reduced_motion: false,
             channel: rx,
+            ticket: Ticket::new(Priority::Offscreen),
+        }
+    }
+
+    // Picks up where the previous copy of a photo left off, if there was one,
+    // and makes do without if there's no longer any source for it at all.
+    fn carry_over(
+        previous: Option<Photo>,
+        src: Option<String>,
+        cut: Cut,
+        queue: &Queue,
+        settings: &Settings,
+    ) -> Photo {
+        match (previous, src) {
+            (_, Some(_)) if !settings.photos => Photo::missing(),
+            (Some(previous), Some(src)) => previous.reuse(src, cut, queue, settings),
+            (None, Some(src)) => Photo::new(src, cut, queue, settings),
+            (_, None) => Photo::missing(),
+        }
+    }
+
+    /// Hands back this very same photo if it is still for the given source, otherwise
+    /// starts the download for the new one. A photo that failed gets another go of it.
+    fn reuse(self, src: String, cut: Cut, queue: &Queue, settings: &Settings) -> Photo {
+        if self.src == src && !self.failed {
+            self
+        } else {
+            Photo::new(src, cut, queue, settings)
         }
     }
 
-    /// Retrieves the RGBa of this photo. Returns None if the photo has not
-    /// completed its download.
-    pub fn get(&mut self) -> Option<&RgbaImage> {
-        if self.photo.is_some() {
-            return self.photo.as_ref();
+    /// Either this photo, identified by its source, or something of the given cut to stand in
+    /// for it. While the photo is on its way that's a blurry preview of it if there is one and a
+    /// shimmering skeleton if there isn't, and once it is never going to show up it's the MLB logo.
+    /// A photo that has only just shown up fades in over whatever stood in for it.
+    fn image(&mut self, cut: Cut) -> Image<'_> {
+        self.used = Instant::now();
+        if let Fetch::Ready(_) = self.get() {
+            let opacity = self.opacity(Instant::now());
+            // Sigh, get() hands back a borrow of all of self, so we have to go back in
+            // for the pieces separately.
+            return Image {
+                id: &self.src,
+                rgba: self.photo.as_ref().unwrap(),
+                fade: match opacity < 1.0 {
+                    true => {
+                        let (id, rgba) = Self::stand_in(&self.preview, cut);
+                        Some(Fade { id, rgba, opacity })
+                    }
+                    false => None,
+                },
+                loading: false,
+            };
         }
-        match self.channel.try_recv() {
-            Ok(image) => {
-                self.photo = Some(image);
-                self.photo.as_ref()
+        match self.pending() || self.evicted {
+            true => {
+                let (id, rgba) = Self::stand_in(&self.preview, cut);
+                Image {
+                    id,
+                    rgba,
+                    fade: None,
+                    loading: true,
+                }
             }
-            _ => None,
+            false => cut.logo(),
+        }
+    }
+
+    // What goes on screen in place of a photo that is still on its way.
+    fn stand_in(preview: &Option<Preview>, cut: Cut) -> (&str, &RgbaImage) {
+        match preview {
+            Some(preview) => (&preview.id, &preview.rgba),
+            None => cut.shimmer(),
         }
     }
+
+    // Blurs the given photo (which is the very same photo, only a different cut of it) down
+    // into a preview of this one, unless there is no need for one.
+    fn preview(&mut self, other: &RgbaImage) {
+        if self.pending() && self.preview.is_none() {
+            self.preview = Some(Preview::new(&self.src, other, self.fit));
+            self.fresh = true;
+        }
+    }
+
+    /// Whether or not the photo is still on its way, as of the last time that it was checked on.
+    /// A photo that was thrown out to make room isn't on its way until it is wanted again.
+    pub fn pending(&self) -> bool {
+        self.photo.is_none() && !self.failed && !self.evicted
+    }
+
+    // Whatever this photo has that is new since the last time that this was called, each with
+    // the id that it is drawn under.
+    fn take_fresh(&mut self) -> Vec<(&str, &RgbaImage)> {
+        if !std::mem::replace(&mut self.fresh, false) {
+            return vec![];
+        }
+        let mut fresh = vec![];
+        if let Some(preview) = &self.preview {
+            fresh.push((preview.id.as_str(), &preview.rgba));
+        }
+        if let Some(photo) = &self.photo {
+            fresh.push((self.src.as_str(), photo));
+        }
+        fresh
+    }
+
+    // How much memory the decoded photo (and its preview) are taking up.
+    fn bytes(&self) -> usize {
+        let size = |rgba: &RgbaImage| rgba.width() as usize * rgba.height() as usize * 4;
+        self.photo.as_ref().map_or(0, size) + self.preview.as_ref().map_or(0, |p| size(&p.rgba))
+    }
+
+    // Throws the decoded photo out to make room, for as long as it is out of sight.
+    fn evict(&mut self) {
+        self.photo = None;
+        self.preview = None;
+        self.arrived = None;
+        self.fresh = false;
+        self.evicted = true;
+    }
+
+    // Gets a photo that was thrown out back again, which is almost always a trip to the disk
+    // cache rather than the network.
+    fn restore(&mut self, cut: Cut, scale: f64, queue: &Queue) {
+        if self.evicted {
+            self.fit = cut.pixels(scale);
+            self.channel = Self::load(self.src.clone(), self.fit, self.ticket.clone(), queue);
+            self.evicted = false;
+        }
+    }
+
+    // Reads the photo back in if it was shrunk down for a window of some other size. The disk
+    // cache still has the whole thing, so that's a quick trip.
+    fn refit(&mut self, cut: Cut, scale: f64, queue: &Queue) {
+        if self.photo.is_some() && self.fit != cut.pixels(scale) {
+            self.evict();
+            self.restore(cut, scale, queue);
+        }
+    }
+
+    /// Whether or not the photo is still fading in, and so looks a little different every frame.
+    pub fn fading(&self) -> bool {
+        self.opacity(Instant::now()) < 1.0
+    }
+
+    // How far along the photo is in fading in as of the given moment. Photos that haven't
+    // shown up yet don't have anything to fade, so they're as faded in as they're going to get.
+    // Neither do any photos at all with reduced motion.
+    fn opacity(&self, now: Instant) -> f32 {
+        match self.arrived {
+            Some(arrived) if !self.reduced_motion => {
+                let elapsed = now.saturating_duration_since(arrived);
+                (elapsed.as_secs_f32() / Self::FADE.as_secs_f32()).min(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Checks in on the download, returning true if the photo has just now shown up
+    /// (or just now failed to).
+    pub fn poll(&mut self) -> bool {
+        if self.photo.is_some() || self.failed {
+            return false;
+        }
+        !matches!(self.get(), Fetch::Pending)
+    }
+
+    /// Retrieves the RGBa of this photo, if it has completed its download.
+    pub fn get(&mut self) -> Fetch<'_> {
+        if self.pending() {
+            match self.channel.try_recv() {
+                Ok(image) => {
+                    self.photo = Some(image);
+                    self.arrived = Some(Instant::now());
+                    self.fresh = true;
+                }
+                // The download task only ever hangs up without sending
+                // anything once it has given up for good.
+                Err(crossbeam_channel::TryRecvError::Disconnected) => self.failed = true,
+                Err(crossbeam_channel::TryRecvError::Empty) => (),
+            }
+        }
+        match &self.photo {
+            Some(photo) => Fetch::Ready(photo),
+            None if self.failed => Fetch::Failed,
+            None => Fetch::Pending,
+        }
+    }
+}
+
+// Roughly what a photo looks like, for showing before it has actually shown up.
+//
+// The API doesn't hand out an average color or a blurhash or anything of the sort, but it does
+// hand out two cuts of every photo, and they rarely show up at the same time. Whichever one
+// shows up first is shrunk down to next to nothing and blown back up to the size of the
+// other, which leaves about a blurhash's worth of detail behind.
+struct Preview {
+    // The source of the photo that this is a preview of, with a suffix so that it doesn't
+    // collide with the photo itself in the renderer's texture cache.
+    id: String,
+    rgba: RgbaImage,
+}
+
+impl Preview {
+    // How many pixels across and down survive the shrinking.
+    const DETAIL: (u32, u32) = (16, 9);
+
+    fn new(src: &str, other: &RgbaImage, (width, height): (u32, u32)) -> Preview {
+        let (across, down) = Self::DETAIL;
+        let tiny = imageops::resize(other, across, down, FilterType::Triangle);
+        Preview {
+            id: format!("{}#preview", src),
+            rgba: imageops::resize(&tiny, width, height, FilterType::Triangle),
+        }
+    }
+
+    // How many pixels across and down survive the shrinking for a backdrop. It's blown up to
+    // fill the whole window, so there is a little more to it than a preview, and it only has to
+    // be blown up partway since the GPU smooths out the rest when it's stretched.
+    const BACKDROP_DETAIL: (u32, u32) = (32, 18);
+    const BACKDROP_SIZE: (u32, u32) = (192, 108);
+    // How much of its brightness a backdrop keeps, so that white text still reads over top of
+    // a sunny afternoon at Wrigley.
+    const BACKDROP_DIM: f32 = 0.45;
+
+    fn backdrop(src: &str, photo: &RgbaImage) -> Preview {
+        let (across, down) = Self::BACKDROP_DETAIL;
+        let (width, height) = Self::BACKDROP_SIZE;
+        let tiny = imageops::resize(photo, across, down, FilterType::Triangle);
+        let mut rgba = imageops::resize(&tiny, width, height, FilterType::Triangle);
+        for pixel in rgba.pixels_mut() {
+            for channel in pixel.0.iter_mut().take(3) {
+                *channel = (*channel as f32 * Self::BACKDROP_DIM).round() as u8;
+            }
+        }
+        Preview {
+            id: Self::backdrop_id(src),
+            rgba,
+        }
+    }
+
+    fn backdrop_id(src: &str) -> String {
+        format!("{}#backdrop", src)
+    }
+}
+
+// Shrinks the given photo down to the given size, if it's any bigger than that. Photos are
+// stretched to fit their snippet anyways, so there's no point in ever blowing one up.
+fn shrink(image: RgbaImage, (width, height): (u32, u32)) -> RgbaImage {
+    if image.width() <= width && image.height() <= height {
+        return image;
+    }
+    imageops::resize(&image, width, height, FilterType::Triangle)
+}
+
+// The cuts used to always be JPEGs, but the CDN has been known to hand out PNGs and WebPs
+// too. Rather than trusting what the server says that it sent us, we go by what the bytes
+// themselves say that they are, which works just as well for photos that come back out of
+// the cache (which doesn't know or care about headers) as for those fresh off the network.
+fn decode(buf: &[u8]) -> Result<RgbaImage, String> {
+    let format = image::guess_format(buf).map_err(|_| {
+        let magic: Vec<String> = buf.iter().take(4).map(|b| format!("{:02x}", b)).collect();
+        format!(
+            "unrecognized image format (starts with {})",
+            magic.join(" ")
+        )
+    })?;
+    image::load_from_memory_with_format(buf, format)
+        .map(|image| image.into_rgba())
+        .map_err(|err| format!("{:?}: {}", format, err))
+}
+
+/// The live state (inning, count, score, and how the runs scored) of a game that is in progress.
+///
+/// Much like a Photo, this is fed from a background task. The difference being that the
+/// task keeps on polling for as long as this struct is alive.
+pub struct Live {
+    /// The API's primary key for the game that this is the state of.
+    pub pk: u64,
+    snapshot: Option<api::live::Snapshot>,
+    channel: crossbeam_channel::Receiver<api::live::Snapshot>,
+}
+
+impl Live {
+    const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+    pub fn new(game_pk: u64) -> Live {
+        Live {
+            pk: game_pk,
+            snapshot: None,
+            channel: api::live::poll(game_pk, Self::POLL_INTERVAL),
+        }
+    }
+
+    /// The final state of a game that is over, which only needs getting the once.
+    pub fn once(game_pk: u64) -> Live {
+        Live {
+            pk: game_pk,
+            snapshot: None,
+            channel: api::live::fetch(game_pk),
+        }
+    }
+
+    /// Retrieves the most recent linescore for this game. Returns None
+    /// until the first poll of the live feed has come back.
+    pub fn get(&mut self) -> Option<&api::live::Linescore> {
+        self.poll();
+        self.snapshot.as_ref().map(|snapshot| &snapshot.linescore)
+    }
+
+    /// The plays that have scored so far, in order. As with get, this is None until the live
+    /// feed has been heard from.
+    pub fn scoring_plays(&mut self) -> Option<&[api::live::Play]> {
+        self.poll();
+        self.snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.scoring_plays.as_slice())
+    }
+
+    /// What it was like out at first pitch. As with get, this is None until the live feed has
+    /// been heard from, and even then the API doesn't always say.
+    pub fn weather(&mut self) -> Option<&api::live::Weather> {
+        self.poll();
+        self.snapshot.as_ref()?.weather.as_ref()
+    }
+
+    /// How the given player's season has gone on the mound, as of this game. None until the
+    /// live feed has been heard from, or if they aren't in this game at all.
+    pub fn record(&mut self, id: u64) -> Option<api::live::Record> {
+        self.poll();
+        self.snapshot.as_ref()?.records.get(&id).copied()
+    }
+
+    /// Checks in on the poller, returning true if a new snapshot has come in.
+    pub fn poll(&mut self) -> bool {
+        let mut fresh = false;
+        while let Ok(snapshot) = self.channel.try_recv() {
+            self.snapshot = Some(snapshot);
+            fresh = true;
+        }
+        fresh
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    static TEST_DATA: &[u8] = include_bytes!("../api/test.json");
+
+    // A schedule for the one day in the test data.
+    fn test_data() -> api::Schedule {
+        serde_json::from_slice(TEST_DATA).unwrap()
+    }
+
+    // Runs the given test with a runtime around, since making a Schedule kicks its downloads
+    // off onto one (even though nothing is ever going to come of them).
+    fn with_runtime<F: FnOnce()>(test: F) {
+        tokio::runtime::Runtime::new().unwrap().enter(test)
+    }
+
+    // A photo that will never arrive.
+    fn photo() -> Photo {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        // Hanging up would mean that the photo failed, rather than just never arriving.
+        std::mem::forget(tx);
+        Photo {
+            src: String::new(),
+            photo: None,
+            arrived: None,
+            preview: None,
+            used: Instant::now(),
+            evicted: false,
+            fit: (0, 0),
+            fresh: false,
+            failed: false,
+            reduced_motion: false,
+            channel: rx,
+            ticket: Ticket::new(Priority::Offscreen),
+        }
+    }
+
+    fn game(away: &str, home: &str) -> Game {
+        Game {
+            pk: 0,
+            state: State::Preview,
+            live: None,
+            probables: None,
+            status: "Scheduled".to_string(),
+            headline: String::new(),
+            subhead: String::new(),
+            story: None,
+            away: Club {
+                id: 0,
+                name: away.to_string(),
+                abbreviation: away.to_string(),
+                score: None,
+                record: None,
+            },
+            home: Club {
+                id: 1,
+                name: home.to_string(),
+                abbreviation: home.to_string(),
+                score: None,
+                record: None,
+            },
+            winner: None,
+            loser: None,
+            save: None,
+            broadcasts: Broadcasts::default(),
+            venue: None,
+            first_pitch: None,
+            start: None,
+            teaser: format!("{} at {}", away, home),
+            revealed: false,
+            favorite: false,
+            order: 0,
+            large: photo(),
+            small: photo(),
+            backdrop: None,
+        }
+    }
+
+    #[test]
+    fn matchup() {
+        let mut game = game("Chicago White Sox", "Boston Red Sox");
+        assert_eq!(game.matchup(), "Chicago White Sox at Boston Red Sox");
+        game.away.score = Some(5);
+        game.home.score = Some(2);
+        assert_eq!(game.matchup(), "Chicago White Sox 5, Boston Red Sox 2");
+    }
+
+    #[test]
+    fn first_pitch_in_any_timezone() {
+        let time = "2018-06-10T17:05:00Z";
+        assert_eq!(
+            first_pitch(time, Some(chrono_tz::America::New_York)).unwrap(),
+            "1:05 PM EDT"
+        );
+        assert_eq!(
+            first_pitch(time, Some(chrono_tz::America::Los_Angeles)).unwrap(),
+            "10:05 AM PDT"
+        );
+        assert!(first_pitch(time, None).unwrap().ends_with('M'));
+        assert_eq!(first_pitch("sometime Sunday", None), None);
+    }
+
+    #[test]
+    fn score() {
+        let mut game = game("Chicago White Sox", "Boston Red Sox");
+        assert_eq!(game.score(), None);
+        assert_eq!(game.badge(), None);
+        game.first_pitch = Some("1:05 PM".to_string());
+        assert_eq!(game.badge().unwrap(), "1:05 PM");
+        game.away.abbreviation = "CWS".to_string();
+        game.home.abbreviation = "BOS".to_string();
+        game.away.score = Some(5);
+        game.home.score = Some(2);
+        game.status = "Final".to_string();
+        assert_eq!(game.score().unwrap(), "CWS 5 – BOS 2, Final");
+        assert_eq!(game.badge(), game.score());
+    }
+
+    #[test]
+    fn favorites_go_first() {
+        let mut games = vec![
+            game("Chicago White Sox", "Boston Red Sox"),
+            game("New York Yankees", "Tampa Bay Rays"),
+            game("Chicago Cubs", "New York Mets"),
+            game("Seattle Mariners", "Houston Astros"),
+        ];
+        games[2].home.abbreviation = "NYM".to_string();
+        games[3].away.id = 136;
+        let favorites = vec!["nym".to_string(), "136".to_string()];
+        sort_games(&mut games, Sort::Favorites, &favorites);
+        let order: Vec<&str> = games.iter().map(|game| game.away.name.as_str()).collect();
+        assert_eq!(
+            order,
+            [
+                "Chicago Cubs",
+                "Seattle Mariners",
+                "Chicago White Sox",
+                "New York Yankees"
+            ]
+        );
+        assert!(games[0].favorite && games[1].favorite);
+        assert!(!games[2].favorite && !games[3].favorite);
+    }
+
+    #[test]
+    fn sorts() {
+        let mut games = vec![
+            game("Chicago White Sox", "Boston Red Sox"),
+            game("New York Yankees", "Tampa Bay Rays"),
+            game("Chicago Cubs", "New York Mets"),
+            game("Seattle Mariners", "Houston Astros"),
+        ];
+        let at = |time| chrono::DateTime::parse_from_rfc3339(time).ok();
+        games[0].start = at("2019-09-24T23:10:00Z");
+        games[1].start = at("2019-09-24T17:05:00Z");
+        games[2].start = at("2019-09-24T13:20:00-04:00");
+        games[0].state = State::Final;
+        games[2].state = State::Live;
+        let favorites = vec!["seattle mariners".to_string()];
+        let away = |games: &[Game]| -> Vec<String> {
+            games.iter().map(|game| game.away.name.clone()).collect()
+        };
+        sort_games(&mut games, Sort::Time, &favorites);
+        // Still marked, just not moved up front.
+        assert!(games[3].favorite);
+        assert_eq!(
+            away(&games),
+            [
+                "New York Yankees",
+                "Chicago Cubs",
+                "Chicago White Sox",
+                "Seattle Mariners"
+            ]
+        );
+        sort_games(&mut games, Sort::Status, &favorites);
+        assert_eq!(
+            away(&games),
+            [
+                "Chicago Cubs",
+                "New York Yankees",
+                "Seattle Mariners",
+                "Chicago White Sox"
+            ]
+        );
+        assert_eq!(Sort::Status.next(), Sort::Favorites);
+    }
+
+    #[test]
+    fn search() {
+        with_runtime(|| {
+            let games = vec![
+                game("Chicago White Sox", "Boston Red Sox"),
+                game("New York Yankees", "Tampa Bay Rays"),
+                game("Chicago Cubs", "New York Mets"),
+            ];
+            let settings = Settings::default();
+            let queue = Queue::new(settings.max_downloads);
+            let mut schedule = Schedule {
+                games: Paginator::new(games, DEFAULT_PAGE_SIZE),
+                logos: Gallery::new(Schedule::LOGO, &queue),
+                headshots: Gallery::new(Schedule::HEADSHOT, &queue),
+                queue,
+                spoiler_free: false,
+                filter: None,
+                progress: Progress::All,
+                sort: Sort::Favorites,
+                set_aside: vec![],
+                failures: 0,
+                searched: None,
+                settings,
+            };
+            schedule.games[1].away.abbreviation = "NYY".to_string();
+            schedule.games[2].home.abbreviation = "NYM".to_string();
+            assert!(schedule.search("cubs"));
+            assert_eq!(schedule.games.cursor(), 2);
+            assert!(schedule.search("NYY"));
+            assert_eq!(schedule.games.cursor(), 1);
+            // The Yankees are the away club, so theirs is the page to open.
+            assert_eq!(schedule.team(), Some(0));
+            // The start of a word, over the letters of "New York Yankees" that happen to spell it.
+            assert!(schedule.search("yank"));
+            assert_eq!(schedule.games.cursor(), 1);
+            assert!(schedule.search("ynks"));
+            assert_eq!(schedule.games.cursor(), 1);
+            // The first of the two New York clubs wins a tie.
+            assert!(schedule.search("new"));
+            assert_eq!(schedule.games.cursor(), 1);
+            assert!(!schedule.search("dodgers"));
+            assert!(!schedule.search(""));
+            assert_eq!(schedule.games.cursor(), 1);
+            assert_eq!(schedule.team(), Some(1));
+        });
+    }
+
+    #[test]
+    fn repage() {
+        with_runtime(|| {
+            let mut schedule = Schedule::from(test_data());
+            schedule.right();
+            // Only a change of size is worth redrawing over.
+            assert!(!schedule.repage(DEFAULT_PAGE_SIZE));
+            assert!(schedule.repage(2));
+            assert_eq!(schedule.games.bounds(), 0..2);
+            assert_eq!(schedule.games.cursor(), 1);
+            assert!(schedule.repage(1));
+            assert_eq!(schedule.games.bounds(), 1..2);
+            assert_eq!(schedule.games.cursor(), 1);
+        });
+    }
+
+    #[test]
+    fn filters() {
+        with_runtime(|| {
+            let mut schedule = Schedule::from(test_data());
+            let everything: Vec<u64> = schedule.games.iter().map(|game| game.pk).collect();
+            assert!(schedule.games.pages() > 1);
+            schedule.set_filter(Some(&["boston red sox", "111"][..]));
+            assert!(schedule.is_filtered());
+            assert_eq!(schedule.games.len(), 1);
+            assert_eq!(schedule.games.pages(), 1);
+            assert!(!schedule.has_more());
+            assert_eq!(schedule.focused().unwrap().home.name, "Boston Red Sox");
+            // A refresh doesn't bring the rest of them back.
+            schedule.merge(test_data());
+            assert_eq!(schedule.games.len(), 1);
+            // But lifting the filter does, in their original order.
+            schedule.set_filter::<&str>(None);
+            let after: Vec<u64> = schedule.games.iter().map(|game| game.pk).collect();
+            assert_eq!(after, everything);
+            assert_eq!(schedule.focused().unwrap().home.name, "Boston Red Sox");
+        });
+    }
+
+    #[test]
+    fn shows_games_by_progress() {
+        let mut data = test_data();
+        // Every game in the test data is over, so a couple of them are put back in play.
+        let games = &mut data.dates[0].games;
+        games[1].status.abstract_game_state = "Live".to_string();
+        games[3].status.abstract_game_state = "Preview".to_string();
+        let (live, preview) = (games[1].game_pk, games[3].game_pk);
+        with_runtime(|| {
+            let mut schedule = Schedule::from(data);
+            let everything = schedule.games.len();
+            schedule.focus(1);
+            schedule.set_progress(Progress::Unfinished);
+            assert!(schedule.is_filtered());
+            let left: Vec<u64> = schedule.games.iter().map(|game| game.pk).collect();
+            assert_eq!(left, vec![live, preview]);
+            assert_eq!(schedule.focused().unwrap().pk, live);
+            schedule.set_progress(Progress::Live);
+            assert_eq!(schedule.games.len(), 1);
+            // Once it's over, the next refresh takes it off too.
+            let mut data = test_data();
+            data.dates[0].games[3].status.abstract_game_state = "Live".to_string();
+            schedule.merge(data);
+            assert_eq!(schedule.focused().unwrap().pk, preview);
+            schedule.set_progress(Progress::All);
+            assert_eq!(schedule.games.len(), everything);
+            assert_eq!(schedule.focused().unwrap().pk, preview);
+        });
+    }
+
+    #[test]
+    fn broadcasts() {
+        let broadcasts: Vec<api::Broadcast> = serde_json::from_str(
+            r#"[
+                {"name": "ESPN", "type": "TV", "homeAway": "away", "isNational": true},
+                {"name": "ESPN", "type": "TV", "homeAway": "home", "isNational": true},
+                {"name": "WGN 720", "type": "AM", "homeAway": "away", "isNational": false},
+                {"name": "NESN", "type": "TV", "homeAway": "home", "isNational": false},
+                {"name": "WEEI 850", "type": "AM", "homeAway": "home", "isNational": false},
+                {"name": "Nowhere", "type": "FM", "isNational": false}
+            ]"#,
+        )
+        .unwrap();
+        let broadcasts = Broadcasts::from(broadcasts);
+        assert_eq!(
+            broadcasts,
+            Broadcasts {
+                national: vec!["ESPN (TV)".to_string()],
+                away: vec!["WGN 720 (AM)".to_string()],
+                home: vec!["NESN (TV)".to_string(), "WEEI 850 (AM)".to_string()],
+            }
+        );
+        assert!(!broadcasts.is_empty());
+        assert!(Broadcasts::from(vec![]).is_empty());
+    }
+
+    #[test]
+    fn decisions() {
+        let data = test_data();
+        with_runtime(|| {
+            let schedule = Schedule::from(data);
+            let game = schedule
+                .games
+                .iter()
+                .find(|game| game.home.name == "Boston Red Sox")
+                .unwrap();
+            let winner = game.winner.as_ref().unwrap();
+            assert_eq!(winner.id, 625643);
+            assert_eq!(winner.surname(), "Lopez");
+            assert_eq!(game.loser.as_ref().unwrap().name, "Rick Porcello");
+            assert_eq!(game.save.as_ref().unwrap().surname(), "Soria");
+        });
+        let pitcher = Pitcher {
+            id: 0,
+            name: "Lance McCullers Jr.".to_string(),
+        };
+        assert_eq!(pitcher.surname(), "McCullers Jr.");
+    }
+
+    #[test]
+    fn venue_and_weather() {
+        let mut data = test_data();
+        // The schedule that the test data came from didn't hydrate the location, so one of
+        // them gets one.
+        let venue = data.dates[0].games[1].venue.as_mut().unwrap();
+        venue.location = Some(api::Location {
+            city: Some("Boston".to_string()),
+            state_abbrev: Some("MA".to_string()),
+        });
+        let name = venue.name.clone();
+        with_runtime(|| {
+            let mut schedule = Schedule::from(data);
+            assert_eq!(schedule.games[0].venue.as_deref(), Some("Fenway Park"));
+            assert_eq!(
+                schedule.games[1].venue,
+                Some(format!("{}, Boston, MA", name))
+            );
+            // Nothing to say about the weather until the live feed has been heard from.
+            assert!(schedule.games[0].weather().is_none());
+        });
+        let feed: api::live::Feed = serde_json::from_str(
+            r#"{
+                "gameData": {"weather": {"condition": "Dome", "temp": "72"}},
+                "liveData": {"linescore": {"teams": {"away": {}, "home": {}}}}
+            }"#,
+        )
+        .unwrap();
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        std::mem::forget(tx);
+        let mut game = game("Chicago White Sox", "Boston Red Sox");
+        game.live = Some(Live {
+            pk: 0,
+            snapshot: Some(api::live::Snapshot::from(feed)),
+            channel: rx,
+        });
+        let weather = game.weather().unwrap();
+        assert_eq!(weather.condition.as_deref(), Some("Dome"));
+        assert_eq!(weather.wind, None);
+    }
+
+    #[test]
+    fn merge_keeps_photos_and_focus() {
+        with_runtime(|| {
+            let mut schedule = Schedule::from(test_data());
+            schedule.right();
+            schedule.right();
+            let focused = schedule.focused().unwrap().pk;
+            schedule.games[2].large.photo = Some(RgbaImage::new(1, 1));
+            schedule.merge(test_data());
+            assert_eq!(schedule.focused().unwrap().pk, focused);
+            match schedule.games[2].large.get() {
+                Fetch::Ready(_) => (),
+                _ => panic!("the photo should have been kept"),
+            }
+        });
+    }
+
+    #[test]
+    fn the_last_game_is_reachable() {
+        with_runtime(|| {
+            let mut schedule = Schedule::from(test_data());
+            let last = schedule.games.last().unwrap().pk;
+            for _ in 0..schedule.games.len() {
+                schedule.right();
+            }
+            assert_eq!(schedule.focused().unwrap().pk, last);
+            assert!(!schedule.has_more());
+            let focus = schedule.games.page_focus();
+            let page = schedule.page();
+            match page.get(focus) {
+                Some(Snippet::Large { .. }) => (),
+                _ => panic!("the last game should be on its page, and large"),
+            }
+        });
+    }
+
+    #[test]
+    fn spoiler_free() {
+        with_runtime(|| {
+            let mut schedule = Schedule::from(test_data());
+            schedule.games[0].large.photo = Some(RgbaImage::new(1, 1));
+            schedule.games[1].small.photo = Some(RgbaImage::new(1, 1));
+            let game = &schedule.games[0];
+            let (teaser, headline) = (game.teaser().to_string(), game.headline.clone());
+            assert_eq!(game.state, State::Final);
+            assert!(game.first_pitch.as_ref().unwrap().ends_with('M'));
+            assert!(schedule.backdrop().is_some());
+            assert!(schedule.story().is_some());
+            schedule.set_spoiler_free(true);
+            assert!(schedule.backdrop().is_none());
+            assert!(schedule.story().is_none());
+            assert!(schedule.highlights(None).is_none());
+            assert!(schedule.feed(None).is_none());
+            match &schedule.page()[0] {
+                Snippet::Large {
+                    image,
+                    heading,
+                    live,
+                    badge,
+                    records,
+                    ..
+                } => {
+                    assert_eq!(image.id, "mlb_logo_large");
+                    assert_eq!(*heading, teaser);
+                    assert!(live.is_none());
+                    assert!(badge.is_none());
+                    assert!(records.is_none());
+                }
+                _ => panic!("the focused game should be large"),
+            }
+            assert_eq!(schedule.strip()[1].id, "mlb_logo_small");
+            // Revealing it brings back the recap, and it stays revealed through a refresh.
+            schedule.reveal();
+            schedule.merge(test_data());
+            match &schedule.page()[0] {
+                Snippet::Large {
+                    heading, records, ..
+                } => {
+                    assert_eq!(*heading, headline);
+                    // The test data didn't hydrate the abbreviations.
+                    assert_eq!(
+                        records.as_deref(),
+                        Some("Chicago White Sox 22-41    Boston Red Sox 44-22")
+                    );
+                }
+                _ => panic!("the focused game should be large"),
+            }
+            // But only the one game.
+            assert!(schedule.games[1].spoils());
+        });
+    }
+
+    #[test]
+    fn backdrops() {
+        let mut game = game("Chicago White Sox", "Boston Red Sox");
+        assert!(game.backdrop().is_none());
+        game.large.src = "first".to_string();
+        game.large.photo = Some(RgbaImage::from_pixel(480, 270, image::Rgba([255; 4])));
+        let backdrop = game.backdrop().unwrap();
+        assert_eq!(backdrop.id, "first#backdrop");
+        assert_eq!(backdrop.rgba.dimensions(), Preview::BACKDROP_SIZE);
+        // Darker, but not any less opaque.
+        assert_eq!(backdrop.rgba.get_pixel(0, 0).0, [115, 115, 115, 255]);
+        game.large.src = "second".to_string();
+        assert_eq!(game.backdrop().unwrap().id, "second#backdrop");
+    }
+
+    #[test]
+    fn decodes_whatever_it_is_given() {
+        let mut png = vec![];
+        image::DynamicImage::ImageRgba8(RgbaImage::new(2, 1))
+            .write_to(&mut png, image::ImageOutputFormat::PNG)
+            .unwrap();
+        assert_eq!(decode(&png).unwrap().dimensions(), (2, 1));
+        assert_eq!(
+            decode(MLB_LOGO_SMALL_BYTES).unwrap().dimensions(),
+            MLB_LOGO_SMALL.dimensions()
+        );
+        assert!(decode(b"<html>Not Found</html>").is_err());
+    }
+
+    #[test]
+    fn shrinks_but_never_grows() {
+        let shrunk = shrink(RgbaImage::new(480, 270), (320, 180));
+        assert_eq!(shrunk.dimensions(), (320, 180));
+        let kept = shrink(RgbaImage::new(480, 270), (960, 540));
+        assert_eq!(kept.dimensions(), (480, 270));
+    }
+
+    #[test]
+    fn games_without_a_recap() {
+        let fresh: api::Schedule = serde_json::from_str(
+            r#"{"copyright": "", "dates": [{"date": "2020-03-26", "games": [{
+                "gamePk": 1,
+                "status": {"abstractGameState": "Preview", "detailedState": "Postponed"},
+                "teams": {
+                    "away": {"team": {"id": 1, "name": "Away"}},
+                    "home": {"team": {"id": 2, "name": "Home"}}
+                }
+            }]}]}"#,
+        )
+        .unwrap();
+        with_runtime(|| {
+            let mut schedule = Schedule::from(fresh);
+            let game = schedule.focused().unwrap();
+            assert_eq!(game.headline, "Away at Home");
+            assert_eq!(game.subhead, "Postponed");
+            assert_eq!(game.large().id, "mlb_logo_large");
+        });
+    }
+
+    #[test]
+    fn no_games() {
+        let empty = || serde_json::from_str::<api::Schedule>(r#"{"copyright": "", "dates": []}"#);
+        with_runtime(|| {
+            let mut schedule = Schedule::from(empty().unwrap());
+            assert!(schedule.is_empty());
+            schedule.left();
+            schedule.right();
+            assert!(schedule.focused().is_none());
+            assert!(schedule.page().is_empty());
+            assert!(!schedule.has_more());
+            assert!(!schedule.has_less());
+            schedule.merge(empty().unwrap());
+            assert!(schedule.is_empty());
+        });
+    }
+
+    #[test]
+    fn photo_poll_only_reports_arrival_once() {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let mut photo = Photo {
+            channel: rx,
+            ..photo()
+        };
+        assert!(!photo.poll());
+        tx.send(RgbaImage::new(1, 1)).unwrap();
+        assert!(photo.poll());
+        assert!(!photo.poll());
+    }
+
+    #[test]
+    fn photos_fade_in() {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let mut photo = Photo {
+            src: "cut.jpg".to_string(),
+            channel: rx,
+            ..photo()
+        };
+        let image = photo.image(Cut::Small);
+        assert!(image.id.starts_with("shimmer_320x180_"));
+        assert!(image.fade.is_none());
+        assert!(image.loading);
+        assert!(!photo.fading());
+        tx.send(RgbaImage::new(2, 2)).unwrap();
+        let image = photo.image(Cut::Small);
+        assert_eq!(image.id, "cut.jpg");
+        assert!(!image.loading);
+        match image.fade {
+            Some(fade) => {
+                assert!(fade.id.starts_with("shimmer_320x180_"));
+                assert!(fade.opacity < 1.0);
+            }
+            None => panic!("the photo only just showed up"),
+        }
+        assert!(photo.fading());
+        let arrived = photo.arrived.unwrap();
+        assert_eq!(photo.opacity(arrived), 0.0);
+        assert_eq!(photo.opacity(arrived + Photo::FADE / 2), 0.5);
+        assert_eq!(photo.opacity(arrived + Photo::FADE * 2), 1.0);
+    }
+
+    #[test]
+    fn previews_stand_in_for_photos() {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let mut photo = Photo {
+            src: "large.jpg".to_string(),
+            fit: (480, 270),
+            channel: rx,
+            ..photo()
+        };
+        photo.preview(&RgbaImage::new(320, 180));
+        let image = photo.image(Cut::Large);
+        assert_eq!(image.id, "large.jpg#preview");
+        assert_eq!(image.rgba.dimensions(), (480, 270));
+        assert!(image.loading);
+        tx.send(RgbaImage::new(480, 270)).unwrap();
+        match photo.image(Cut::Large).fade {
+            Some(fade) => assert_eq!(fade.id, "large.jpg#preview"),
+            None => panic!("the photo only just showed up"),
+        }
+        // There's no call for a preview of a photo that is already here.
+        let mut other = Photo::missing();
+        other.photo = Some(RgbaImage::new(320, 180));
+        other.preview(&RgbaImage::new(480, 270));
+        assert!(other.preview.is_none());
+    }
+
     #[test]
     /// Testing that a broken send channel due to a failed download
-    /// doesn't unexpectedly panic us or something.
+    /// doesn't unexpectedly panic us or something, and reports the failure.
     fn broken_photo_channel() {
         let (tx, rx) = crossbeam_channel::bounded(1);
         let mut photo = Photo {
-            photo: None,
             channel: rx,
+            ..photo()
         };
-        assert!(photo.get().is_none());
+        match photo.get() {
+            Fetch::Pending => (),
+            _ => panic!("nothing has happened yet"),
+        }
         drop(tx);
-        assert!(photo.get().is_none());
+        assert!(photo.poll());
+        match photo.get() {
+            Fetch::Failed => (),
+            _ => panic!("the download hung up"),
+        }
+        assert!(!photo.poll());
+        // Which is not the same as still being on its way, so it gets the logo instead.
+        let image = photo.image(Cut::Large);
+        assert!(!image.loading);
+        assert_eq!(image.id, "mlb_logo_large");
     }
 }
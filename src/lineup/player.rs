@@ -0,0 +1,289 @@
+use super::{poll, Polled};
+use crate::api::people::{self, Stat, Stats};
+use chrono::NaiveDate;
+
+/// A player's page: their biography, and how they've hit and pitched this season and over
+/// their whole career.
+///
+/// It's looked up in the background the moment that this is made. Their headshot comes the way
+/// that every other one does, through the schedule.
+pub struct Player {
+    /// The API's id for the player.
+    pub id: u64,
+    card: Option<Card>,
+    // The player that is still on their way, if they are.
+    channel: Option<crossbeam_channel::Receiver<people::Player>>,
+}
+
+/// Everything on a player's page. The words that go around it are left to whoever draws it,
+/// so that they come out in the user's language.
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    /// E.G. "Rick Porcello"
+    pub name: String,
+    /// E.G. "22"
+    pub number: Option<String>,
+    /// E.G. "P"
+    pub position: Option<String>,
+    /// Which side they hit from, E.G. "R", "L", or "S" for switch hitters.
+    pub bats: Option<String>,
+    /// Which hand they throw with, E.G. "R".
+    pub throws: Option<String>,
+    /// E.G. "6' 5\""
+    pub height: Option<String>,
+    /// In pounds.
+    pub weight: Option<u32>,
+    pub age: Option<u32>,
+    pub born: Option<NaiveDate>,
+    /// Where they were born, E.G. "Morristown, NJ, USA".
+    pub birthplace: Option<String>,
+    /// The day of their first game in the majors, if they've had one.
+    pub debut: Option<NaiveDate>,
+    pub stats: Vec<StatLine>,
+}
+
+/// One of a player's stat lines, E.G. their 2019 on the mound.
+#[derive(Debug, PartialEq)]
+pub struct StatLine {
+    /// E.G. "2019". None for their whole career.
+    pub season: Option<String>,
+    pub group: Group,
+    /// E.G. "14-12, 5.52 ERA, 174.1 IP, 143 K, 1.40 WHIP". The abbreviations are the same in
+    /// every box score, whatever language it's in.
+    pub line: String,
+}
+
+/// What a stat line is for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Group {
+    Hitting,
+    Pitching,
+}
+
+impl Player {
+    pub fn new(id: u64) -> Player {
+        Player {
+            id,
+            card: None,
+            channel: Some(people::player(id)),
+        }
+    }
+
+    /// Whether or not the player is still on their way.
+    pub fn loading(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    /// The page, once it has come in.
+    pub fn card(&self) -> Option<&Card> {
+        self.card.as_ref()
+    }
+
+    /// Whether or not the player couldn't be had, which has already been logged.
+    pub fn failed(&self) -> bool {
+        !self.loading() && self.card.is_none()
+    }
+
+    /// Checks in on the player, returning true if they've just come in (or just given up).
+    pub fn poll(&mut self) -> bool {
+        match poll(&mut self.channel) {
+            Polled::Arrived(player) => {
+                self.card = Some(Card::from(player));
+                true
+            }
+            Polled::Failed => true,
+            Polled::Waiting => false,
+        }
+    }
+}
+
+impl From<people::Player> for Card {
+    fn from(player: people::Player) -> Self {
+        let code = |code: Option<people::Code>| code.map(|code| code.code);
+        let date = |date: Option<String>| {
+            date.and_then(|date| NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok())
+        };
+        let birthplace: Vec<&str> = [
+            &player.birth_city,
+            &player.birth_state_province,
+            &player.birth_country,
+        ]
+        .iter()
+        .filter_map(|place| place.as_deref())
+        .collect();
+        let birthplace = Some(birthplace.join(", ")).filter(|place| !place.is_empty());
+        let id = player.id;
+        Card {
+            name: player.full_name.unwrap_or_else(|| id.to_string()),
+            number: player.primary_number,
+            position: player
+                .primary_position
+                .map(|position| position.abbreviation),
+            bats: code(player.bat_side),
+            throws: code(player.pitch_hand),
+            height: player.height,
+            weight: player.weight,
+            age: player.current_age,
+            born: date(player.birth_date),
+            birthplace,
+            debut: date(player.mlb_debut_date),
+            stats: player.stats.iter().flat_map(lines).collect(),
+        }
+    }
+}
+
+// Every line out of one bunch of stats. A season split is labeled with its season, and a
+// career split with none at all.
+fn lines(stats: &Stats) -> Vec<StatLine> {
+    let group = match stats
+        .group
+        .as_ref()
+        .map(|group| group.display_name.as_str())
+    {
+        Some("hitting") => Group::Hitting,
+        Some("pitching") => Group::Pitching,
+        _ => return vec![],
+    };
+    let career = stats.kind.as_ref().map(|kind| kind.display_name.as_str()) == Some("career");
+    stats
+        .splits
+        .iter()
+        .filter_map(|split| {
+            let season = match (career, &split.season) {
+                (true, _) => None,
+                (false, Some(season)) => Some(season.clone()),
+                (false, None) => return None,
+            };
+            let line = match group {
+                Group::Hitting => hitting(&split.stat),
+                Group::Pitching => pitching(&split.stat),
+            };
+            Some(StatLine {
+                season,
+                group,
+                line,
+            })
+        })
+        .collect()
+}
+
+// E.G. ".311 AVG, 32 HR, 115 RBI, .916 OPS, 156 G"
+fn hitting(stat: &Stat) -> String {
+    let line: Vec<String> = stat
+        .avg
+        .as_ref()
+        .map(|avg| format!("{} AVG", avg))
+        .into_iter()
+        .chain(stat.home_runs.map(|runs| format!("{} HR", runs)))
+        .chain(stat.rbi.map(|rbi| format!("{} RBI", rbi)))
+        .chain(stat.ops.as_ref().map(|ops| format!("{} OPS", ops)))
+        .chain(stat.games_played.map(|games| format!("{} G", games)))
+        .collect();
+    line.join(", ")
+}
+
+// E.G. "14-12, 5.52 ERA, 174.1 IP, 143 K, 1.40 WHIP", with the saves tacked on for anybody who
+// has any.
+fn pitching(stat: &Stat) -> String {
+    let record = match (stat.wins, stat.losses) {
+        (Some(wins), Some(losses)) => Some(format!("{}-{}", wins, losses)),
+        _ => None,
+    };
+    let line: Vec<String> = record
+        .into_iter()
+        .chain(stat.era.as_ref().map(|era| format!("{} ERA", era)))
+        .chain(stat.innings_pitched.as_ref().map(|ip| format!("{} IP", ip)))
+        .chain(
+            stat.strike_outs
+                .map(|strike_outs| format!("{} K", strike_outs)),
+        )
+        .chain(stat.whip.as_ref().map(|whip| format!("{} WHIP", whip)))
+        .chain(
+            stat.saves
+                .filter(|saves| *saves > 0)
+                .map(|saves| format!("{} SV", saves)),
+        )
+        .collect();
+    line.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card() {
+        let player: people::Player = serde_json::from_str(
+            r#"{
+                "id": 519144,
+                "fullName": "Rick Porcello",
+                "primaryNumber": "22",
+                "birthDate": "1988-12-27",
+                "currentAge": 30,
+                "birthCity": "Morristown",
+                "birthStateProvince": "NJ",
+                "birthCountry": "USA",
+                "height": "6' 5\"",
+                "weight": 205,
+                "mlbDebutDate": "2009-04-09",
+                "primaryPosition": {"abbreviation": "P"},
+                "batSide": {"code": "R"},
+                "pitchHand": {"code": "R"},
+                "stats": [
+                    {
+                        "type": {"displayName": "season"},
+                        "group": {"displayName": "pitching"},
+                        "splits": [{"season": "2019", "stat": {
+                            "wins": 14, "losses": 12, "saves": 0, "era": "5.52",
+                            "inningsPitched": "174.1", "strikeOuts": 143, "whip": "1.40"
+                        }}]
+                    },
+                    {
+                        "type": {"displayName": "career"},
+                        "group": {"displayName": "hitting"},
+                        "splits": [{"stat": {"avg": ".094", "homeRuns": 0, "rbi": 2}}]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let card = Card::from(player);
+        assert_eq!(card.name, "Rick Porcello");
+        assert_eq!(card.number.as_deref(), Some("22"));
+        assert_eq!(card.position.as_deref(), Some("P"));
+        assert_eq!(card.bats.as_deref(), Some("R"));
+        assert_eq!(card.throws.as_deref(), Some("R"));
+        assert_eq!(card.height.as_deref(), Some("6' 5\""));
+        assert_eq!(card.weight, Some(205));
+        assert_eq!(card.age, Some(30));
+        assert_eq!(card.born, NaiveDate::from_ymd_opt(1988, 12, 27));
+        assert_eq!(card.birthplace.as_deref(), Some("Morristown, NJ, USA"));
+        assert_eq!(card.debut, NaiveDate::from_ymd_opt(2009, 4, 9));
+        assert_eq!(
+            card.stats,
+            vec![
+                StatLine {
+                    season: Some("2019".to_string()),
+                    group: Group::Pitching,
+                    line: "14-12, 5.52 ERA, 174.1 IP, 143 K, 1.40 WHIP".to_string(),
+                },
+                StatLine {
+                    season: None,
+                    group: Group::Hitting,
+                    line: ".094 AVG, 0 HR, 2 RBI".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn nothing_but_a_name() {
+        let player: people::Player =
+            serde_json::from_str(r#"{"id": 1, "fullName": "Nobody In Particular"}"#).unwrap();
+        let card = Card::from(player);
+        assert_eq!(card.number, None);
+        assert_eq!(card.born, None);
+        assert_eq!(card.birthplace, None);
+        assert!(card.stats.is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+use super::{poll, Pitcher, Polled};
+use crate::api::people;
+use std::collections::HashMap;
+
+/// Who is expected to start a game that hasn't, along with how each of them has pitched so far
+/// this season.
+///
+/// The schedule only names them, so their ERAs are looked up in the background the moment that
+/// this is made. Until they come in (or if they never do) it's just their names.
+pub struct Probables {
+    // Either club can go without for a while, until they've made up their minds.
+    away: Option<Pitcher>,
+    home: Option<Pitcher>,
+    eras: HashMap<u64, String>,
+    // The ERAs that are still on their way, if they are.
+    channel: Option<crossbeam_channel::Receiver<HashMap<u64, String>>>,
+}
+
+impl Probables {
+    /// Looks up the given pitchers for the given season, E.G. "2019". None if neither club has
+    /// named anybody yet, as there's nothing to look up (or to show).
+    pub(super) fn new(
+        away: Option<Pitcher>,
+        home: Option<Pitcher>,
+        season: &str,
+    ) -> Option<Probables> {
+        let ids: Vec<u64> = away
+            .iter()
+            .chain(home.iter())
+            .map(|pitcher| pitcher.id)
+            .collect();
+        if ids.is_empty() {
+            return None;
+        }
+        Some(Probables {
+            away,
+            home,
+            eras: HashMap::new(),
+            channel: Some(people::fetch(ids, season.to_string())),
+        })
+    }
+
+    /// Whether or not these are the same two pitchers, for keeping the ERAs around when the
+    /// schedule is refreshed out from under them.
+    pub(super) fn same(&self, away: &Option<Pitcher>, home: &Option<Pitcher>) -> bool {
+        &self.away == away && &self.home == home
+    }
+
+    /// Checks in on the ERAs, returning true if they've just come in.
+    pub fn poll(&mut self) -> bool {
+        match poll(&mut self.channel) {
+            Polled::Arrived(eras) => {
+                self.eras = eras;
+                true
+            }
+            // The names will have to do.
+            Polled::Failed | Polled::Waiting => false,
+        }
+    }
+
+    /// The pitching matchup, E.G. "Lopez (5.38) vs. Porcello (4.79)". A club that hasn't named
+    /// anybody yet gets "TBD".
+    pub fn summary(&mut self) -> String {
+        self.poll();
+        let pitcher = |pitcher: &Option<Pitcher>| match pitcher {
+            Some(pitcher) => match self.eras.get(&pitcher.id) {
+                Some(era) => format!("{} ({})", pitcher.surname(), era),
+                None => pitcher.surname().to_string(),
+            },
+            None => "TBD".to_string(),
+        };
+        format!("{} vs. {}", pitcher(&self.away), pitcher(&self.home))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pitcher(id: u64, name: &str) -> Option<Pitcher> {
+        Some(Pitcher {
+            id,
+            name: name.to_string(),
+        })
+    }
+
+    #[test]
+    fn summary() {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let mut probables = Probables {
+            away: pitcher(625643, "Reynaldo Lopez"),
+            home: pitcher(519144, "Rick Porcello"),
+            eras: HashMap::new(),
+            channel: Some(rx),
+        };
+        assert_eq!(probables.summary(), "Lopez vs. Porcello");
+        // Porcello hasn't pitched yet this season.
+        let eras = vec![(625643, "5.38".to_string())].into_iter().collect();
+        tx.send(eras).unwrap();
+        assert!(probables.poll());
+        assert!(!probables.poll());
+        assert_eq!(probables.summary(), "Lopez (5.38) vs. Porcello");
+        assert!(probables.same(
+            &pitcher(625643, "Reynaldo Lopez"),
+            &pitcher(519144, "Rick Porcello")
+        ));
+        assert!(!probables.same(&pitcher(625643, "Reynaldo Lopez"), &None));
+    }
+
+    #[test]
+    fn to_be_determined() {
+        let (_, rx) = crossbeam_channel::bounded(1);
+        let mut probables = Probables {
+            away: None,
+            home: pitcher(519144, "Rick Porcello"),
+            eras: HashMap::new(),
+            channel: Some(rx),
+        };
+        assert!(!probables.poll());
+        assert_eq!(probables.summary(), "TBD vs. Porcello");
+        assert!(Probables::new(None, None, "2019").is_none());
+    }
+}
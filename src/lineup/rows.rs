@@ -0,0 +1,604 @@
+use super::download::{Queue, Stats};
+use super::{Photo, Progress, Schedule, Settings, Sort, State, Visibility};
+use crate::api;
+use crate::export::Entry;
+use image::RgbaImage;
+use std::collections::HashMap;
+
+/// One date's worth of games in a Lineup.
+pub struct Row {
+    /// As the API gives it to us, E.G. "2018-06-10".
+    pub date: String,
+    pub schedule: Schedule,
+    // This date's games as they were last given to us, for exporting.
+    entries: Vec<Entry>,
+}
+
+// The given date's games, for a row's entries.
+fn entries(date: &api::Date) -> Vec<Entry> {
+    date.games
+        .iter()
+        .map(|game| Entry::new(&date.date, game))
+        .collect()
+}
+
+/// A stack of schedules, one for each date that came back from the API, with the user
+/// focused on one of them.
+///
+/// Most of the time there is only the one date. Ask the API for a range of dates though, and
+/// each one of them gets a row (a carousel) of its own that up and down move between.
+pub struct Lineup {
+    rows: Vec<Row>,
+    cursor: usize,
+    copyright: String,
+    spoiler_free: bool,
+    filter: Option<Vec<String>>,
+    progress: Progress,
+    sort: Sort,
+    settings: Settings,
+    // Every row shares the one download line so that the focused row can cut to the front of it.
+    queue: Queue,
+}
+
+impl Lineup {
+    /// Lays out every date in the given schedule, one row apiece, going about it as the given
+    /// settings say to.
+    pub fn new(schedule: api::Schedule, settings: Settings) -> Lineup {
+        let queue = Queue::new(settings.max_downloads);
+        let rows = schedule
+            .dates
+            .into_iter()
+            .map(|date| Row {
+                entries: entries(&date),
+                date: date.date,
+                schedule: Schedule::with_queue(date.games, queue.clone(), settings.clone()),
+            })
+            .collect();
+        let mut lineup = Lineup {
+            rows,
+            cursor: 0,
+            copyright: schedule.copyright,
+            spoiler_free: false,
+            filter: None,
+            progress: Progress::All,
+            sort: Sort::Favorites,
+            settings,
+            queue,
+        };
+        lineup.prioritize();
+        lineup
+    }
+
+    /// Whether or not there is a single game in any of the rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.iter().all(|row| row.schedule.is_empty())
+    }
+
+    /// The attribution that the API asks to be shown alongside anything that came out of it.
+    pub fn copyright(&self) -> &str {
+        &self.copyright
+    }
+
+    /// The same as Schedule::set_spoiler_free, but for every row, including any that show
+    /// up later on.
+    pub fn set_spoiler_free(&mut self, spoiler_free: bool) {
+        self.spoiler_free = spoiler_free;
+        for row in self.rows.iter_mut() {
+            row.schedule.set_spoiler_free(spoiler_free);
+        }
+    }
+
+    /// The same as Schedule::set_filter, but for every row, including any that show up
+    /// later on.
+    pub fn set_filter<T: AsRef<str>>(&mut self, teams: Option<&[T]>) {
+        self.filter =
+            teams.map(|teams| teams.iter().map(|team| team.as_ref().to_string()).collect());
+        for row in self.rows.iter_mut() {
+            row.schedule.set_filter(teams);
+        }
+    }
+
+    /// The same as Schedule::set_progress, but for every row, including any that show up
+    /// later on.
+    pub fn set_progress(&mut self, progress: Progress) {
+        self.progress = progress;
+        for row in self.rows.iter_mut() {
+            row.schedule.set_progress(progress);
+        }
+    }
+
+    /// The same as Schedule::set_sort, but for every row, including any that show up later on.
+    pub fn set_sort(&mut self, sort: Sort) {
+        self.sort = sort;
+        for row in self.rows.iter_mut() {
+            row.schedule.set_sort(sort);
+        }
+    }
+
+    /// The same as Schedule::set_page_size, but for every row, including any that show up later
+    /// on.
+    pub fn set_page_size(&mut self, size: usize) {
+        self.settings.page_size = size.max(1);
+        for row in self.rows.iter_mut() {
+            row.schedule.set_page_size(size);
+        }
+    }
+
+    /// The same as Schedule::set_scale, but for every row, including any that show up later on.
+    pub fn set_scale(&mut self, scale: f64) {
+        self.settings.scale = scale;
+        for row in self.rows.iter_mut() {
+            row.schedule.set_scale(scale);
+        }
+    }
+
+    /// The row that the user is scrolling through. None if the API didn't have any dates for us.
+    pub fn current(&mut self) -> Option<&mut Row> {
+        self.rows.get_mut(self.cursor)
+    }
+
+    /// The rows directly above the current one, the current one itself, and the one directly
+    /// below it. Which is to say, every row that is on screen.
+    pub fn neighborhood(&mut self) -> (Option<&mut Row>, Option<&mut Row>, Option<&mut Row>) {
+        if self.rows.is_empty() {
+            return (None, None, None);
+        }
+        let (before, rest) = self.rows.split_at_mut(self.cursor);
+        let (current, after) = rest.split_first_mut().unwrap();
+        (before.last_mut(), Some(current), after.first_mut())
+    }
+
+    /// Moves up a row, returning whether or not there was a row up there to move to.
+    pub fn up(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        self.prioritize();
+        true
+    }
+
+    /// Moves down a row, returning whether or not there was a row down there to move to.
+    pub fn down(&mut self) -> bool {
+        if self.cursor + 1 >= self.rows.len() {
+            return false;
+        }
+        self.cursor += 1;
+        self.prioritize();
+        true
+    }
+
+    /// Puts the focus on the very last row, E.G. for when the user has come up from below.
+    pub fn focus_last(&mut self) {
+        self.cursor = self.rows.len().saturating_sub(1);
+        self.prioritize();
+    }
+
+    /// Puts the focus on the game with the given primary key, in whichever row it's in,
+    /// returning whether or not it was anywhere to be found. Games that are filtered out are as
+    /// good as nowhere.
+    pub fn focus_game(&mut self, pk: u64) -> bool {
+        let found = self.rows.iter().enumerate().find_map(|(index, row)| {
+            row.schedule
+                .games
+                .iter()
+                .position(|game| game.pk == pk)
+                .map(|game| (index, game))
+        });
+        match found {
+            Some((row, game)) => {
+                self.cursor = row;
+                self.rows[row].schedule.focus(game);
+                self.prioritize();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The date of the first row with a game in progress, if any of them have one, E.G.
+    /// "2018-06-10".
+    pub fn live_date(&self) -> Option<&str> {
+        self.rows
+            .iter()
+            .find(|row| {
+                row.schedule
+                    .games
+                    .iter()
+                    .any(|game| game.state == State::Live)
+            })
+            .map(|row| row.date.as_str())
+    }
+
+    /// Moves on to the next game, carrying on to the first game of the next row once this one
+    /// runs out, and back around to the very first game of the very first row once they all
+    /// have. This is for kiosk mode, where nobody is around to do the moving.
+    pub fn advance(&mut self) {
+        let row = match self.rows.get_mut(self.cursor) {
+            Some(row) => row,
+            None => return,
+        };
+        if row.schedule.games.right() {
+            row.schedule.prioritize();
+            return;
+        }
+        self.cursor = (self.cursor + 1) % self.rows.len();
+        self.rows[self.cursor].schedule.first();
+        self.prioritize();
+    }
+
+    /// The same as Schedule::poll, but for every row. This is also where the decoded photos
+    /// are kept within their budget (see Settings::budget).
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        for row in self.rows.iter_mut() {
+            changed |= row.schedule.poll();
+        }
+        self.evict(self.settings.budget);
+        changed
+    }
+
+    /// Hands over the photos (and previews) that have shown up since the last time that this
+    /// was called, along with the ids that they'll be drawn under, for getting them onto the
+    /// GPU ahead of drawing them. No more than the given number of photos are handed over at
+    /// a time, and the rest wait their turn for the next call.
+    pub fn take_fresh(&mut self, most: usize) -> Vec<(&str, &RgbaImage)> {
+        self.rows
+            .iter_mut()
+            .flat_map(|row| row.schedule.photos_mut())
+            .filter(|photo| photo.fresh)
+            .take(most)
+            .flat_map(Photo::take_fresh)
+            .collect()
+    }
+
+    /// How the photos are getting along: how many are on their way, how many were found on
+    /// disk, and how much memory the decoded ones are taking up.
+    pub fn stats(&mut self) -> Stats {
+        let mut stats = self.queue.stats();
+        for photo in self
+            .rows
+            .iter_mut()
+            .flat_map(|row| row.schedule.photos_mut())
+        {
+            if photo.bytes() > 0 {
+                stats.decoded += 1;
+                stats.bytes += photo.bytes();
+            }
+        }
+        stats
+    }
+
+    // Throws out whichever off screen photos have gone unseen the longest until everything
+    // fits in the given number of bytes, or until there's nothing left off screen to throw out.
+    fn evict(&mut self, budget: usize) {
+        let mut photos: Vec<&mut Photo> = self
+            .rows
+            .iter_mut()
+            .flat_map(|row| row.schedule.photos_mut())
+            .collect();
+        let mut total: usize = photos.iter().map(|photo| photo.bytes()).sum();
+        if total <= budget {
+            return;
+        }
+        photos.retain(|photo| photo.bytes() > 0 && photo.ticket.is_offscreen());
+        photos.sort_by_key(|photo| photo.used);
+        for photo in photos {
+            if total <= budget {
+                break;
+            }
+            total -= photo.bytes();
+            photo.evict();
+        }
+    }
+
+    /// Every game in every row, as of the last time that the API was heard from, for writing
+    /// out with export::write.
+    pub fn entries(&self) -> Vec<Entry> {
+        self.rows
+            .iter()
+            .flat_map(|row| row.entries.iter().cloned())
+            .collect()
+    }
+
+    /// The same as Schedule::take_failures, but for every row.
+    pub fn take_failures(&mut self) -> usize {
+        self.rows
+            .iter_mut()
+            .map(|row| row.schedule.take_failures())
+            .sum()
+    }
+
+    /// The same as Schedule::merge, but row by row. Rows are matched up by their dates, and
+    /// the focus stays on whichever date it was on.
+    pub fn merge(&mut self, fresh: api::Schedule) {
+        if fresh.is_empty() {
+            return;
+        }
+        if !fresh.copyright.is_empty() {
+            self.copyright = fresh.copyright;
+        }
+        let focused = self.rows.get(self.cursor).map(|row| row.date.clone());
+        let mut previous: HashMap<String, Row> = self
+            .rows
+            .drain(..)
+            .map(|row| (row.date.clone(), row))
+            .collect();
+        let (queue, spoiler_free, filter, progress, sort, settings) = (
+            &self.queue,
+            self.spoiler_free,
+            &self.filter,
+            self.progress,
+            self.sort,
+            &self.settings,
+        );
+        let rows: Vec<Row> = fresh
+            .dates
+            .into_iter()
+            .map(|date| {
+                let (schedule, entries) = match previous.remove(&date.date) {
+                    // Much like the whole schedule, a single date coming back empty is
+                    // more likely to be a hiccup than a mass cancellation.
+                    Some(row) if date.games.is_empty() => (row.schedule, row.entries),
+                    Some(mut row) => {
+                        let entries = entries(&date);
+                        row.schedule.merge_games(date.games);
+                        (row.schedule, entries)
+                    }
+                    None => {
+                        let entries = entries(&date);
+                        let mut schedule =
+                            Schedule::with_queue(date.games, queue.clone(), settings.clone());
+                        schedule.set_spoiler_free(spoiler_free);
+                        if filter.is_some() {
+                            schedule.set_filter(filter.as_deref());
+                        }
+                        if progress != Progress::All {
+                            schedule.set_progress(progress);
+                        }
+                        if sort != Sort::Favorites {
+                            schedule.set_sort(sort);
+                        }
+                        (schedule, entries)
+                    }
+                };
+                Row {
+                    date: date.date,
+                    schedule,
+                    entries,
+                }
+            })
+            .collect();
+        self.rows = rows;
+        self.cursor = focused
+            .and_then(|date| self.rows.iter().position(|row| row.date == date))
+            .unwrap_or_else(|| self.cursor.min(self.rows.len().saturating_sub(1)));
+        self.prioritize();
+    }
+
+    // The current row's photos go first, then the strips above and below it, then the rest.
+    fn prioritize(&mut self) {
+        let cursor = self.cursor;
+        for (index, row) in self.rows.iter().enumerate() {
+            row.schedule.prioritize_as(if index == cursor {
+                Visibility::Focused
+            } else if index + 1 == cursor || index == cursor + 1 {
+                Visibility::Strip
+            } else {
+                Visibility::Hidden
+            });
+        }
+    }
+}
+
+impl From<api::Schedule> for Lineup {
+    fn from(schedule: api::Schedule) -> Self {
+        Lineup::new(schedule, Settings::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_DATA: &str = include_str!("../api/test.json");
+
+    // The test data only has the one date, so we make up a few more out of it.
+    fn schedule(dates: &[&str]) -> api::Schedule {
+        let mut json: serde_json::Value = serde_json::from_str(TEST_DATA).unwrap();
+        let date = json["dates"][0].clone();
+        json["dates"] = dates
+            .iter()
+            .map(|name| {
+                let mut date = date.clone();
+                date["date"] = serde_json::Value::from(*name);
+                date
+            })
+            .collect();
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn moving_between_rows() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut lineup = Lineup::from(schedule(&["2018-06-09", "2018-06-10"]));
+            assert!(!lineup.up());
+            assert!(lineup.down());
+            assert!(!lineup.down());
+            assert_eq!(lineup.current().unwrap().date, "2018-06-10");
+            match lineup.neighborhood() {
+                (Some(above), Some(_), None) => assert_eq!(above.date, "2018-06-09"),
+                _ => panic!("expected a row above and none below"),
+            }
+            assert!(lineup.up());
+            lineup.focus_last();
+            assert_eq!(lineup.current().unwrap().date, "2018-06-10");
+        });
+    }
+
+    #[test]
+    fn settings_carry_over_to_new_rows() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let settings = Settings {
+                page_size: 3,
+                ..Settings::default()
+            };
+            let mut lineup = Lineup::new(schedule(&["2018-06-09"]), settings);
+            assert_eq!(lineup.rows[0].schedule.games.page_size(), 3);
+            // The window changing size re-pages the rows that are already around, and any that
+            // show up later on start out that way.
+            lineup.set_page_size(2);
+            lineup.poll();
+            lineup.merge(schedule(&["2018-06-09", "2018-06-10"]));
+            for row in lineup.rows.iter() {
+                assert_eq!(row.schedule.games.page_size(), 2);
+            }
+        });
+    }
+
+    #[test]
+    fn focus_game() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut lineup = Lineup::from(schedule(&["2018-06-09"]));
+            let pk = lineup.rows[0].schedule.games[2].pk;
+            assert!(lineup.focus_game(pk));
+            assert_eq!(lineup.current().unwrap().schedule.focused().unwrap().pk, pk);
+            assert!(!lineup.focus_game(0));
+        });
+    }
+
+    #[test]
+    fn live_date() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut schedule = schedule(&["2018-06-09", "2018-06-10"]);
+            assert_eq!(Lineup::from(schedule.clone()).live_date(), None);
+            schedule.dates[1].games[0].status.abstract_game_state = "Live".to_string();
+            assert_eq!(Lineup::from(schedule).live_date(), Some("2018-06-10"));
+        });
+    }
+
+    #[test]
+    fn merge_follows_the_date() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut lineup = Lineup::from(schedule(&["2018-06-09", "2018-06-10"]));
+            lineup.down();
+            lineup.merge(schedule(&["2018-06-08", "2018-06-09", "2018-06-10"]));
+            assert_eq!(lineup.current().unwrap().date, "2018-06-10");
+            lineup.merge(schedule(&[]));
+            assert_eq!(lineup.current().unwrap().date, "2018-06-10");
+        });
+    }
+
+    #[test]
+    fn keeps_the_copyright() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut lineup = Lineup::from(schedule(&["2018-06-09"]));
+            assert!(lineup
+                .copyright()
+                .starts_with("Copyright 2020 MLB Advanced Media"));
+            // A refresh that somehow came back without one doesn't get to erase it.
+            let mut fresh = schedule(&["2018-06-09"]);
+            fresh.copyright.clear();
+            lineup.merge(fresh);
+            assert!(lineup
+                .copyright()
+                .starts_with("Copyright 2020 MLB Advanced Media"));
+        });
+    }
+
+    #[test]
+    fn evicts_the_least_recently_seen() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut lineup = Lineup::from(schedule(&["2018-06-09", "2018-06-10"]));
+            // Every photo gets a decoded image, each one seen a little later than the last.
+            let start = std::time::Instant::now();
+            let mut total = 0;
+            for (index, photo) in lineup
+                .rows
+                .iter_mut()
+                .flat_map(|row| row.schedule.photos_mut())
+                .enumerate()
+            {
+                photo.photo = Some(image::RgbaImage::new(10, 10));
+                photo.used = start + std::time::Duration::from_secs(index as u64);
+                total += photo.bytes();
+            }
+            let evicted = |lineup: &mut Lineup| -> Vec<bool> {
+                lineup
+                    .rows
+                    .iter_mut()
+                    .flat_map(|row| row.schedule.photos_mut())
+                    .map(|photo| photo.evicted)
+                    .collect()
+            };
+            lineup.evict(total);
+            assert!(evicted(&mut lineup).iter().all(|&evicted| !evicted));
+            // Just the one has to go, and it's the oldest of those that are off screen.
+            lineup.evict(total - 1);
+            let oldest = lineup
+                .rows
+                .iter_mut()
+                .flat_map(|row| row.schedule.photos_mut())
+                .position(|photo| photo.ticket.is_offscreen())
+                .unwrap();
+            let after = evicted(&mut lineup);
+            assert_eq!(after.iter().filter(|&&evicted| evicted).count(), 1);
+            assert!(after[oldest]);
+            // Whatever is on screen stays put no matter how tight things get.
+            lineup.evict(0);
+            for photo in lineup
+                .rows
+                .iter_mut()
+                .flat_map(|row| row.schedule.photos_mut())
+            {
+                assert_eq!(photo.evicted, photo.ticket.is_offscreen());
+                assert_eq!(photo.photo.is_some(), !photo.evicted);
+            }
+        });
+    }
+
+    #[test]
+    fn advancing_wraps_around() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut lineup = Lineup::from(schedule(&["2018-06-09", "2018-06-10"]));
+            let at = |lineup: &mut Lineup| {
+                let row = lineup.current().unwrap();
+                (row.date.clone(), row.schedule.games.cursor())
+            };
+            let mut games = 0;
+            while at(&mut lineup).0 == "2018-06-09" {
+                lineup.advance();
+                games += 1;
+            }
+            assert!(games > 1);
+            assert_eq!(at(&mut lineup), ("2018-06-10".to_string(), 0));
+            for _ in 0..games {
+                lineup.advance();
+            }
+            assert_eq!(at(&mut lineup), ("2018-06-09".to_string(), 0));
+        });
+    }
+
+    #[test]
+    fn fresh_photos_are_handed_over_once() {
+        tokio::runtime::Runtime::new().unwrap().enter(|| {
+            let mut lineup = Lineup::from(schedule(&["2018-06-09"]));
+            assert!(lineup.take_fresh(100).is_empty());
+            let mut count = 0;
+            for photo in lineup
+                .rows
+                .iter_mut()
+                .flat_map(|row| row.schedule.photos_mut())
+            {
+                photo.photo = Some(RgbaImage::new(4, 4));
+                photo.fresh = true;
+                count += 1;
+            }
+            let stats = lineup.stats();
+            assert_eq!((stats.decoded, stats.bytes), (count, count * 4 * 4 * 4));
+            assert_eq!(lineup.take_fresh(2).len(), 2);
+            assert_eq!(lineup.take_fresh(100).len(), count - 2);
+            assert!(lineup.take_fresh(100).is_empty());
+        });
+    }
+}
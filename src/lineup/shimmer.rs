@@ -0,0 +1,95 @@
+use super::Cut;
+use image::{Rgba, RgbaImage};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// How many frames make up one sweep of the highlight, and how long a sweep takes. A dozen
+// frames is about as few as it takes for the band to glide rather than hop, and each one of
+// them is a whole texture, so there's no sense in going overboard.
+const FRAMES: usize = 12;
+const SWEEP: u128 = 1200;
+
+// Just a touch lighter than the background, so that the skeleton reads as "something goes
+// here" without shouting over the photos that have already shown up.
+const BASE: [f32; 3] = [38.0, 38.0, 44.0];
+const HIGHLIGHT: [f32; 3] = [70.0, 70.0, 80.0];
+
+lazy_static! {
+    // Drawing these isn't free, but it only ever happens the once.
+    static ref LARGE: Vec<(String, RgbaImage)> = frames(Cut::Large.dimensions());
+    static ref SMALL: Vec<(String, RgbaImage)> = frames(Cut::Small.dimensions());
+}
+
+/// The frame of the large shimmer that is up right now, along with its id.
+pub fn large() -> (&'static str, &'static RgbaImage) {
+    current(&LARGE)
+}
+
+/// The frame of the small shimmer that is up right now, along with its id.
+pub fn small() -> (&'static str, &'static RgbaImage) {
+    current(&SMALL)
+}
+
+// Everything shimmering goes by the wall clock rather than by when its photo was asked for,
+// so that a whole page of them sweeps along together instead of each doing its own thing.
+fn current(frames: &'static [(String, RgbaImage)]) -> (&'static str, &'static RgbaImage) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_millis())
+        .unwrap_or(0);
+    let (id, rgba) = &frames[frame(now)];
+    (id, rgba)
+}
+
+// Which frame is up at the given number of milliseconds into the epoch.
+fn frame(millis: u128) -> usize {
+    (millis % SWEEP * FRAMES as u128 / SWEEP) as usize
+}
+
+// Every frame of a shimmer of the given size. Each one is a flat grey with a soft, lighter
+// band running diagonally across it, and the band is a little further along in each frame
+// until it has swept from just off of the left edge to just off of the right.
+fn frames((width, height): (u32, u32)) -> Vec<(String, RgbaImage)> {
+    let band = width as f32 / 4.0;
+    // The diagonal runs from 0 at the top left corner to this at the bottom right.
+    let span = width as f32 + height as f32 / 2.0;
+    (0..FRAMES)
+        .map(|index| {
+            let center = -band + (span + band * 2.0) * index as f32 / FRAMES as f32;
+            let rgba = RgbaImage::from_fn(width, height, |x, y| {
+                let distance = (x as f32 + y as f32 / 2.0 - center).abs();
+                let t = (1.0 - distance / band).max(0.0);
+                // Smoothstep, so that the band doesn't have hard edges to it.
+                let t = t * t * (3.0 - 2.0 * t);
+                let mix = |channel: usize| {
+                    (BASE[channel] + (HIGHLIGHT[channel] - BASE[channel]) * t).round() as u8
+                };
+                Rgba([mix(0), mix(1), mix(2), 255])
+            });
+            (format!("shimmer_{}x{}_{}", width, height, index), rgba)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sized_like_the_cuts() {
+        assert_eq!(LARGE.len(), FRAMES);
+        assert_eq!(LARGE[0].1.dimensions(), (480, 270));
+        assert_eq!(SMALL[0].1.dimensions(), (320, 180));
+        assert_eq!(SMALL[3].0, "shimmer_320x180_3");
+    }
+
+    #[test]
+    fn the_band_sweeps_across() {
+        let frames = frames((40, 20));
+        let brightest =
+            |rgba: &RgbaImage| (0..40).max_by_key(|&x| rgba.get_pixel(x, 0).0[0]).unwrap();
+        assert!(brightest(&frames[3].1) < brightest(&frames[6].1));
+        assert_eq!(frame(0), 0);
+        assert_eq!(frame(SWEEP / 2), FRAMES / 2);
+        assert_eq!(frame(SWEEP * 3 - 1), FRAMES - 1);
+    }
+}
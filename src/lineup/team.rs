@@ -0,0 +1,341 @@
+use super::{first_pitch, poll, Polled, State};
+use crate::api::{self, teams};
+use chrono::NaiveDate;
+use chrono_tz::Tz;
+
+/// A team's page: their record, the last five games that they played and the next five that
+/// they're going to, and who is on their active roster.
+///
+/// It all comes from a handful of calls that go out in the background the moment that this is
+/// made. Until they come back there's only the team's id to go on.
+pub struct Team {
+    /// The API's id for the team.
+    pub id: u32,
+    page: Option<Page>,
+    // What start times are given in, or None for the machine's own timezone.
+    timezone: Option<Tz>,
+    // The page that is still on its way, if it is.
+    channel: Option<crossbeam_channel::Receiver<teams::Club>>,
+}
+
+/// Everything on a team's page, already written out.
+#[derive(Debug, PartialEq)]
+pub struct Page {
+    /// E.G. "Boston Red Sox"
+    pub name: String,
+    /// Where they play their home games, E.G. "Fenway Park".
+    pub venue: Option<String>,
+    // Wins and losses, in that order, as of the last game that they played.
+    record: Option<(u32, u32)>,
+    // The most recent first.
+    played: Vec<Played>,
+    /// The games coming up, soonest first, E.G. "9/24 @ NYY, 1:05 PM".
+    pub upcoming: Vec<String>,
+    /// Everybody on the active roster by their id, E.G. (519144, "#22 Rick Porcello, P").
+    pub roster: Vec<(u64, String)>,
+}
+
+// A game that has been played, with who it was against kept apart from how it went so that
+// spoiler-free mode can leave the latter off.
+#[derive(Debug, PartialEq)]
+struct Played {
+    // E.G. "9/22 vs. CWS"
+    matchup: String,
+    // E.G. "L 1-8"
+    result: String,
+}
+
+impl Team {
+    /// Looks up the team with the given id, as of the given date, in the given sport. Their
+    /// upcoming games start at times in the given timezone (or the machine's own for None).
+    pub fn new(id: u32, date: NaiveDate, sport_id: u32, timezone: Option<Tz>) -> Team {
+        Team {
+            id,
+            page: None,
+            timezone,
+            channel: Some(teams::fetch(id, date, sport_id)),
+        }
+    }
+
+    /// Whether or not the page is still on its way.
+    pub fn loading(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    /// The page, once it has come in.
+    pub fn page(&self) -> Option<&Page> {
+        self.page.as_ref()
+    }
+
+    /// Whether or not the page couldn't be had, which has already been logged.
+    pub fn failed(&self) -> bool {
+        !self.loading() && self.page.is_none()
+    }
+
+    /// Checks in on the page, returning true if it has just come in (or just given up).
+    pub fn poll(&mut self, today: NaiveDate) -> bool {
+        match poll(&mut self.channel) {
+            Polled::Arrived(club) => {
+                self.page = Some(Page::new(club, today, self.timezone));
+                true
+            }
+            Polled::Failed => true,
+            Polled::Waiting => false,
+        }
+    }
+}
+
+impl Page {
+    // How many games to show on either side of today.
+    const GAMES: usize = 5;
+
+    /// Writes out the given team's page as of the given day. Games from before it that are over
+    /// count as played, and the rest from it on are still to come, starting at times in the
+    /// given timezone.
+    pub fn new(club: teams::Club, today: NaiveDate, timezone: Option<Tz>) -> Page {
+        let id = club.team.id;
+        let mut record = None;
+        let mut played = vec![];
+        let mut upcoming = vec![];
+        for date in club.schedule.dates {
+            let day = NaiveDate::parse_from_str(&date.date, "%Y-%m-%d").ok();
+            for game in date.games {
+                let (ours, theirs, home) = match game.teams {
+                    api::Teams { away, home } if home.team.id == id => (home, away, true),
+                    api::Teams { away, home } => (away, home, false),
+                };
+                let opponent = theirs
+                    .team
+                    .abbreviation
+                    .as_ref()
+                    .unwrap_or(&theirs.team.name);
+                let matchup = format!(
+                    "{} {} {}",
+                    day.map(|day| day.format("%-m/%-d").to_string())
+                        .unwrap_or_default(),
+                    if home { "vs." } else { "@" },
+                    opponent
+                );
+                let state = State::from(game.status.abstract_game_state.as_str());
+                match (state, ours.score, theirs.score) {
+                    // Games that got called off before they started are "Final" too, but
+                    // without a score.
+                    (State::Final, Some(us), Some(them)) => {
+                        let outcome = match us.cmp(&them) {
+                            std::cmp::Ordering::Greater => "W",
+                            std::cmp::Ordering::Less => "L",
+                            std::cmp::Ordering::Equal => "T",
+                        };
+                        record = ours
+                            .league_record
+                            .map(|record| (record.wins, record.losses))
+                            .or(record);
+                        played.push(Played {
+                            matchup,
+                            result: format!("{} {}-{}", outcome, us, them),
+                        });
+                    }
+                    (State::Final, _, _) => (),
+                    _ if day.filter(|day| *day < today).is_some() => (),
+                    _ => {
+                        let time = game
+                            .game_date
+                            .as_deref()
+                            .and_then(|time| first_pitch(time, timezone));
+                        upcoming.push(match (state, time) {
+                            (State::Live, _) => {
+                                format!("{}, {}", matchup, game.status.detailed_state)
+                            }
+                            (_, Some(time)) => format!("{}, {}", matchup, time),
+                            (_, None) => matchup,
+                        });
+                    }
+                }
+            }
+        }
+        played.reverse();
+        played.truncate(Self::GAMES);
+        upcoming.truncate(Self::GAMES);
+        let roster = club
+            .roster
+            .into_iter()
+            .map(|member| {
+                let player = format!(
+                    "{}, {}",
+                    member.person.full_name, member.position.abbreviation
+                );
+                let player = match member.jersey_number {
+                    Some(number) => format!("#{} {}", number, player),
+                    None => player,
+                };
+                (member.person.id, player)
+            })
+            .collect();
+        Page {
+            name: club.team.name,
+            venue: club.team.venue.map(|venue| venue.name),
+            record,
+            played,
+            upcoming,
+            roster,
+        }
+    }
+
+    /// Their record, E.G. "84-78". None in spoiler-free mode, since it gives away how their
+    /// last game went, or if they haven't played yet.
+    pub fn record(&self, spoiler_free: bool) -> Option<String> {
+        let (wins, losses) = self.record.filter(|_| !spoiler_free)?;
+        Some(format!("{}-{}", wins, losses))
+    }
+
+    /// The last games that they played, most recent first, E.G. "9/22 vs. CWS L 1-8". In
+    /// spoiler-free mode it's only who they played, E.G. "9/22 vs. CWS".
+    pub fn played(&self, spoiler_free: bool) -> Vec<String> {
+        self.played
+            .iter()
+            .map(|played| match spoiler_free {
+                true => played.matchup.clone(),
+                false => format!("{} {}", played.matchup, played.result),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(
+        date: &str,
+        state: &str,
+        away: (u32, &str, Option<u32>),
+        home: (u32, &str, Option<u32>),
+    ) -> String {
+        let side = |(id, abbreviation, score): (u32, &str, Option<u32>)| {
+            let score = score.map(|score| format!(r#""score": {},"#, score));
+            format!(
+                r#"{{{} "leagueRecord": {{"wins": {}, "losses": 1}},
+                    "team": {{"id": {}, "name": "{}", "abbreviation": "{}"}}}}"#,
+                score.unwrap_or_default(),
+                date[8..].parse::<u32>().unwrap(),
+                id,
+                abbreviation,
+                abbreviation
+            )
+        };
+        format!(
+            r#"{{"date": "{}", "games": [{{
+                "gamePk": 0,
+                "status": {{"abstractGameState": "{}", "detailedState": "{}"}},
+                "teams": {{"away": {}, "home": {}}}
+            }}]}}"#,
+            date,
+            state,
+            state,
+            side(away),
+            side(home)
+        )
+    }
+
+    #[test]
+    fn page() {
+        let (bos, cws, nyy) = ((111, "BOS"), (145, "CWS"), (147, "NYY"));
+        let dates = [
+            game(
+                "2019-09-20",
+                "Final",
+                (bos.0, bos.1, Some(5)),
+                (nyy.0, nyy.1, Some(2)),
+            ),
+            // Rained out.
+            game(
+                "2019-09-21",
+                "Final",
+                (bos.0, bos.1, None),
+                (nyy.0, nyy.1, None),
+            ),
+            game(
+                "2019-09-22",
+                "Final",
+                (cws.0, cws.1, Some(8)),
+                (bos.0, bos.1, Some(1)),
+            ),
+            game(
+                "2019-09-23",
+                "Preview",
+                (cws.0, cws.1, None),
+                (bos.0, bos.1, None),
+            ),
+            game(
+                "2019-09-24",
+                "Preview",
+                (bos.0, bos.1, None),
+                (nyy.0, nyy.1, None),
+            ),
+        ];
+        let club = teams::Club {
+            team: serde_json::from_str(
+                r#"{"id": 111, "name": "Boston Red Sox", "abbreviation": "BOS",
+                    "venue": {"id": 3, "name": "Fenway Park"}}"#,
+            )
+            .unwrap(),
+            schedule: serde_json::from_str(&format!(
+                r#"{{"copyright": "", "dates": [{}]}}"#,
+                dates.join(",")
+            ))
+            .unwrap(),
+            roster: serde_json::from_str::<teams::Roster>(
+                r#"{"roster": [
+                    {"person": {"id": 519144, "fullName": "Rick Porcello"}, "jerseyNumber": "22",
+                     "position": {"abbreviation": "P"}},
+                    {"person": {"id": 646240, "fullName": "Rafael Devers"},
+                     "position": {"abbreviation": "3B"}}
+                ]}"#,
+            )
+            .unwrap()
+            .roster,
+        };
+        let page = Page::new(club, NaiveDate::from_ymd_opt(2019, 9, 23).unwrap(), None);
+        assert_eq!(page.name, "Boston Red Sox");
+        assert_eq!(page.venue.as_deref(), Some("Fenway Park"));
+        // As of the 22nd, the last game that they played.
+        assert_eq!(page.record(false).as_deref(), Some("22-1"));
+        assert_eq!(page.record(true), None);
+        assert_eq!(
+            page.played(false),
+            vec!["9/22 vs. CWS L 1-8", "9/20 @ NYY W 5-2"]
+        );
+        assert_eq!(page.played(true), vec!["9/22 vs. CWS", "9/20 @ NYY"]);
+        assert_eq!(page.upcoming, vec!["9/23 vs. CWS", "9/24 @ NYY"]);
+        let roster: Vec<(u64, &str)> = page
+            .roster
+            .iter()
+            .map(|(id, player)| (*id, player.as_str()))
+            .collect();
+        assert_eq!(
+            roster,
+            vec![
+                (519144, "#22 Rick Porcello, P"),
+                (646240, "Rafael Devers, 3B")
+            ]
+        );
+    }
+
+    #[test]
+    fn failed() {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let mut team = Team {
+            id: 111,
+            page: None,
+            timezone: None,
+            channel: Some(rx),
+        };
+        let today = NaiveDate::from_ymd_opt(2019, 9, 23).unwrap();
+        assert!(team.loading());
+        assert!(!team.poll(today));
+        drop(tx);
+        assert!(team.poll(today));
+        assert!(team.failed());
+        assert!(team.page().is_none());
+    }
+}
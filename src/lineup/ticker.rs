@@ -0,0 +1,216 @@
+use super::State;
+use crate::api::{self, scoreboard, Schedule};
+use chrono::NaiveDate;
+use std::time::{Duration, Instant};
+
+/// The scores of every game in progress on a given day, one at a time, for running along the
+/// bottom of the screen. The scoreboard is polled in the background for as long as this is
+/// around, and each score gets a few seconds up before the next one takes its turn.
+pub struct Ticker {
+    /// The day whose games these are.
+    pub date: NaiveDate,
+    /// And the sport that they're being played in.
+    pub sport_id: u32,
+    scores: Vec<Score>,
+    // Which of the scores is up, and since when.
+    index: usize,
+    shown: Instant,
+    channel: crossbeam_channel::Receiver<Schedule>,
+}
+
+/// Where one game in progress stands.
+#[derive(Debug, PartialEq)]
+pub struct Score {
+    /// The API's primary key for the game.
+    pub pk: u64,
+    // E.G. ("CWS", 5)
+    away: (String, u32),
+    home: (String, u32),
+    // E.G. "Top 5th", once the game has gotten that far.
+    inning: Option<String>,
+    // The outs only mean anything in the middle of a half inning, so they're None otherwise.
+    outs: Option<u32>,
+}
+
+impl Ticker {
+    const POLL_INTERVAL: Duration = Duration::from_secs(15);
+    const DWELL: Duration = Duration::from_secs(5);
+
+    /// Starts polling the scoreboard for the given day, in the given sport.
+    pub fn new(date: NaiveDate, sport_id: u32) -> Ticker {
+        Ticker {
+            date,
+            sport_id,
+            scores: vec![],
+            index: 0,
+            shown: Instant::now(),
+            channel: scoreboard::poll(date, sport_id, Self::POLL_INTERVAL),
+        }
+    }
+
+    /// The score that is up, if there's anything to show. There isn't until the scoreboard has
+    /// come in, nor once every game on it is over.
+    pub fn current(&self) -> Option<&Score> {
+        self.scores.get(self.index)
+    }
+
+    /// Which score is up, counting from one, and how many there are, E.G. (2, 5).
+    pub fn position(&self) -> (usize, usize) {
+        (self.index + 1, self.scores.len())
+    }
+
+    /// Checks in on the poller and moves on to the next score if this one has been up long
+    /// enough, returning true if what's up has changed either way.
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok(schedule) = self.channel.try_recv() {
+            let up = self.current().map(|score| score.pk);
+            self.scores = Self::scores(schedule);
+            // Whichever game was up stays up, wherever it is now. If it's over then the next
+            // one along is up instead.
+            self.index = up
+                .and_then(|pk| self.scores.iter().position(|score| score.pk == pk))
+                .unwrap_or(self.index);
+            changed = true;
+        }
+        if self.index >= self.scores.len() {
+            self.index = 0;
+        }
+        if self.scores.len() > 1 && self.shown.elapsed() >= Self::DWELL {
+            self.index = (self.index + 1) % self.scores.len();
+            self.shown = Instant::now();
+            changed = true;
+        }
+        changed
+    }
+
+    // Every game in progress, in the order that the API has them.
+    fn scores(schedule: Schedule) -> Vec<Score> {
+        schedule
+            .dates
+            .into_iter()
+            .flat_map(|date| date.games)
+            .filter(|game| State::from(game.status.abstract_game_state.as_str()) == State::Live)
+            .map(Score::from)
+            .collect()
+    }
+}
+
+impl From<api::Game> for Score {
+    fn from(game: api::Game) -> Self {
+        let linescore = game.linescore;
+        let runs = |away: bool| {
+            linescore
+                .as_ref()
+                .map(|linescore| match away {
+                    true => linescore.teams.away.runs,
+                    false => linescore.teams.home.runs,
+                })
+                .unwrap_or(0)
+        };
+        let club = |side: api::TeamSide| {
+            let team = side.team;
+            team.abbreviation.unwrap_or(team.name)
+        };
+        let (inning, outs) = match &linescore {
+            Some(linescore) => {
+                let inning = match (&linescore.inning_state, &linescore.inning) {
+                    (Some(state), Some(inning)) => Some(format!("{} {}", state, inning)),
+                    _ => None,
+                };
+                let outs = match linescore.inning_state.as_deref() {
+                    Some("Top") | Some("Bottom") => Some(linescore.outs),
+                    _ => None,
+                };
+                (inning, outs)
+            }
+            None => (None, None),
+        };
+        Score {
+            pk: game.game_pk,
+            away: (club(game.teams.away), runs(true)),
+            home: (club(game.teams.home), runs(false)),
+            inning,
+            outs,
+        }
+    }
+}
+
+impl Score {
+    /// E.G. "CWS 5 @ BOS 2, Top 5th, 1 out". In spoiler-free mode the score is left off, E.G.
+    /// "CWS @ BOS, Top 5th, 1 out".
+    pub fn text(&self, spoiler_free: bool) -> String {
+        let ((away, away_runs), (home, home_runs)) = (&self.away, &self.home);
+        let mut text = match spoiler_free {
+            true => format!("{} @ {}", away, home),
+            false => format!("{} {} @ {} {}", away, away_runs, home, home_runs),
+        };
+        if let Some(inning) = &self.inning {
+            text.push_str(&format!(", {}", inning));
+        }
+        if let Some(outs) = self.outs {
+            let noun = if outs == 1 { "out" } else { "outs" };
+            text.push_str(&format!(", {} {}", outs, noun));
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores() {
+        let schedule: Schedule = serde_json::from_str(
+            r#"{"copyright": "", "dates": [{"date": "2019-09-24", "games": [
+                {
+                    "gamePk": 1,
+                    "status": {"abstractGameState": "Live", "detailedState": "In Progress"},
+                    "teams": {
+                        "away": {"score": 5, "team": {"id": 145, "name": "Chicago White Sox",
+                                                      "abbreviation": "CWS"}},
+                        "home": {"score": 2, "team": {"id": 111, "name": "Boston Red Sox",
+                                                      "abbreviation": "BOS"}}
+                    },
+                    "linescore": {
+                        "currentInningOrdinal": "5th",
+                        "inningState": "Top",
+                        "outs": 1,
+                        "teams": {"away": {"runs": 5}, "home": {"runs": 2}}
+                    }
+                },
+                {
+                    "gamePk": 2,
+                    "status": {"abstractGameState": "Live", "detailedState": "In Progress"},
+                    "teams": {
+                        "away": {"team": {"id": 147, "name": "New York Yankees"}},
+                        "home": {"team": {"id": 110, "name": "Baltimore Orioles"}}
+                    },
+                    "linescore": {
+                        "currentInningOrdinal": "7th",
+                        "inningState": "Middle",
+                        "teams": {"away": {}, "home": {}}
+                    }
+                },
+                {
+                    "gamePk": 3,
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"team": {"id": 117, "name": "Houston Astros"}},
+                        "home": {"team": {"id": 136, "name": "Seattle Mariners"}}
+                    }
+                }
+            ]}]}"#,
+        )
+        .unwrap();
+        let scores = Ticker::scores(schedule);
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].text(false), "CWS 5 @ BOS 2, Top 5th, 1 out");
+        assert_eq!(scores[0].text(true), "CWS @ BOS, Top 5th, 1 out");
+        assert_eq!(
+            scores[1].text(false),
+            "New York Yankees 0 @ Baltimore Orioles 0, Middle 7th"
+        );
+    }
+}
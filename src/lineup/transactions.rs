@@ -0,0 +1,192 @@
+use super::{poll, Polled};
+use crate::api::transactions::{self, Scope, Transaction};
+use crate::paginator::Paginator;
+use chrono::{Duration, NaiveDate};
+
+/// The trades, signings, and roster moves of the last month, for one team or for the whole
+/// league, newest first, for paging through.
+///
+/// They're looked up in the background the moment that this is made.
+pub struct Transactions {
+    /// Whose moves these are.
+    pub scope: Scope,
+    moves: Paginator<Move>,
+    // The moves that are still on their way, if they are.
+    channel: Option<crossbeam_channel::Receiver<Vec<Transaction>>>,
+}
+
+/// One transaction, already written out.
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    /// E.G. "9/20"
+    pub date: String,
+    /// E.G. "Status Change"
+    pub kind: String,
+    /// E.G. "Boston Red Sox activated RHP Nathan Eovaldi from the 10-day injured list."
+    pub description: String,
+}
+
+impl Transactions {
+    // As many lines as fit down the screen at once.
+    pub const PAGE_SIZE: usize = 15;
+    // How far back to go.
+    const DAYS: i64 = 30;
+
+    /// Looks up the given scope's moves from the month leading up through the given day.
+    pub fn new(scope: Scope, today: NaiveDate) -> Transactions {
+        Transactions {
+            scope,
+            moves: Paginator::new(vec![], Self::PAGE_SIZE),
+            channel: Some(transactions::fetch(
+                scope,
+                today - Duration::days(Self::DAYS),
+                today,
+            )),
+        }
+    }
+
+    /// Whether or not the moves are still on their way.
+    pub fn loading(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    /// Whether or not there turned out to be nothing to list (or the moves couldn't be had,
+    /// which has already been logged).
+    pub fn is_empty(&self) -> bool {
+        !self.loading() && self.moves.is_empty()
+    }
+
+    pub fn up(&mut self) {
+        self.moves.left();
+    }
+
+    pub fn down(&mut self) {
+        self.moves.right();
+    }
+
+    pub fn previous_page(&mut self) {
+        self.moves.previous_page();
+    }
+
+    pub fn next_page(&mut self) {
+        self.moves.next_page();
+    }
+
+    /// Every move on the current page, along with whether or not it's the one that the cursor
+    /// is on.
+    pub fn page(&self) -> Vec<(&Move, bool)> {
+        let focus = self.moves.page_focus();
+        self.moves
+            .current_page()
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (entry, index == focus))
+            .collect()
+    }
+
+    /// Checks in on the moves, returning true if they've just come in (or just given up).
+    pub fn poll(&mut self) -> bool {
+        match poll(&mut self.channel) {
+            Polled::Arrived(transactions) => {
+                self.moves.replace(moves(transactions));
+                true
+            }
+            Polled::Failed => true,
+            Polled::Waiting => false,
+        }
+    }
+}
+
+// The API lists them oldest first, and the ones without anything to say for themselves are
+// left off.
+fn moves(transactions: Vec<Transaction>) -> Vec<Move> {
+    let mut moves: Vec<(Option<NaiveDate>, Move)> = transactions
+        .into_iter()
+        .filter_map(|transaction| {
+            let date = transaction
+                .date
+                .as_deref()
+                .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+            let kind = transaction.type_desc.unwrap_or_default();
+            // Without a description, who it happened to and what it was is the next best thing.
+            let description = match (transaction.description, transaction.person) {
+                (Some(description), _) => description,
+                (None, Some(person)) if !kind.is_empty() => {
+                    format!("{}: {}", kind, person.full_name)
+                }
+                _ => return None,
+            };
+            Some((
+                date,
+                Move {
+                    date: date
+                        .map(|date| date.format("%-m/%-d").to_string())
+                        .unwrap_or_default(),
+                    kind,
+                    description,
+                },
+            ))
+        })
+        .collect();
+    // Stable, so that moves from the same day keep the order that they were made in, reversed.
+    moves.reverse();
+    moves.sort_by(|(a, _), (b, _)| b.cmp(a));
+    moves.into_iter().map(|(_, entry)| entry).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newest_first() {
+        let transactions: transactions::Transactions = serde_json::from_str(
+            r#"{"transactions": [
+                {"date": "2019-09-01", "typeDesc": "Recalled",
+                 "description": "Boston Red Sox recalled RHP Travis Lakins."},
+                {"date": "2019-09-20", "typeDesc": "Status Change",
+                 "person": {"id": 543135, "fullName": "Nathan Eovaldi"}},
+                {"date": "2019-09-20", "typeDesc": "Trade"},
+                {"date": "2019-09-21", "typeDesc": "Signed as Free Agent",
+                 "description": "Boston Red Sox signed Rusney Castillo."}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            moves(transactions.transactions),
+            [
+                Move {
+                    date: "9/21".to_string(),
+                    kind: "Signed as Free Agent".to_string(),
+                    description: "Boston Red Sox signed Rusney Castillo.".to_string(),
+                },
+                Move {
+                    date: "9/20".to_string(),
+                    kind: "Status Change".to_string(),
+                    description: "Status Change: Nathan Eovaldi".to_string(),
+                },
+                Move {
+                    date: "9/1".to_string(),
+                    kind: "Recalled".to_string(),
+                    description: "Boston Red Sox recalled RHP Travis Lakins.".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn failed() {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let mut transactions = Transactions {
+            scope: Scope::Sport(1),
+            moves: Paginator::new(vec![], Transactions::PAGE_SIZE),
+            channel: Some(rx),
+        };
+        assert!(transactions.loading());
+        assert!(!transactions.poll());
+        drop(tx);
+        assert!(transactions.poll());
+        assert!(transactions.is_empty());
+        assert!(transactions.page().is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+use super::{first_pitch, poll, Polled, State};
+use crate::api::{
+    self,
+    days::{self, Days},
+};
+use chrono::{Duration, NaiveDate};
+use chrono_tz::Tz;
+
+/// A week of games, a day at a time, with each game boiled down to a line. This is for getting
+/// the lay of the land before settling on a day to look at in full.
+///
+/// The whole week is looked up in the background, with the one request, the moment that this
+/// is made.
+pub struct Week {
+    /// The first of the seven days.
+    pub start: NaiveDate,
+    days: Option<Vec<Day>>,
+    // What start times are given in, or None for the machine's own timezone.
+    timezone: Option<Tz>,
+    // The week that is still on its way, if it is.
+    channel: Option<crossbeam_channel::Receiver<Days>>,
+}
+
+/// One day of the week.
+#[derive(Debug, PartialEq)]
+pub struct Day {
+    pub date: NaiveDate,
+    /// In the order that the API has them, which is by first pitch.
+    pub games: Vec<Line>,
+}
+
+/// One game, written out small.
+#[derive(Debug, PartialEq)]
+pub struct Line {
+    /// The API's primary key for the game, for finding it again in the carousel.
+    pub pk: u64,
+    // E.G. ("CWS", Some(5))
+    away: (String, Option<u32>),
+    home: (String, Option<u32>),
+    // When it starts for games that haven't, E.G. "7:10 PM", and otherwise where it stands,
+    // E.G. "Final".
+    status: String,
+    // Whether or not it has gotten underway, since those are the games with something to spoil.
+    started: bool,
+}
+
+impl Week {
+    pub const DAYS: usize = 7;
+
+    /// Looks up the week starting on the given day, in the given sport, with start times given
+    /// in the given timezone (or the machine's own for None).
+    pub fn new(start: NaiveDate, sport_id: u32, timezone: Option<Tz>) -> Week {
+        let end = start + Duration::days(Self::DAYS as i64 - 1);
+        Week {
+            start,
+            days: None,
+            timezone,
+            channel: Some(days::fetch(start, end, sport_id)),
+        }
+    }
+
+    /// Whether or not the week is still on its way.
+    pub fn loading(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    /// Every day of the week, once they've come in. Days without any games are still there,
+    /// just without any games.
+    pub fn days(&self) -> Option<&[Day]> {
+        self.days.as_deref()
+    }
+
+    /// Whether or not the week couldn't be had, which has already been logged.
+    pub fn failed(&self) -> bool {
+        !self.loading() && self.days.is_none()
+    }
+
+    /// Checks in on the week, returning true if it has just come in (or just given up).
+    pub fn poll(&mut self) -> bool {
+        match poll(&mut self.channel) {
+            Polled::Arrived(days) => {
+                self.days = Some(Self::split(&days, self.start, self.timezone));
+                true
+            }
+            Polled::Failed => true,
+            Polled::Waiting => false,
+        }
+    }
+
+    // Each of the seven days starting at the given one, with their games written out.
+    fn split(days: &Days, start: NaiveDate, timezone: Option<Tz>) -> Vec<Day> {
+        (0..Self::DAYS as i64)
+            .map(|offset| start + Duration::days(offset))
+            .map(|date| Day {
+                date,
+                games: days
+                    .schedule(date, 1)
+                    .map(|schedule| schedule.dates)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .flat_map(|day| day.games)
+                    .map(|game| Line::new(game, timezone))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+impl Line {
+    // Writes the given game out small, with its start time in the given timezone.
+    fn new(game: api::Game, timezone: Option<Tz>) -> Line {
+        let state = State::from(game.status.abstract_game_state.as_str());
+        let club = |side: api::TeamSide| {
+            let team = side.team;
+            (team.abbreviation.unwrap_or(team.name), side.score)
+        };
+        let time = game
+            .game_date
+            .as_deref()
+            .and_then(|time| first_pitch(time, timezone));
+        let status = match (state, time) {
+            // Postponed games are still previews, but when they were going to start is moot.
+            (State::Preview, Some(time)) if game.status.detailed_state == "Scheduled" => time,
+            _ => game.status.detailed_state,
+        };
+        Line {
+            pk: game.game_pk,
+            away: club(game.teams.away),
+            home: club(game.teams.home),
+            status,
+            started: state != State::Preview,
+        }
+    }
+
+    /// E.G. "CWS 5 @ BOS 2, Final", or "CWS @ BOS, 7:10 PM" for a game that hasn't started. In
+    /// spoiler-free mode the score is left off, E.G. "CWS @ BOS, Final".
+    pub fn text(&self, spoiler_free: bool) -> String {
+        let ((away, away_score), (home, home_score)) = (&self.away, &self.home);
+        match (away_score, home_score) {
+            (Some(away_score), Some(home_score)) if !(spoiler_free && self.started) => format!(
+                "{} {} @ {} {}, {}",
+                away, away_score, home, home_score, self.status
+            ),
+            _ => format!("{} @ {}, {}", away, home, self.status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Schedule;
+
+    #[test]
+    fn week() {
+        let schedule: Schedule = serde_json::from_str(
+            r#"{"copyright": "", "dates": [
+                {"date": "2019-09-23", "games": [{
+                    "gamePk": 1,
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"score": 5, "team": {"id": 145, "name": "Chicago White Sox",
+                                                      "abbreviation": "CWS"}},
+                        "home": {"score": 2, "team": {"id": 111, "name": "Boston Red Sox",
+                                                      "abbreviation": "BOS"}}
+                    }
+                }]},
+                {"date": "2019-09-25", "games": [{
+                    "gamePk": 2,
+                    "status": {"abstractGameState": "Preview", "detailedState": "Postponed"},
+                    "teams": {
+                        "away": {"team": {"id": 147, "name": "New York Yankees"}},
+                        "home": {"team": {"id": 111, "name": "Boston Red Sox",
+                                          "abbreviation": "BOS"}}
+                    }
+                }]}
+            ]}"#,
+        )
+        .unwrap();
+        let start = NaiveDate::from_ymd_opt(2019, 9, 23).unwrap();
+        let days = Days::new(schedule, start, start + Duration::days(6));
+        let week = Week::split(&days, start, None);
+        assert_eq!(week.len(), Week::DAYS);
+        assert_eq!(week[6].date, NaiveDate::from_ymd_opt(2019, 9, 29).unwrap());
+        assert!(week[1].games.is_empty());
+        let final_score = &week[0].games[0];
+        assert_eq!(final_score.pk, 1);
+        assert_eq!(final_score.text(false), "CWS 5 @ BOS 2, Final");
+        assert_eq!(final_score.text(true), "CWS @ BOS, Final");
+        assert_eq!(
+            week[2].games[0].text(false),
+            "New York Yankees @ BOS, Postponed"
+        );
+    }
+}
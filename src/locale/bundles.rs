@@ -0,0 +1,48 @@
+use super::Language;
+use fluent::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
+
+thread_local! {
+    // Bundles can't be shared between threads, so each thread that has something to say gets
+    // its own. In practice that's only ever the main one. They're put together the first time
+    // that they're needed, since most runs only ever need the one.
+    static BUNDLES: RefCell<HashMap<Language, FluentBundle<FluentResource>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// The message with the given id in the given language, with the given blanks filled in, if the
+/// language has it.
+pub fn format(language: Language, id: &str, args: &[(&str, &str)]) -> Option<String> {
+    BUNDLES.with(|bundles| {
+        let mut bundles = bundles.borrow_mut();
+        let bundle = bundles.entry(language).or_insert_with(|| bundle(language));
+        let pattern = bundle.get_message(id)?.value?;
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.insert(*name, FluentValue::from(*value));
+        }
+        let mut errors = vec![];
+        let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+        if !errors.is_empty() {
+            warn!(id = id, errors = ?errors, "Failed to fill in a message");
+        }
+        Some(text.into_owned())
+    })
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let tag: LanguageIdentifier = language.tag().parse().unwrap();
+    let mut bundle = FluentBundle::new(&[tag]);
+    // Fluent wraps whatever fills in a blank with invisible marks, so that a name written
+    // right to left doesn't scramble the sentence around it. OpenSans doesn't have them and
+    // draws them as empty boxes, which scrambles things a good deal worse.
+    bundle.set_use_isolating(false);
+    let resource = FluentResource::try_new(language.resource().to_string())
+        .map_err(|(_, errors)| errors)
+        .unwrap();
+    bundle.add_resource(resource).unwrap();
+    bundle
+}
@@ -0,0 +1,157 @@
+# Everything that the window has to say, in English. Each of these has to be in every other
+# language too, even if only to say the same thing.
+
+## The carousel
+
+loading = Loading...
+no-games-on = No games scheduled on { $date }
+no-games-between = No games scheduled from { $from } through { $through }
+no-games-hint = Press up or down to look at another day
+no-games-label = { $date } - No games
+filter = Only showing games for { $teams }
+filter-unfinished = Only showing games that aren't over
+filter-unfinished-teams = Only showing games for { $teams } that aren't over
+filter-live = Only showing games being played right now
+filter-live-teams = Only showing games for { $teams } being played right now
+story-hint = Press { $key } for the whole story
+
+## Banners
+
+export-failed = Couldn't export the schedule to { $path }
+schedule-failed = Couldn't get the schedule for { $date }
+refresh-failed = Couldn't refresh the schedule, will try again in a bit
+photos-failed = Some photos couldn't be downloaded
+story-failed = Couldn't open the story in a browser
+video-failed = Couldn't open the video in a browser
+sport-switched = Now showing { $sport } games
+sort-favorites = Favorite teams' games first
+sort-time = Games in order of when they start
+sort-status = Games being played first, then the ones still to come
+on-this-day = On this day in { $year }
+
+## The ticker
+
+ticker-live = LIVE
+
+## Levels, by what they go by on the command line
+
+sport-mlb = MLB
+sport-aaa = Triple-A
+sport-aa = Double-A
+sport-high-a = High-A
+sport-single-a = Single-A
+sport-rookie = Rookie ball
+
+## A game's details
+
+spoilers-hidden = Spoilers are hidden. Reveal this game to see how it went.
+first-pitch = First pitch at { $time }
+weather-temperature = { $degrees }°F at first pitch
+weather-wind = wind { $wind }
+decision-win = W: { $pitcher }
+decision-loss = L: { $pitcher }
+decision-save = SV: { $pitcher }
+broadcasts-national = National: { $outlets }
+broadcasts-market = { $team }: { $outlets }
+scoring-plays = How the runs scored
+scoring-more = ...and { $count } more
+inning-top = Top { $inning }
+inning-bottom = Bottom { $inning }
+highlights = Highlights
+highlights-loading = Loading highlights...
+condensed-hint = Press { $key } to watch the condensed game
+recap-hint = Press { $key } to watch the recap
+
+## A team's page
+
+team-loading = Loading the { $team }...
+team-failed = Couldn't get the page for the { $team }
+team-record = Record: { $record }
+team-played = Last five
+team-upcoming = Next five
+team-roster = Active roster
+team-hint = Press left or right for the other club, or up, down, and Enter for a player
+
+## A player's page
+
+player-failed = Couldn't get this player's page
+player-no-stats = No stats yet
+player-bats = bats { $hand }
+player-throws = throws { $hand }
+player-weight = { $pounds } lb
+player-age = age { $age }
+player-born-in = Born { $date } in { $place }
+player-born = Born { $date }
+player-from = From { $place }
+player-debuted = Debuted { $date }
+player-season-hitting = { $season } hitting
+player-season-pitching = { $season } pitching
+player-career-hitting = Career hitting
+player-career-pitching = Career pitching
+
+## The transactions feed
+
+transactions-league = Transactions around the league, the last 30 days
+transactions-team = Transactions, the last 30 days
+transactions-loading = Loading transactions...
+transactions-none = Nothing to list
+transactions-hint = Press up and down to scroll, or Page Up and Page Down for a page at a time
+
+## The week view
+
+week-loading = Loading the week...
+week-failed = Couldn't get this week's games
+week-none = No games
+week-hint = Press left and right for a day, up and down for a game, Enter to open it up, or Page Up and Page Down for another week
+
+## The calendar
+
+calendar-loading = Looking up which days have games...
+calendar-failed = Couldn't look up which days have games
+calendar-hint = Press the arrow keys for a day, Page Up and Page Down for a month, or Enter to go there
+
+## Search
+
+search = Search: { $query }_
+search-no-matches = Search: { $query }_ (no matches)
+
+## Going to a date
+
+goto = Go to (YYYY-MM-DD): { $date }_
+goto-format = Dates go like 2021-10-03
+goto-no-such-day = There's no such day as { $date }
+
+## Errors
+
+panicked = Something went wrong: { $message }
+error-hint = Press R to try again, or Escape to quit.
+
+## Dates
+
+date-full = { $weekday }, { $month } { $day }, { $year }
+date-weekday = { $weekday }, { $month } { $day }
+date-month = { $month } { $day }
+date-month-year = { $month } { $day }, { $year }
+date-weekday-only = { $weekday }
+date-month-only = { $month } { $year }
+
+weekday-1 = Monday
+weekday-2 = Tuesday
+weekday-3 = Wednesday
+weekday-4 = Thursday
+weekday-5 = Friday
+weekday-6 = Saturday
+weekday-7 = Sunday
+
+month-1 = January
+month-2 = February
+month-3 = March
+month-4 = April
+month-5 = May
+month-6 = June
+month-7 = July
+month-8 = August
+month-9 = September
+month-10 = October
+month-11 = November
+month-12 = December
@@ -0,0 +1,156 @@
+# Todo lo que dice la ventana, en español.
+
+## El carrusel
+
+loading = Cargando...
+no-games-on = No hay juegos programados el { $date }
+no-games-between = No hay juegos programados del { $from } al { $through }
+no-games-hint = Pulsa arriba o abajo para ver otro día
+no-games-label = { $date } - Sin juegos
+filter = Solo se muestran los juegos de { $teams }
+filter-unfinished = Solo se muestran los juegos que no han terminado
+filter-unfinished-teams = Solo se muestran los juegos de { $teams } que no han terminado
+filter-live = Solo se muestran los juegos que se están jugando ahora
+filter-live-teams = Solo se muestran los juegos de { $teams } que se están jugando ahora
+story-hint = Pulsa { $key } para leer la historia completa
+
+## Avisos
+
+export-failed = No se pudo exportar el calendario a { $path }
+schedule-failed = No se pudo obtener el calendario del { $date }
+refresh-failed = No se pudo actualizar el calendario, se volverá a intentar en un rato
+photos-failed = No se pudieron descargar algunas fotos
+story-failed = No se pudo abrir la historia en el navegador
+video-failed = No se pudo abrir el video en el navegador
+sport-switched = Ahora se muestran los juegos de { $sport }
+sort-favorites = Primero los juegos de los equipos favoritos
+sort-time = Los juegos en orden de inicio
+sort-status = Primero los juegos que se están jugando, luego los que faltan
+on-this-day = Un día como hoy en { $year }
+
+## El marcador
+
+ticker-live = EN VIVO
+
+## Las categorías, por el nombre que llevan en la línea de comandos
+
+sport-mlb = MLB
+sport-aaa = Triple A
+sport-aa = Doble A
+sport-high-a = Clase A avanzada
+sport-single-a = Clase A
+sport-rookie = Liga de novatos
+
+## Los detalles de un juego
+
+spoilers-hidden = Los resultados están ocultos. Revela este juego para ver cómo terminó.
+first-pitch = Primer lanzamiento a las { $time }
+weather-temperature = { $degrees }°F en el primer lanzamiento
+weather-wind = viento { $wind }
+decision-win = G: { $pitcher }
+decision-loss = P: { $pitcher }
+decision-save = JS: { $pitcher }
+broadcasts-national = Nacional: { $outlets }
+broadcasts-market = { $team }: { $outlets }
+scoring-plays = Cómo se anotaron las carreras
+scoring-more = ...y { $count } más
+inning-top = Alta { $inning }
+inning-bottom = Baja { $inning }
+highlights = Jugadas destacadas
+highlights-loading = Cargando jugadas destacadas...
+condensed-hint = Pulsa { $key } para ver el juego condensado
+recap-hint = Pulsa { $key } para ver el resumen
+
+## La página de un equipo
+
+team-loading = Cargando { $team }...
+team-failed = No se pudo obtener la página de { $team }
+team-record = Récord: { $record }
+team-played = Últimos cinco
+team-upcoming = Próximos cinco
+team-roster = Plantilla activa
+team-hint = Pulsa izquierda o derecha para ver el otro equipo, o arriba, abajo y Enter para un jugador
+
+## La página de un jugador
+
+player-failed = No se pudo obtener la página de este jugador
+player-no-stats = Todavía no tiene estadísticas
+player-bats = batea { $hand }
+player-throws = lanza { $hand }
+player-weight = { $pounds } lb
+player-age = { $age } años
+player-born-in = Nació el { $date } en { $place }
+player-born = Nació el { $date }
+player-from = De { $place }
+player-debuted = Debutó el { $date }
+player-season-hitting = Bateo en { $season }
+player-season-pitching = Pitcheo en { $season }
+player-career-hitting = Bateo en su carrera
+player-career-pitching = Pitcheo en su carrera
+
+## Los movimientos
+
+transactions-league = Movimientos en toda la liga, últimos 30 días
+transactions-team = Movimientos del equipo, últimos 30 días
+transactions-loading = Cargando movimientos...
+transactions-none = No hay nada que mostrar
+transactions-hint = Pulsa arriba y abajo para desplazarte, o Re Pág y Av Pág para pasar de página
+
+## La semana
+
+week-loading = Cargando la semana...
+week-failed = No se pudieron obtener los partidos de esta semana
+week-none = Sin partidos
+week-hint = Pulsa izquierda y derecha para elegir un día, arriba y abajo para un partido, Enter para abrirlo, o Re Pág y Av Pág para otra semana
+
+## El calendario
+
+calendar-loading = Buscando los días con partidos...
+calendar-failed = No se pudo saber qué días hay partidos
+calendar-hint = Pulsa las flechas para elegir un día, Re Pág y Av Pág para cambiar de mes, o Enter para ir allí
+
+## Búsqueda
+
+search = Buscar: { $query }_
+search-no-matches = Buscar: { $query }_ (sin resultados)
+
+## Ir a una fecha
+
+goto = Ir a (AAAA-MM-DD): { $date }_
+goto-format = Las fechas van así: 2021-10-03
+goto-no-such-day = No existe el día { $date }
+
+## Errores
+
+panicked = Algo salió mal: { $message }
+error-hint = Pulsa R para volver a intentarlo, o Escape para salir.
+
+## Fechas
+
+date-full = { $weekday }, { $day } de { $month } de { $year }
+date-weekday = { $weekday }, { $day } de { $month }
+date-month = { $day } de { $month }
+date-month-year = { $day } de { $month } de { $year }
+date-weekday-only = { $weekday }
+date-month-only = { $month } de { $year }
+
+weekday-1 = lunes
+weekday-2 = martes
+weekday-3 = miércoles
+weekday-4 = jueves
+weekday-5 = viernes
+weekday-6 = sábado
+weekday-7 = domingo
+
+month-1 = enero
+month-2 = febrero
+month-3 = marzo
+month-4 = abril
+month-5 = mayo
+month-6 = junio
+month-7 = julio
+month-8 = agosto
+month-9 = septiembre
+month-10 = octubre
+month-11 = noviembre
+month-12 = diciembre
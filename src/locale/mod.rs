@@ -0,0 +1,179 @@
+use chrono::{Datelike, NaiveDate};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+mod bundles;
+
+/// The languages that the window knows how to speak. The words themselves are in a Fluent file
+/// for each of them, right next to this one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    English,
+    Spanish,
+}
+
+impl Language {
+    const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    /// Figures out the language from a locale, whether it's written the way that --language
+    /// takes it (E.G. "es" or "es-MX") or the way that $LANG has it (E.G. "es_MX.UTF-8").
+    /// Only the language counts, since no two countries' Spanish is different enough to matter
+    /// here.
+    pub fn parse(locale: &str) -> Option<Language> {
+        let language = locale
+            .split(['-', '_', '.', '@'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        match language.as_str() {
+            "en" => Some(Language::English),
+            "es" => Some(Language::Spanish),
+            _ => None,
+        }
+    }
+
+    /// Whatever language the system is set to, going by the same variables that everything
+    /// else on Linux (and macOS, from a terminal) goes by. Anything that isn't one of ours, or
+    /// the lack of anything at all, is English.
+    pub fn system() -> Language {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|locale| !locale.is_empty())
+            .and_then(|locale| Self::parse(&locale))
+            .unwrap_or(Language::English)
+    }
+
+    /// The language's two letter code, E.G. "es", which is what the API goes by.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    // The language's tag, for the bundle to go by when it comes to plurals and numbers.
+    fn tag(self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::Spanish => "es",
+        }
+    }
+
+    // The Fluent file with all of the language's words in it.
+    fn resource(self) -> &'static str {
+        match self {
+            Language::English => include_str!("en-US.ftl"),
+            Language::Spanish => include_str!("es.ftl"),
+        }
+    }
+}
+
+static LANGUAGE: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the language that everything is written in from here on out.
+pub fn set_language(language: Language) {
+    let index = Language::ALL.iter().position(|l| *l == language).unwrap();
+    LANGUAGE.store(index, Ordering::SeqCst);
+}
+
+fn language() -> Language {
+    Language::ALL[LANGUAGE.load(Ordering::SeqCst)]
+}
+
+/// The message with the given id, in whichever language was last given to set_language.
+pub fn text(id: &str) -> String {
+    text_with(id, &[])
+}
+
+/// The same as text, for messages with blanks in them to be filled in.
+pub fn text_with(id: &str, args: &[(&str, &str)]) -> String {
+    format(language(), id, args)
+}
+
+/// Writes out the given date using the given message, which is one of the date-* messages.
+/// chrono only knows the English names of the days and months, so they come out of the
+/// language's file along with everything else.
+pub fn date(id: &str, date: NaiveDate) -> String {
+    date_in(language(), id, date)
+}
+
+// A message that is missing from the language falls back to English, and one that's missing
+// from English too (a typo, most likely) is shown as its id, which is easy enough to spot.
+fn format(language: Language, id: &str, args: &[(&str, &str)]) -> String {
+    bundles::format(language, id, args)
+        .or_else(|| bundles::format(Language::English, id, args))
+        .unwrap_or_else(|| id.to_string())
+}
+
+fn date_in(language: Language, id: &str, date: NaiveDate) -> String {
+    let weekday = format(
+        language,
+        &format!("weekday-{}", date.weekday().number_from_monday()),
+        &[],
+    );
+    let month = format(language, &format!("month-{}", date.month()), &[]);
+    let (day, year) = (date.day().to_string(), date.year().to_string());
+    format(
+        language,
+        id,
+        &[
+            ("weekday", &weekday),
+            ("month", &month),
+            ("day", &day),
+            ("year", &year),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The ids of every message in the given Fluent file.
+    fn ids(resource: &str) -> Vec<&str> {
+        let mut ids: Vec<&str> = resource
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split(" =").next())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn every_language_says_everything() {
+        let english = ids(Language::English.resource());
+        assert!(english.contains(&"no-games-on"));
+        for language in Language::ALL.iter() {
+            assert_eq!(ids(language.resource()), english, "{:?}", language);
+        }
+    }
+
+    #[test]
+    fn locales() {
+        assert_eq!(Language::parse("es"), Some(Language::Spanish));
+        assert_eq!(Language::parse("es_MX.UTF-8"), Some(Language::Spanish));
+        assert_eq!(Language::parse("EN-gb"), Some(Language::English));
+        assert_eq!(Language::parse("fr_FR"), None);
+        assert_eq!(Language::parse("C"), None);
+        assert_eq!(Language::parse(""), None);
+    }
+
+    #[test]
+    fn dates() {
+        let date = NaiveDate::from_ymd_opt(2019, 7, 4).unwrap();
+        assert_eq!(
+            date_in(Language::English, "date-full", date),
+            "Thursday, July 4, 2019"
+        );
+        assert_eq!(
+            date_in(Language::Spanish, "date-full", date),
+            "jueves, 4 de julio de 2019"
+        );
+        assert_eq!(
+            format(Language::Spanish, "first-pitch", &[("time", "7:05 PM")]),
+            "Primer lanzamiento a las 7:05 PM"
+        );
+        assert_eq!(format(Language::Spanish, "nope", &[]), "nope");
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::warn;
+use tracing_subscriber::EnvFilter;
+
+/// The environment variable that decides how much gets logged, in tracing's filter syntax.
+/// E.G. MLB_LOG=debug for everything, or MLB_LOG=mlb::lineup=debug for just the photos.
+pub const FILTER: &str = "MLB_LOG";
+
+// Left to its own devices, only what is actually worth a person's attention makes it out.
+// Everything else is there for when a flaky network needs digging into.
+const DEFAULT_FILTER: &str = "warn";
+
+/// Starts logging to stderr, or to the given file instead if there is one. The file is
+/// appended to rather than truncated, so that a few runs' worth of trouble can pile up in it.
+///
+/// This has to happen before anything else gets the chance to log, since anything logged
+/// before there is anywhere for it to go simply goes nowhere.
+pub fn init(file: Option<&Path>) {
+    let filter = EnvFilter::try_from_env(FILTER).unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    let opened = file.map(|path| {
+        (
+            path,
+            OpenOptions::new().create(true).append(true).open(path),
+        )
+    });
+    match opened {
+        Some((_, Ok(file))) => {
+            let file = Arc::new(file);
+            builder
+                .with_ansi(false)
+                .with_writer(move || LogFile(file.clone()))
+                .init();
+        }
+        // Not being able to log to the file isn't a reason to not log at all.
+        Some((path, Err(err))) => {
+            builder.with_writer(io::stderr).init();
+            warn!(path = %path.display(), error = %err, "Couldn't open the log file");
+        }
+        None => builder.with_writer(io::stderr).init(),
+    }
+}
+
+// Every event gets a writer of its own, and they all share the one file. Writes to a File
+// through a shared reference are fine as far as the OS is concerned, and each event is
+// written out in one go.
+struct LogFile(Arc<File>);
+
+impl Write for LogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&*self.0).write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self.0).flush()
+    }
+}
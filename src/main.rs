@@ -2,13 +2,25 @@
 extern crate lazy_static;
 
 use image::{ImageFormat, RgbaImage};
-use piston_window::{EventLoop, Glyphs, ReleaseEvent, Transformed};
+use piston_window::EventLoop;
+use std::path::Path;
 use std::process::exit;
+use tracing::error;
 
-mod api;
-mod lineup;
-
-use lineup::*;
+mod app;
+mod backdrop;
+mod banner;
+mod config;
+mod font;
+mod input;
+mod layout;
+mod locale;
+mod logging;
+mod metrics;
+mod render;
+mod screen;
+mod session;
+mod theme;
 
 // I gotta say, I was ecstatic the first time I ever found out that include_bytes/str was a thing.
 // I have long hated the bundling of loose assets and little file extras into what is suppose
@@ -16,12 +28,6 @@ use lineup::*;
 static BACKGROUND_BYTES: &[u8] = include_bytes!("../assets/background.jpg");
 static LEFT_ARROW_BYTES: &[u8] = include_bytes!("../assets/left_arrow.png");
 static RIGHT_ARROW_BYTES: &[u8] = include_bytes!("../assets/right_arrow.png");
-static FONT: &[u8] = include_bytes!("../assets/OpenSans-Bold.ttf");
-
-static BLACK: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
-static WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
-// The padding between onscreen game snippets.
-static PADDING: f64 = 27.5;
 
 lazy_static! {
     static ref BACKGROUND: RgbaImage =
@@ -48,41 +54,60 @@ lazy_static! {
 async fn main() {
     // Well, I know the name of the org I'm interviewing with. So I've got that going for me.
     let title = "Disney Streaming Services";
-    // I chose piston simply because my quick experimentation with other libraries, such as glium,
-    // asked me to write GLSL code and feed that into macros for consumption by OpenGL. I don't
-    // need vectors and shading and all that jazz, I just needed a 2D window.
-    //
-    // I also admit that my use of this library can be rather repetitive. This is not my usual
-    // style of programming as of course you want typical use cases to resolve down to shared
-    // functionality. However, this requires that you fundamentally understand what your underlying
-    // dependency is asking of you as well of its general philosophies. I pulled this library off
-    // the shelf so...sorry, my use of it is rather blunt.
-    let mut window: piston_window::PistonWindow =
-        piston_window::WindowSettings::new(title, [1920, 1080])
-            .exit_on_esc(true)
-            .build()
-            .unwrap_or_else(|e| panic!("Failed to build PistonWindow: {}", e));
-    // We're going to be using this context repeatedly in each loop.
-    // Calling something a ThingContext that takes in ThingFactory is so library specific and
-    // mysterious that I admit that I do not understand the original intent here. I have
-    // heard this called "homeopathic naming" - the notion that the more you dilute the naming
-    // the more meaningful it becomes (an engineer has a problem, she decides to use Java,
-    // she now has an AbstractObserverFactoryImpl).
-    //
-    // My use of this library was purely a panic to find any reasonable 2D graphics library
-    // that could see me through this ordeal. So I admit that this is a case of satisfying the API
-    // without any real deep understanding of what they are asking of me here.
-    let mut ctx = piston_window::TextureContext {
-        factory: window.factory.clone(),
-        encoder: window.factory.create_command_buffer().into(),
+    let mut config = config::Config::from_args();
+    if config.export.is_some() || config.export_ics.is_some() {
+        exit(export(&config).await);
+    }
+    let mut session = match (config.restore, session::Session::user()) {
+        (true, Some(path)) => session::Session::load(&path),
+        _ => session::Session::default(),
     };
-    let fullscreen = graphics::image::Image::new().rect([0.0, 0.0, 1920.0, 1080.0]);
-    let background: piston_window::G2dTexture = piston_window::Texture::from_image(
-        &mut ctx,
-        &(*BACKGROUND),
-        &piston_window::TextureSettings::new(),
-    )
-    .unwrap();
+    // A date on the command line beats wherever the last run left off.
+    match config.date {
+        Some(_) => session.cursor = 0,
+        None => config.date = session.date(),
+    }
+    app::install_panic_hook();
+    locale::set_language(config.language);
+    // Everything from here on out lives in the screens. The app just keeps track of which one
+    // is on top.
+    let status = app::App::new(title, config, session).run();
+    // Whatever is still downloading or polling in the background is of no use to anybody now,
+    // and is only going to hold up the runtime on its way out.
+    mlb::api::shutdown();
+    match status {
+        0 => (),
+        status => exit(status),
+    }
+}
+
+// I chose piston simply because my quick experimentation with other libraries, such as glium,
+// asked me to write GLSL code and feed that into macros for consumption by OpenGL. I don't
+// need vectors and shading and all that jazz, I just needed a 2D window.
+//
+// I also admit that my use of this library can be rather repetitive. This is not my usual
+// style of programming as of course you want typical use cases to resolve down to shared
+// functionality. However, this requires that you fundamentally understand what your underlying
+// dependency is asking of you as well of its general philosophies. I pulled this library off
+// the shelf so...sorry, my use of it is rather blunt.
+//
+// Piston has no way of flipping an existing window in and out of fullscreen, so toggling it
+// means building a whole new window with this and throwing the old one away.
+fn build_window(
+    title: &str,
+    config: &config::Config,
+    size: [u32; 2],
+    fullscreen: bool,
+) -> piston_window::PistonWindow {
+    let mut window: piston_window::PistonWindow = piston_window::WindowSettings::new(title, size)
+        // Escape is how you back out of a game's details, so it only quits the
+        // application when there is nothing left to back out of.
+        .exit_on_esc(false)
+        .fullscreen(fullscreen)
+        .decorated(!config.borderless)
+        .vsync(config.vsync)
+        .build()
+        .unwrap_or_else(|e| panic!("Failed to build PistonWindow: {}", e));
     // This is me TRYING to make this a bit more efficient. The downside of using this easy 2D
     // library is that I have apparently inherited a rather inefficient event loop
     // (see https://github.com/PistonDevelopers/piston/issues/1109). Frankly, I should NOT be
@@ -90,232 +115,111 @@ async fn main() {
     // a completely blank screen will force me into that consumption, and that is unfortunate.
     //
     // However, limiting the frame rate cuts the CPU usage (on my box) down to under 1% at least.
-    // This framerate seemed like a fair emulation of how quickly these sorts of menus tend
-    // to render on actual TVs.
-    window.set_max_fps(10);
-    // It's kind of a useless thing to .await immediately upon application startup as it is
-    // blocking the window from rendering. I stretched for having the photos load
-    // asynchronously, however getting that initial API call to load in the background as well
-    // would have been a bit much for such a short time frame. Backlog candidate.
-    let mut schedule: Schedule = match api::Schedule::try_from(api::DEFAULT).await {
-        Ok(schedule) => schedule.into(),
-        // I handle the error of not being able to pull the initial API call and render
-        // as the sole text onto the screen. A restart is required to try again. I admit
-        // that after this, any Result given back by the graphics library I just unwrap. This
-        // is because after this point everything is already in memory so we're not suffering
-        // from IO failures, however it is entirely possible that we were given back, say,
-        // images that don't parse out correctly. I simply did not have the time to scope
-        // out such rich error handling and how that would tie into the main window rendering.
-        //
-        // Other parts of this application that are more in my problem domain I am more careful with.
+    // The idle rate (10 unless told otherwise) seemed like a fair emulation of how quickly these
+    // sorts of menus tend to render on actual TVs. The App speeds it back up whenever something
+    // is on the move, since a photo fading in at 10 FPS looks more like a slideshow.
+    window.set_max_fps(config.fps(false));
+    // Even at 10 FPS, redrawing a screen that hasn't changed one bit is a waste. So we only
+    // draw (and swap buffers ourselves) when something has actually happened: the user did
+    // something, the window changed, or something new came in over the network.
+    window.set_swap_buffers(false);
+    window
+}
+
+// Textures and glyphs belong to the window that they were uploaded to, so they have to be
+// remade whenever the window is. The images that they are made from (the photos included)
+// live on the CPU side and survive just fine.
+struct Assets {
+    background: piston_window::G2dTexture,
+    left_arrow: piston_window::G2dTexture,
+    right_arrow: piston_window::G2dTexture,
+    // Everything else (the photos) comes and goes, so those go through the cache.
+    textures: render::TextureCache,
+    glyphs: render::Fonts,
+}
+
+impl Assets {
+    fn new(window: &mut piston_window::PistonWindow, config: &config::Config) -> Assets {
+        // We're going to be using this context repeatedly in each loop.
+        // Calling something a ThingContext that takes in ThingFactory is so library specific and
+        // mysterious that I admit that I do not understand the original intent here. I have
+        // heard this called "homeopathic naming" - the notion that the more you dilute the naming
+        // the more meaningful it becomes (an engineer has a problem, she decides to use Java,
+        // she now has an AbstractObserverFactoryImpl).
         //
-        // I am aware that the text needs to be wrapped around as the error messages fall
-        // off the screen. Wrapping text into columns is not difficult, however you have
-        // to handle the newlines manually within this text renderer which I did not have
-        // the time to do. Some of the snippet subheaders suffer from this same problem.
-        Err(err) => display_err(err, window, background),
-    };
-    // Glyphs are the font cache that we will be using for this application.
-    //
-    // It's a shame, I found a cool open source font that looked very much like that blocky
-    // MLB sans serif font, however it has a very anemic selection of symbols and just looked
-    // back when dealing with non-alpha text.
-    let mut glyphs = Glyphs::from_bytes(
-        FONT,
-        piston_window::TextureContext {
+        // My use of this library was purely a panic to find any reasonable 2D graphics library
+        // that could see me through this ordeal. So I admit that this is a case of satisfying the
+        // API without any real deep understanding of what they are asking of me here.
+        let mut ctx = piston_window::TextureContext {
             factory: window.factory.clone(),
             encoder: window.factory.create_command_buffer().into(),
-        },
-        piston_window::TextureSettings::new(),
-    )
-    .unwrap();
-    while let Some(e) = window.next() {
-        // Move the cursor on key-up events. I would kinda like to implement fast scrolling
-        // via long key holds. But alas, into the backlog it goes.
-        match e.release_args() {
-            Some(piston_window::Button::Keyboard(piston_window::Key::Left)) => {
-                schedule.left();
-            }
-            Some(piston_window::Button::Keyboard(piston_window::Key::Right)) => {
-                schedule.right();
-            }
-            _ => (),
         };
-        window.draw_2d(&e, |c, g, device| {
-            // This is the main rendering loop as per piston convention.
-            //
-            // I admit that these X/Y transformations are more of a result
-            // of me experimenting around to get an orientation on the page
-            // and seeing what works aesthetically. I did do some manual computations
-            // to get an idea of where these objects should lay on the screen.
-            // However, by and large, I am admitting that this applications is not
-            // "responsive" in the sense that it does not respond to different sizes.
-            // In Agile terms, I reckon that I would put that work onto the next sprint.
-            piston_window::clear(BLACK, g);
-            fullscreen.draw(&background, &graphics::DrawState::default(), c.transform, g);
-            // The first item is padded from the left most wall of the screen.
-            let mut left_edge = PADDING;
-            // And the right edge is computed as the left_edge plus
-            // whatever the width of the image is.
-            let mut right_edge: f64;
-            for item in schedule.page() {
-                match item {
-                    Snippet::Large(image, heading, subheading) => {
-                        right_edge = left_edge + image.width() as f64;
-                        let rect = graphics::image::Image::new().rect([
-                            0.0,
-                            0.0,
-                            image.width() as f64,
-                            image.height() as f64,
-                        ]);
-                        let txt = piston_window::Texture::from_image(
-                            &mut ctx,
-                            image,
-                            &piston_window::TextureSettings::new(),
-                        )
-                        .unwrap();
-                        rect.draw(
-                            &txt,
-                            &graphics::DrawState::default(),
-                            c.transform.trans(left_edge, 540.0),
-                            g,
-                        );
-                        // Render our header and subheader
-                        piston_window::text(
-                            WHITE,
-                            16,
-                            heading,
-                            &mut glyphs,
-                            c.transform.trans(left_edge + 40.0, 500.0),
-                            g,
-                        )
-                        .unwrap();
-                        piston_window::text(
-                            WHITE,
-                            16,
-                            subheading,
-                            &mut glyphs,
-                            c.transform.trans(left_edge, 855.0),
-                            g,
-                        )
-                        .unwrap();
-                        // And I guess we have to...flush the font encoder with the given device?
-                        // This object graph doesn't make much sense to me, but that just
-                        // might be because I don't know anything about graphics.
-                        glyphs.factory.encoder.flush(device);
-                    }
-                    Snippet::Small(image) => {
-                        right_edge = left_edge + image.width() as f64;
-                        let rect = graphics::image::Image::new().rect([
-                            0.0,
-                            0.0,
-                            image.width() as f64,
-                            image.height() as f64,
-                        ]);
-                        let txt = piston_window::Texture::from_image(
-                            &mut ctx,
-                            image,
-                            &piston_window::TextureSettings::new(),
-                        )
-                        .unwrap();
-                        rect.draw(
-                            &txt,
-                            &graphics::DrawState::default(),
-                            c.transform.trans(left_edge, 578.5),
-                            g,
-                        );
-                    }
-                }
-                // This is computing the small padding in-between snippets.
-                left_edge = right_edge + PADDING;
-            }
-            // has_less and has_more describe whether or not there is a page to left or the right,
-            // which drives the decision on whether or not to render the scroll arrow indicators.
-            //
-            // When you don't have enough time for large technical implementations goals
-            // (such as richer error handling or window responsiveness) then you should try to
-            // fill in the sprint/release with small attention to detail that often delight
-            // stakeholders. These small details don't take much time, they're going to be there
-            // eventually anyways, and their implementation buys you a bit more time (politically)
-            // to implement the harder stuff while keeping everyone happy.
-            if schedule.has_less() {
-                let txt = piston_window::Texture::from_image(
-                    &mut ctx,
-                    &*LEFT_ARROW,
-                    &piston_window::TextureSettings::new(),
-                )
-                .unwrap();
-                let rect = graphics::image::Image::new().rect([
-                    0.0,
-                    0.0,
-                    LEFT_ARROW.width() as f64,
-                    LEFT_ARROW.height() as f64,
-                ]);
-                rect.draw(&txt, &graphics::DrawState::default(), c.transform, g);
-            }
-            if schedule.has_more() {
-                let txt = piston_window::Texture::from_image(
-                    &mut ctx,
-                    &*RIGHT_ARROW,
-                    &piston_window::TextureSettings::new(),
-                )
-                .unwrap();
-                let rect = graphics::image::Image::new().rect([
-                    0.0,
-                    0.0,
-                    RIGHT_ARROW.width() as f64,
-                    RIGHT_ARROW.height() as f64,
-                ]);
-                rect.draw(
-                    &txt,
-                    &graphics::DrawState::default(),
-                    c.transform.trans(1920.0 - RIGHT_ARROW.width() as f64, 0.0),
-                    g,
-                );
-            }
-        });
+        let background: piston_window::G2dTexture = piston_window::Texture::from_image(
+            &mut ctx,
+            config.background.as_ref().unwrap_or(&*BACKGROUND),
+            &piston_window::TextureSettings::new(),
+        )
+        .unwrap();
+        let left_arrow: piston_window::G2dTexture = piston_window::Texture::from_image(
+            &mut ctx,
+            &LEFT_ARROW,
+            &piston_window::TextureSettings::new(),
+        )
+        .unwrap();
+        let right_arrow: piston_window::G2dTexture = piston_window::Texture::from_image(
+            &mut ctx,
+            &RIGHT_ARROW,
+            &piston_window::TextureSettings::new(),
+        )
+        .unwrap();
+        // Glyphs are the font cache that we will be using for this application.
+        //
+        // It's a shame, I found a cool open source font that looked very much like that blocky
+        // MLB sans serif font, however it has a very anemic selection of symbols and just looked
+        // back when dealing with non-alpha text. Hence the fallback, for those who have one.
+        let glyphs = render::Fonts::new(&config.font, config.fallback_font.as_ref(), window);
+        Assets {
+            background,
+            left_arrow,
+            right_arrow,
+            textures: render::TextureCache::new(ctx),
+            glyphs,
+        }
     }
 }
 
-// The error case alternative. It takes ownership of the window and displays the APIError until exit.
-//
-// This was sort of a noisy, last minute, function to begin with but cargo fmt really formatted
-// in a way that I don't quite to boot.
-//
-// Which brings up a good point - style. I grab whatever is the formatter de'jure and just use it.
-// Don't like how go fmt mangled your beautiful code? Deal with it, arguments and unnecessary
-// differences in change requests make this a hill not worth dying on.
-fn display_err(
-    err: api::APIError,
-    mut window: piston_window::PistonWindow,
-    background: piston_window::G2dTexture,
-) -> ! {
-    let err_text = format!("{}", err);
-    let mut glyphs = Glyphs::from_bytes(
-        FONT,
-        piston_window::TextureContext {
-            factory: window.factory.clone(),
-            encoder: window.factory.create_command_buffer().into(),
-        },
-        piston_window::TextureSettings::new(),
-    )
-    .unwrap();
-    let fullscreen = graphics::image::Image::new().rect([0.0, 0.0, 1920.0, 1080.0]);
-    while let Some(e) = window.next() {
-        window.draw_2d(&e, |c, g, device| {
-            piston_window::clear(BLACK, g);
-            fullscreen.draw(&background, &graphics::DrawState::default(), c.transform, g);
-            piston_window::text(
-                WHITE,
-                16,
-                err_text.as_str(),
-                &mut glyphs,
-                c.transform.trans(0.0, 500.0),
-                g,
-            )
-            .unwrap();
-            glyphs.factory.encoder.flush(device);
-        });
+// Writes out the schedule that the window would have opened up to, in whichever of the
+// export formats were asked for, and that's all. Returns the exit status.
+async fn export(config: &config::Config) -> i32 {
+    let schedule = match screen::first_schedule(config).await {
+        (_, Ok(schedule)) => schedule,
+        (_, Err(err)) => {
+            error!(error = %err, "Failed to retrieve the schedule");
+            return 1;
+        }
+    };
+    let mut status = 0;
+    let mut written = |path: &Path, result: std::io::Result<()>, games: usize| match result {
+        Ok(()) => println!("Exported {} games to {}", games, path.display()),
+        Err(err) => {
+            error!(path = %path.display(), error = %err, "Failed to write the schedule");
+            status = 1;
+        }
+    };
+    if let Some(path) = &config.export {
+        let entries = mlb::export::entries(&schedule);
+        written(path, mlb::export::write(&entries, path), entries.len());
     }
-    exit(1);
+    if let Some(path) = &config.export_ics {
+        let calendar = mlb::export::ics::calendar(&schedule, chrono::Utc::now());
+        let games = calendar.matches("BEGIN:VEVENT").count();
+        written(path, std::fs::write(path, calendar), games);
+    }
+    status
+}
+
+// The size of the window's drawable area, in the same units that everything is drawn in.
+fn window_size(window: &piston_window::PistonWindow) -> [f64; 2] {
+    let size = piston_window::Window::size(window);
+    [size.width, size.height]
 }
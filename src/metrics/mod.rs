@@ -0,0 +1,149 @@
+use mlb::lineup::download::Stats;
+use std::time::{Duration, Instant};
+
+/// Numbers on how the app is running, for the debug overlay (F3). Everything in here is cheap
+/// enough to keep track of whether or not the overlay is up, so it always is.
+pub struct Metrics {
+    /// Whether or not the overlay is up.
+    pub shown: bool,
+    /// How long the last frame took to draw, start to swap.
+    pub frame: Duration,
+    /// How many frames have been drawn since launch.
+    pub draws: u64,
+    /// When the last frame was drawn.
+    pub drawn: Option<Instant>,
+    /// When the schedule last came in from the API, be it a fetch or a refresh.
+    pub fetched: Option<Instant>,
+}
+
+impl Metrics {
+    // Nothing gets drawn unless something has changed, which the numbers on the overlay
+    // don't count as. So while it's up, it's redrawn every so often to keep them current.
+    const STALE: Duration = Duration::from_millis(500);
+
+    pub fn new() -> Metrics {
+        Metrics {
+            shown: false,
+            frame: Duration::from_secs(0),
+            draws: 0,
+            drawn: None,
+            fetched: None,
+        }
+    }
+
+    /// Takes note of a frame that took the given time to draw, finishing at the given instant.
+    pub fn frame(&mut self, took: Duration, now: Instant) {
+        self.frame = took;
+        self.draws += 1;
+        self.drawn = Some(now);
+    }
+
+    /// Whether or not the overlay is up and has gone long enough without being redrawn.
+    pub fn stale(&self, now: Instant) -> bool {
+        self.shown
+            && self
+                .drawn
+                .is_none_or(|drawn| now.saturating_duration_since(drawn) >= Self::STALE)
+    }
+
+    /// What the overlay says, one line at a time. Photos are None until there's a lineup for
+    /// them to be in, and textures are how many there are and how often one was already there.
+    pub fn lines(
+        &self,
+        photos: Option<Stats>,
+        textures: (usize, Option<f64>),
+        now: Instant,
+    ) -> Vec<String> {
+        let mut lines = vec![format!(
+            "Frame: {:.1} ms ({} drawn)",
+            self.frame.as_secs_f64() * 1000.0,
+            self.draws
+        )];
+        if let Some(photos) = photos {
+            lines.push(format!(
+                "Photos: {} downloading, {} queued, {} downloaded",
+                photos.downloading, photos.queued, photos.downloaded
+            ));
+            lines.push(format!(
+                "Decoded: {} photos, {:.1} MB",
+                photos.decoded,
+                photos.bytes as f64 / (1024.0 * 1024.0)
+            ));
+            lines.push(format!("Photo cache: {} hits", percent(photos.hit_rate())));
+        }
+        lines.push(format!(
+            "Textures: {}, {} hits",
+            textures.0,
+            percent(textures.1)
+        ));
+        lines.push(match self.fetched {
+            Some(fetched) => format!(
+                "Schedule: fetched {}s ago",
+                now.saturating_duration_since(fetched).as_secs()
+            ),
+            None => "Schedule: not fetched yet".to_string(),
+        });
+        lines
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics::new()
+    }
+}
+
+// E.G. "97%", or "n/a" before there's anything to go on.
+fn percent(rate: Option<f64>) -> String {
+    match rate {
+        Some(rate) => format!("{:.0}%", rate * 100.0),
+        None => "n/a".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let start = Instant::now();
+        let mut metrics = Metrics::new();
+        metrics.frame(Duration::from_micros(4300), start);
+        metrics.fetched = Some(start);
+        let photos = Stats {
+            queued: 10,
+            downloading: 2,
+            downloaded: 35,
+            hits: 3,
+            misses: 1,
+            decoded: 40,
+            bytes: 3 * 1024 * 1024 / 2,
+        };
+        let lines = metrics.lines(Some(photos), (80, None), start + Duration::from_secs(42));
+        assert_eq!(
+            lines,
+            vec![
+                "Frame: 4.3 ms (1 drawn)",
+                "Photos: 2 downloading, 10 queued, 35 downloaded",
+                "Decoded: 40 photos, 1.5 MB",
+                "Photo cache: 75% hits",
+                "Textures: 80, n/a hits",
+                "Schedule: fetched 42s ago",
+            ]
+        );
+        assert_eq!(metrics.lines(None, (0, Some(1.0)), start).len(), 3);
+    }
+
+    #[test]
+    fn stale_only_while_shown() {
+        let start = Instant::now();
+        let mut metrics = Metrics::new();
+        assert!(!metrics.stale(start));
+        metrics.shown = true;
+        assert!(metrics.stale(start));
+        metrics.frame(Duration::from_millis(5), start);
+        assert!(!metrics.stale(start + Duration::from_millis(100)));
+        assert!(metrics.stale(start + Duration::from_secs(1)));
+    }
+}
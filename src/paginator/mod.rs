@@ -0,0 +1,357 @@
+use std::ops::{Deref, DerefMut, Range};
+
+/// A list of things with a cursor sitting on one of them, chopped up into pages.
+///
+/// This is the bookkeeping behind any screen that scrolls through a list a page at a time
+/// (E.G. the carousel of games), pulled out on its own so that it can be gotten right once
+/// rather than once per screen. The cursor only ever moves one item at a time, and the page
+/// that is on screen is always whichever one the cursor is on.
+///
+/// It derefs to a slice of its items, so everything that you'd want to do with the items
+/// themselves (indexing, iterating, len, and so on) works as usual. The slice can't grow or
+/// shrink out from under the cursor that way, so for that there is replace. (The cursor's own
+/// jumps are to_first and to_last, so as not to shadow the slice's first and last.)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Paginator<T> {
+    items: Vec<T>,
+    cursor: usize,
+    page_size: usize,
+    wrap: bool,
+}
+
+impl<T> Paginator<T> {
+    /// Starts off with the cursor on the first item. A page size of zero makes no sense,
+    /// so it is taken to mean one.
+    pub fn new(items: Vec<T>, page_size: usize) -> Paginator<T> {
+        Paginator {
+            items,
+            cursor: 0,
+            page_size: page_size.max(1),
+            wrap: false,
+        }
+    }
+
+    /// Whether or not moving off of either end brings the cursor around to the other end.
+    pub fn wrapping(mut self, wrap: bool) -> Paginator<T> {
+        self.wrap = wrap;
+        self
+    }
+
+    /// The index of the item that the cursor is on. Zero for an empty list.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Chops the items up into pages of a different size. The cursor stays on the same item,
+    /// which puts it on whichever of the new pages that item landed on. Zero is taken to mean
+    /// one, as with new.
+    pub fn set_page_size(&mut self, page_size: usize) {
+        self.page_size = page_size.max(1);
+    }
+
+    /// The item that the cursor is on, if there are any items at all.
+    pub fn focused(&self) -> Option<&T> {
+        self.items.get(self.cursor)
+    }
+
+    /// The same as focused, but mutable.
+    pub fn focused_mut(&mut self) -> Option<&mut T> {
+        self.items.get_mut(self.cursor)
+    }
+
+    /// Moves the cursor one to the left, returning whether or not it actually went anywhere.
+    pub fn left(&mut self) -> bool {
+        match self.cursor {
+            _ if self.items.is_empty() => false,
+            0 if self.wrap && self.items.len() > 1 => {
+                self.cursor = self.items.len() - 1;
+                true
+            }
+            0 => false,
+            _ => {
+                self.cursor -= 1;
+                true
+            }
+        }
+    }
+
+    /// Moves the cursor one to the right, returning whether or not it actually went anywhere.
+    pub fn right(&mut self) -> bool {
+        if self.cursor + 1 < self.items.len() {
+            self.cursor += 1;
+            true
+        } else if self.wrap && self.cursor > 0 {
+            self.cursor = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves the cursor a whole page to the right, or to the last item if there isn't a whole
+    /// page left to go. Returns whether or not it actually went anywhere.
+    pub fn next_page(&mut self) -> bool {
+        let before = self.cursor;
+        self.focus(self.cursor + self.page_size);
+        before != self.cursor
+    }
+
+    /// Moves the cursor a whole page to the left, or to the first item if there isn't a whole
+    /// page left to go. Returns whether or not it actually went anywhere.
+    pub fn previous_page(&mut self) -> bool {
+        let before = self.cursor;
+        self.cursor = self.cursor.saturating_sub(self.page_size);
+        before != self.cursor
+    }
+
+    /// Moves the cursor to the very first item, returning whether or not it actually went anywhere.
+    pub fn to_first(&mut self) -> bool {
+        let moved = self.cursor != 0;
+        self.cursor = 0;
+        moved
+    }
+
+    /// Moves the cursor to the very last item, returning whether or not it actually went anywhere.
+    pub fn to_last(&mut self) -> bool {
+        let before = self.cursor;
+        self.focus(self.items.len().saturating_sub(1));
+        before != self.cursor
+    }
+
+    /// Puts the cursor on the given index, or as close to it as the list allows.
+    pub fn focus(&mut self, index: usize) {
+        self.cursor = index.min(self.items.len().saturating_sub(1));
+    }
+
+    /// Swaps in an entirely new list of items, handing back the old ones. The cursor stays at
+    /// the same index if it can, and otherwise ends up on the last item.
+    pub fn replace(&mut self, items: Vec<T>) -> Vec<T> {
+        let old = std::mem::replace(&mut self.items, items);
+        self.focus(self.cursor);
+        old
+    }
+
+    /// Which page (counting from zero) the cursor is on.
+    pub fn page(&self) -> usize {
+        self.cursor / self.page_size
+    }
+
+    /// How many pages there are, counting a short last page as a page.
+    pub fn pages(&self) -> usize {
+        self.items.len().div_ceil(self.page_size)
+    }
+
+    /// The indices of the items on the cursor's page. The last page may well be short,
+    /// and an empty list has an empty page.
+    pub fn bounds(&self) -> Range<usize> {
+        let left = (self.page() * self.page_size).min(self.items.len());
+        left..(left + self.page_size).min(self.items.len())
+    }
+
+    /// Where the cursor is within its page. E.G. the cursor may be on item 7, but with five
+    /// items to a page that is the third item on the second page, so this is 2.
+    pub fn page_focus(&self) -> usize {
+        self.cursor % self.page_size
+    }
+
+    /// The items on the cursor's page.
+    pub fn current_page(&self) -> &[T] {
+        &self.items[self.bounds()]
+    }
+
+    /// The same as current_page, but mutable.
+    pub fn current_page_mut(&mut self) -> &mut [T] {
+        let bounds = self.bounds();
+        &mut self.items[bounds]
+    }
+
+    /// Whether or not there is a page to the left of this one. When wrapping, the last page
+    /// is to the left of the first, so this is true whenever there is more than one page.
+    pub fn has_less(&self) -> bool {
+        self.page() > 0 || (self.wrap && self.pages() > 1)
+    }
+
+    /// Whether or not there is a page to the right of this one. When wrapping, the first page
+    /// is to the right of the last, so this is true whenever there is more than one page.
+    pub fn has_more(&self) -> bool {
+        self.bounds().end < self.items.len() || (self.wrap && self.pages() > 1)
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> Default for Paginator<T> {
+    fn default() -> Self {
+        Paginator::new(vec![], 1)
+    }
+}
+
+impl<T> Deref for Paginator<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> DerefMut for Paginator<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paginator(len: usize) -> Paginator<usize> {
+        Paginator::new((0..len).collect(), 5)
+    }
+
+    #[test]
+    fn empty() {
+        let mut empty = paginator(0);
+        assert!(!empty.left());
+        assert!(!empty.right());
+        assert_eq!(empty.focused(), None);
+        assert_eq!(empty.bounds(), 0..0);
+        assert_eq!(empty.pages(), 0);
+        assert!(!empty.has_less());
+        assert!(!empty.has_more());
+        let mut empty = empty.wrapping(true);
+        assert!(!empty.left());
+        assert!(!empty.right());
+    }
+
+    #[test]
+    fn walks_every_item() {
+        let mut items = paginator(7);
+        let mut seen = vec![*items.focused().unwrap()];
+        while items.right() {
+            seen.push(*items.focused().unwrap());
+        }
+        // The last item is just as reachable as the rest of them.
+        assert_eq!(seen, (0..7).collect::<Vec<_>>());
+        assert_eq!(items.cursor(), 6);
+        while items.left() {}
+        assert_eq!(items.cursor(), 0);
+    }
+
+    #[test]
+    fn short_last_page() {
+        let mut items = paginator(7);
+        assert_eq!(items.pages(), 2);
+        assert_eq!(items.current_page(), &[0, 1, 2, 3, 4]);
+        assert!(!items.has_less());
+        // Everywhere on the first page, not just at its start, there is more to the right.
+        items.focus(4);
+        assert!(items.has_more());
+        items.focus(5);
+        assert_eq!(items.current_page(), &[5, 6]);
+        assert_eq!(items.page_focus(), 0);
+        assert!(items.has_less());
+        assert!(!items.has_more());
+    }
+
+    #[test]
+    fn exactly_full_pages() {
+        let mut items = paginator(10);
+        items.focus(9);
+        assert_eq!(items.pages(), 2);
+        assert_eq!(items.current_page(), &[5, 6, 7, 8, 9]);
+        assert_eq!(items.page_focus(), 4);
+        assert!(!items.has_more());
+        items.focus(100);
+        assert_eq!(items.cursor(), 9);
+    }
+
+    #[test]
+    fn first_and_last() {
+        let mut items = paginator(12);
+        assert!(!items.to_first());
+        assert!(items.to_last());
+        assert_eq!(items.focused(), Some(&11));
+        // The last page only has the two on it, and there is nothing beyond it.
+        assert_eq!(items.current_page(), &[10, 11]);
+        assert!(items.has_less());
+        assert!(!items.has_more());
+        assert!(!items.to_last());
+        assert!(items.to_first());
+        assert!(!items.has_less());
+        assert!(items.has_more());
+        let mut empty = paginator(0);
+        assert!(!empty.to_first());
+        assert!(!empty.to_last());
+    }
+
+    #[test]
+    fn a_page_at_a_time() {
+        let mut items = paginator(12);
+        items.focus(2);
+        assert!(items.next_page());
+        assert_eq!(items.cursor(), 7);
+        assert_eq!(items.page_focus(), 2);
+        // There aren't five more to go, so this stops short on the last one.
+        assert!(items.next_page());
+        assert_eq!(items.cursor(), 11);
+        assert!(!items.next_page());
+        assert!(items.previous_page());
+        assert_eq!(items.cursor(), 6);
+        assert!(items.previous_page());
+        assert!(items.previous_page());
+        assert_eq!(items.cursor(), 0);
+        assert!(!items.previous_page());
+        assert!(!paginator(0).next_page());
+    }
+
+    #[test]
+    fn wraps_around() {
+        let mut items = paginator(7).wrapping(true);
+        assert!(items.left());
+        assert_eq!(items.cursor(), 6);
+        assert!(items.right());
+        assert_eq!(items.cursor(), 0);
+        assert!(items.has_less());
+        assert!(items.has_more());
+        // There is nowhere to wrap around to with only the one item.
+        let mut single = paginator(1).wrapping(true);
+        assert!(!single.left());
+        assert!(!single.right());
+        assert!(!single.has_less());
+    }
+
+    #[test]
+    fn replace_keeps_the_cursor_in_bounds() {
+        let mut items = paginator(7);
+        items.focus(6);
+        assert_eq!(items.replace(vec![10, 11, 12]), (0..7).collect::<Vec<_>>());
+        assert_eq!(items.focused(), Some(&12));
+        items.replace(vec![]);
+        assert_eq!(items.cursor(), 0);
+        assert_eq!(items.focused(), None);
+    }
+
+    #[test]
+    fn resizing_pages_keeps_the_cursor() {
+        let mut items = paginator(12);
+        items.focus(6);
+        assert_eq!(items.bounds(), 5..10);
+        items.set_page_size(7);
+        assert_eq!(items.cursor(), 6);
+        assert_eq!(items.bounds(), 0..7);
+        assert_eq!(items.pages(), 2);
+        assert!(items.has_more());
+        items.set_page_size(3);
+        assert_eq!(items.page(), 2);
+        assert_eq!(items.page_focus(), 0);
+        assert!(items.has_less());
+        items.set_page_size(0);
+        assert_eq!(items.page_size(), 1);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::font::Typeface;
+use graphics::character::{Character, CharacterCache};
+use graphics::types::FontSize;
+use piston_window::{G2dTexture, Glyphs};
+
+// A font and the glyphs that have been rendered from it so far.
+struct Face {
+    typeface: Typeface,
+    glyphs: Glyphs,
+}
+
+/// Everything that text is drawn with: the font, and a second font to fill in for whichever
+/// characters the first one doesn't have. OpenSans has never heard of most of the world's
+/// alphabets, and a headline about a game in Tokyo shouldn't be a row of empty boxes.
+///
+/// This is a CharacterCache in its own right, so it goes wherever a Glyphs would.
+pub struct Fonts {
+    primary: Face,
+    fallback: Option<Face>,
+}
+
+impl Fonts {
+    pub fn new(
+        primary: &Typeface,
+        fallback: Option<&Typeface>,
+        window: &mut piston_window::PistonWindow,
+    ) -> Fonts {
+        Fonts {
+            primary: face(primary, window),
+            fallback: fallback.map(|typeface| face(typeface, window)),
+        }
+    }
+
+    /// Each font's glyphs are uploaded through a command buffer of their own, all of which
+    /// have to be flushed along with everything else that was drawn.
+    pub fn caches(&mut self) -> impl Iterator<Item = &mut Glyphs> {
+        std::iter::once(&mut self.primary.glyphs)
+            .chain(self.fallback.as_mut().map(|fallback| &mut fallback.glyphs))
+    }
+}
+
+fn face(typeface: &Typeface, window: &mut piston_window::PistonWindow) -> Face {
+    let glyphs = Glyphs::from_bytes(
+        typeface.bytes,
+        piston_window::TextureContext {
+            factory: window.factory.clone(),
+            encoder: window.factory.create_command_buffer().into(),
+        },
+        piston_window::TextureSettings::new(),
+    )
+    .unwrap();
+    Face {
+        typeface: typeface.clone(),
+        glyphs,
+    }
+}
+
+impl CharacterCache for Fonts {
+    type Texture = G2dTexture;
+    type Error = <Glyphs as CharacterCache>::Error;
+
+    // The fallback only gets the characters that it has and the primary doesn't. Should
+    // neither of them have it, then it's the primary's empty box that gets drawn.
+    fn character<'a>(
+        &'a mut self,
+        font_size: FontSize,
+        ch: char,
+    ) -> Result<Character<'a, Self::Texture>, Self::Error> {
+        let primary = &mut self.primary;
+        match &mut self.fallback {
+            Some(fallback) if !primary.typeface.covers(ch) && fallback.typeface.covers(ch) => {
+                fallback.glyphs.character(font_size, ch)
+            }
+            _ => primary.glyphs.character(font_size, ch),
+        }
+    }
+}
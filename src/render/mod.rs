@@ -0,0 +1,188 @@
+use crate::theme::Theme;
+use image::RgbaImage;
+use mlb::lineup::Image;
+use piston_window::{ImageSize, Transformed};
+use std::collections::HashMap;
+
+mod fonts;
+
+pub use fonts::Fonts;
+
+/// Turning an RgbaImage into a texture means uploading it to the GPU, which is not the
+/// sort of thing that you want to be doing for every snippet on every single frame.
+/// This holds on to every texture that has been made, keyed by the identity of the
+/// image that it was made from, so that each image is only ever uploaded the once.
+pub struct TextureCache {
+    ctx: piston_window::G2dTextureContext,
+    textures: HashMap<String, piston_window::G2dTexture>,
+    // How many times drawing found its texture already made, and how many times it didn't.
+    hits: u64,
+    misses: u64,
+}
+
+impl TextureCache {
+    pub fn new(ctx: piston_window::G2dTextureContext) -> TextureCache {
+        TextureCache {
+            ctx,
+            textures: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Retrieves the texture for the image with the given id, creating it
+    /// out of the given image if this is the first time that we've seen it.
+    /// Photos get read back in at a new size when the window is resized, so
+    /// a texture of the wrong size is as good as no texture at all.
+    pub fn get(&mut self, id: &str, image: &RgbaImage) -> &piston_window::G2dTexture {
+        let stale = match self.textures.get(id) {
+            Some(texture) => texture.get_size() != image.dimensions(),
+            None => true,
+        };
+        if stale {
+            self.misses += 1;
+            self.upload(id, image);
+        } else {
+            self.hits += 1;
+        }
+        &self.textures[id]
+    }
+
+    /// The texture for the image with the given id, if one has been made. This is for images
+    /// that have gone away but are still on their way off of the screen.
+    pub fn cached(&self, id: &str) -> Option<&piston_window::G2dTexture> {
+        self.textures.get(id)
+    }
+
+    /// How many textures there are.
+    pub fn count(&self) -> usize {
+        self.textures.len()
+    }
+
+    /// The share of draws that found their texture already made, if anything has been drawn.
+    /// Photos that were uploaded ahead of time count as hits, which is the whole idea.
+    pub fn hit_rate(&self) -> Option<f64> {
+        match self.hits + self.misses {
+            0 => None,
+            total => Some(self.hits as f64 / total as f64),
+        }
+    }
+
+    /// Makes a texture for the image with the given id out of the given image, replacing any
+    /// that there already was. Photos are uploaded this way as soon as they show up, so that
+    /// the work is done between frames rather than in the middle of drawing one.
+    pub fn upload(&mut self, id: &str, image: &RgbaImage) {
+        let texture = piston_window::Texture::from_image(
+            &mut self.ctx,
+            image,
+            &piston_window::TextureSettings::new(),
+        )
+        .unwrap();
+        self.textures.insert(id.to_string(), texture);
+    }
+
+    /// Lets go of every texture. Handy for when the images that they were made from
+    /// are never coming back, such as when we move on to another date.
+    pub fn clear(&mut self) {
+        self.textures.clear();
+    }
+}
+
+/// Draws the given text the way that piston_window::text does, only crisply on a HiDPI screen.
+/// Everything is drawn in the window's points, which are bigger than the screen's pixels on
+/// one of those. So the glyphs are rendered at the screen's density and then shrunk back
+/// down to size, rather than rendered at size and then blown up (and blurred) to fit.
+pub fn text(
+    color: [f32; 4],
+    size: u32,
+    text: &str,
+    glyphs: &mut Fonts,
+    transform: graphics::math::Matrix2d,
+    density: f64,
+    g: &mut piston_window::G2d,
+) {
+    let rendered = ((size as f64 * density).round() as u32).max(1);
+    piston_window::text(
+        color,
+        rendered,
+        text,
+        glyphs,
+        transform.zoom(1.0 / density),
+        g,
+    )
+    .unwrap();
+}
+
+/// Draws the given image so that it fills the given rectangle. A photo that is fading in is
+/// drawn partway see-through over top of whatever stood in for it, and a placeholder for a
+/// photo that is still on its way gets a spinner in its corner.
+pub fn draw_image(
+    image: &Image,
+    rect: [f64; 4],
+    textures: &mut TextureCache,
+    theme: &Theme,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let opacity = match &image.fade {
+        Some(fade) => {
+            graphics::image::Image::new().rect(rect).draw(
+                textures.get(fade.id, fade.rgba),
+                &graphics::DrawState::default(),
+                c.transform,
+                g,
+            );
+            fade.opacity
+        }
+        None => 1.0,
+    };
+    graphics::image::Image::new()
+        .color([1.0, 1.0, 1.0, opacity])
+        .rect(rect)
+        .draw(
+            textures.get(image.id, image.rgba),
+            &graphics::DrawState::default(),
+            c.transform,
+            g,
+        );
+    if image.loading {
+        draw_spinner(rect, theme, c, g);
+    }
+}
+
+// A quarter circle chasing its tail in the top right corner of the given rectangle, much like
+// the one on the loading screen only smaller. It has a dark backdrop so that it shows up
+// against the lighter parts of the logo.
+fn draw_spinner(rect: [f64; 4], theme: &Theme, c: graphics::Context, g: &mut piston_window::G2d) {
+    let [x, y, width, _] = rect;
+    let radius = width * 0.05;
+    let bounds = [
+        x + width - radius * 3.0,
+        y + radius,
+        radius * 2.0,
+        radius * 2.0,
+    ];
+    graphics::ellipse(theme.scrim, bounds, c.transform, g);
+    // Only where the spinner is in its turn matters, so any clock will do.
+    let elapsed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let start = (elapsed.as_millis() % 1000) as f64 / 1000.0 * 2.0 * std::f64::consts::PI;
+    graphics::CircleArc::new(
+        theme.text,
+        radius / 4.0,
+        start,
+        start + std::f64::consts::FRAC_PI_2,
+    )
+    .draw(
+        [
+            bounds[0] + radius / 2.0,
+            bounds[1] + radius / 2.0,
+            radius,
+            radius,
+        ],
+        &graphics::DrawState::default(),
+        c.transform,
+        g,
+    );
+}
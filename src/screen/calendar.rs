@@ -0,0 +1,160 @@
+use super::{draw_centered, draw_footer, Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use chrono::{Datelike, NaiveDate};
+use mlb::lineup;
+use piston_window::Transformed;
+
+/// A month at a glance for picking out a day to go to, with the days that have games marked.
+/// The arrow keys move a day (or a week) at a time, page up and page down a month at a time,
+/// and Enter sends the carousel off to the day that the cursor is on.
+pub struct Calendar {
+    calendar: lineup::Calendar,
+}
+
+impl Calendar {
+    /// Opens up to the day on screen, or today if there's nothing on screen yet.
+    pub fn new(state: &mut State) -> Calendar {
+        let today = state
+            .current()
+            .and_then(|row| NaiveDate::parse_from_str(&row.date, "%Y-%m-%d").ok())
+            .unwrap_or_else(|| chrono::Local::now().naive_local().date());
+        Calendar {
+            calendar: lineup::Calendar::new(today, state.config.sport_id),
+        }
+    }
+}
+
+impl Screen for Calendar {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) | Command::Action(Action::Calendar) => {
+                return Transition::Pop
+            }
+            Command::Action(Action::Select) => {
+                state.jump = Some((self.calendar.cursor, None));
+                return Transition::Pop;
+            }
+            Command::Action(Action::Left) => self.calendar.move_days(-1),
+            Command::Action(Action::Right) => self.calendar.move_days(1),
+            Command::Action(Action::Up) => self.calendar.move_days(-7),
+            Command::Action(Action::Down) => self.calendar.move_days(7),
+            Command::Action(Action::PreviousPage) => self.calendar.move_months(-1),
+            Command::Action(Action::NextPage) => self.calendar.move_months(1),
+            Command::Action(Action::Fullscreen) | Command::Action(Action::Debug) => {
+                return Transition::Pass
+            }
+            _ => (),
+        }
+        Transition::Stay
+    }
+
+    fn update(&mut self, state: &mut State) -> Transition {
+        state.dirty |= self.calendar.poll();
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        let (layout, glyphs, theme) =
+            (&state.layout, &mut state.assets.glyphs, &state.config.theme);
+        draw_calendar(&self.calendar, layout, theme, glyphs, c, g);
+        if let Some(schedule) = &state.schedule {
+            draw_footer(schedule.copyright(), layout, theme, glyphs, c, g);
+        }
+    }
+}
+
+// The month and how to get around up top, the days of the week over the columns, and then a
+// square per day with its number in the corner. Days with games get a dot in the accent color
+// underneath the number, and the cursor's day is filled in like a badge.
+fn draw_calendar(
+    calendar: &lineup::Calendar,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    piston_window::rectangle(theme.scrim, layout.fullscreen(), c.transform, g);
+    let (headline, heading, body) = (
+        layout.font_size(32),
+        layout.font_size(20),
+        layout.font_size(24),
+    );
+    let month = locale::date("date-month-only", calendar.first());
+    draw_centered(
+        &month,
+        theme.text,
+        headline,
+        layout.point(960.0, 140.0),
+        layout.density,
+        glyphs,
+        c,
+        g,
+    );
+    let status = match (calendar.loading(), calendar.failed()) {
+        (true, _) => "calendar-loading",
+        (false, true) => "calendar-failed",
+        (false, false) => "calendar-hint",
+    };
+    draw_centered(
+        &locale::text(status),
+        theme.text,
+        heading,
+        layout.point(960.0, 196.0),
+        layout.density,
+        glyphs,
+        c,
+        g,
+    );
+    for weekday in 0..7 {
+        let [x, y, ..] = layout.calendar_cell(0, weekday);
+        let name = locale::text(&format!("weekday-{}", weekday + 1));
+        render::text(
+            theme.text,
+            heading,
+            &name,
+            glyphs,
+            c.transform.trans(x, y - layout.length(16.0)),
+            layout.density,
+            g,
+        );
+    }
+    for (week, days) in calendar.weeks().iter().enumerate() {
+        for (weekday, date) in days.iter().enumerate() {
+            let date = match date {
+                Some(date) => *date,
+                None => continue,
+            };
+            let cell = layout.calendar_cell(week, weekday);
+            let color = match date == calendar.cursor {
+                true => {
+                    piston_window::rectangle(theme.badge, cell, c.transform, g);
+                    theme.badge_text
+                }
+                false => theme.text,
+            };
+            let [x, y, ..] = cell;
+            let inset = layout.length(12.0);
+            render::text(
+                color,
+                body,
+                &date.day().to_string(),
+                glyphs,
+                c.transform
+                    .trans(x + inset, y + inset + layout.length(24.0)),
+                layout.density,
+                g,
+            );
+            if calendar.has_games(date) == Some(true) {
+                let size = layout.length(12.0);
+                let dot = [x + inset, y + layout.length(64.0), size, size];
+                piston_window::rectangle(theme.accent, dot, c.transform, g);
+            }
+        }
+    }
+}
@@ -0,0 +1,1316 @@
+use super::{
+    draw_centered, draw_footer, open_in_browser, pretty_date, Calendar, Detail, Error, GoTo,
+    Screen, Search, Team, Transactions, Transition, Week,
+};
+use crate::app::State;
+use crate::backdrop::Backdrop;
+use crate::config::Config;
+use crate::input::{Action, Command};
+use crate::layout::{self, Fit};
+use crate::locale::{self, Language};
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use chrono::{Datelike, NaiveDate};
+use graphics::character::CharacterCache;
+use mlb::api;
+use mlb::lineup::{download, Image, Lineup, Progress, Row, Snippet, Sort, Ticker};
+use piston_window::{ImageSize, Transformed};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tracing::{info, info_span, warn, Instrument};
+
+/// The rows of games, which is what the app opens up to and what is always at the bottom of
+/// the stack. This is also what walks the calendar, so it is in charge of downloading (and
+/// re-downloading) the schedule.
+pub struct Carousel {
+    // With more than one day on screen, date is the first of them, and walking the calendar
+    // off of either end of the rows moves a whole span of days at a time.
+    date: NaiveDate,
+    // The date of the schedule that is actually on screen, which lags behind date
+    // while the user is walking the calendar.
+    showing: NaiveDate,
+    // Whether the user walked off of the top row, in which case they ought to land on the
+    // bottom row of whatever comes in next rather than the top.
+    from_below: bool,
+    // The schedule that is currently in flight, if any.
+    pending: Option<Pending>,
+    // Periodic re-downloads of whatever date is on screen.
+    refreshing: Option<Pending>,
+    // The days on either side of whatever is on screen (and it too), all fetched with the one
+    // request, so that walking the calendar a day at a time doesn't wait on the network every
+    // time. They're only trusted for as long as a refresh would have left them be.
+    window: Option<(Instant, api::days::Days)>,
+    // The next window, while it's on its way.
+    prefetching: Option<Pending>,
+    loading_since: Instant,
+    // When the cursor last moved on its own in kiosk mode, or was last moved by anybody.
+    advanced: Instant,
+    // Which game the cursor was on when the last run closed, for the first schedule to land on.
+    restore: Option<usize>,
+    // The game to put the cursor on once the schedule that is on its way lands, when one was
+    // picked out of the week view.
+    focus: Option<u64>,
+    // The scores of the games in progress, for as long as there are any on screen.
+    ticker: Option<Ticker>,
+    // The focused game's photo, blurred in behind everything else.
+    backdrop: Backdrop,
+}
+
+impl Carousel {
+    // The initial API call used to be .awaited right up front, which kept the window from ever
+    // showing up until it came back. Now it goes off into the background just like every
+    // other request and we spin a loading indicator until the schedule snaps into place.
+    pub fn new(state: &State) -> Carousel {
+        Self::starting(state, 1)
+    }
+
+    /// The same as new, except that the schedule is given a few tries (backing off a little
+    /// more after each one) before it is given up on. This is for coming back from the error
+    /// screen, where the user already knows that the network is having a bad day.
+    pub fn retrying(state: &State) -> Carousel {
+        Self::starting(state, Self::RETRIES)
+    }
+
+    const RETRIES: u32 = 4;
+
+    fn starting(state: &State, attempts: u32) -> Carousel {
+        let config = &state.config;
+        let date = config
+            .date
+            .unwrap_or_else(|| chrono::Local::now().naive_local().date());
+        Carousel {
+            date,
+            showing: date,
+            from_below: false,
+            pending: Some(fetch_initial(
+                config.date,
+                config.days,
+                config.sport_id,
+                config.language,
+                config.timeout,
+                attempts,
+            )),
+            refreshing: None,
+            window: None,
+            prefetching: None,
+            loading_since: Instant::now(),
+            advanced: Instant::now(),
+            restore: Some(state.session.cursor),
+            focus: None,
+            ticker: None,
+            backdrop: Backdrop::new(config.reduced_motion),
+        }
+    }
+
+    // Walks the calendar to the given date. The current lineup stays on screen until the
+    // new one shows up, which is right away if it's in the window.
+    fn walk(&mut self, date: NaiveDate, from_below: bool, state: &State) {
+        let config = &state.config;
+        self.date = date;
+        self.from_below = from_below;
+        let windowed = self
+            .window
+            .as_ref()
+            .filter(|(fetched, _)| fetched.elapsed() < config.refresh)
+            .and_then(|(_, days)| days.schedule(date, config.days));
+        self.pending = Some(match windowed {
+            Some(schedule) => Pending::ready(date, schedule),
+            None => fetch(
+                date,
+                config.days,
+                config.sport_id,
+                config.language,
+                config.timeout,
+            ),
+        });
+    }
+
+    // Makes sure that there's a window around the given date that reaches at least one more
+    // walk of the calendar in either direction, sending for a new one if not. Whatever window
+    // was already on its way was for somewhere else, so it's called off.
+    fn prefetch(&mut self, date: NaiveDate, config: &Config) {
+        let span = chrono::Duration::days(config.days as i64);
+        let covered = self.window.as_ref().is_some_and(|(fetched, days)| {
+            fetched.elapsed() < config.refresh && days.covers(date - span, config.days * 3)
+        });
+        if covered {
+            return;
+        }
+        let before = chrono::Duration::days(margin(config.days) as i64);
+        self.prefetching = Some(fetch(
+            date - before,
+            window_length(config.days),
+            config.sport_id,
+            config.language,
+            config.timeout,
+        ));
+    }
+
+    // Forgets the window, for when whatever is in it is no good anymore.
+    fn close_window(&mut self) {
+        self.window = None;
+        self.prefetching = None;
+    }
+
+    fn act(&mut self, action: Action, state: &mut State) -> Transition {
+        let span = chrono::Duration::days(state.config.days as i64);
+        match action {
+            Action::Left => {
+                if let Some(row) = state.current() {
+                    row.schedule.left();
+                }
+            }
+            Action::Right => {
+                if let Some(row) = state.current() {
+                    row.schedule.right();
+                }
+            }
+            Action::PreviousPage => {
+                if let Some(row) = state.current() {
+                    row.schedule.previous_page();
+                }
+            }
+            Action::NextPage => {
+                if let Some(row) = state.current() {
+                    row.schedule.next_page();
+                }
+            }
+            // For when the game that you're after is all the way at the other end of the slate.
+            Action::First => {
+                if let Some(row) = state.current() {
+                    row.schedule.first();
+                }
+            }
+            Action::Last => {
+                if let Some(row) = state.current() {
+                    row.schedule.last();
+                }
+            }
+            // Up and down move between the rows, and walk the calendar once they run out of rows.
+            Action::Up => {
+                if !state.schedule.as_mut().is_some_and(Lineup::up) {
+                    self.walk(self.date - span, true, state);
+                }
+            }
+            Action::Down => {
+                if !state.schedule.as_mut().is_some_and(Lineup::down) {
+                    self.walk(self.date + span, false, state);
+                }
+            }
+            Action::Select => {
+                if state.current().is_some_and(|row| !row.schedule.is_empty()) {
+                    return Transition::Push(Box::new(Detail::new()));
+                }
+            }
+            Action::Back => return Transition::Exit(0),
+            Action::Search => return Transition::Push(Box::new(Search::new(String::new(), state))),
+            Action::GoTo => return Transition::Push(Box::new(GoTo::default())),
+            Action::Today => self.walk(chrono::Local::now().naive_local().date(), false, state),
+            // A random season is a different one every time, so this can be pressed over and
+            // over to go wandering through history.
+            Action::History => {
+                let season = state.config.on_this_day.unwrap_or(api::Season::Random);
+                let date = season.same_day(chrono::Local::now().naive_local().date());
+                self.walk(date, false, state);
+                let year = date.year().to_string();
+                let text = locale::text_with("on-this-day", &[("year", &year)]);
+                state.banners.push(text, Instant::now());
+            }
+            // Calls off whatever is in flight (which is probably stuck, if you're reaching for
+            // this) and starts over. This doubles as a way to refresh the schedule on demand.
+            Action::Refresh => {
+                self.close_window();
+                let config = &state.config;
+                self.pending = Some(match state.schedule {
+                    Some(_) => fetch(
+                        self.date,
+                        config.days,
+                        config.sport_id,
+                        config.language,
+                        config.timeout,
+                    ),
+                    None => fetch_initial(
+                        config.date,
+                        config.days,
+                        config.sport_id,
+                        config.language,
+                        config.timeout,
+                        1,
+                    ),
+                });
+            }
+            Action::Spoilers => {
+                state.spoiler_free = !state.spoiler_free;
+                let spoiler_free = state.spoiler_free;
+                if let Some(schedule) = &mut state.schedule {
+                    schedule.set_spoiler_free(spoiler_free);
+                }
+            }
+            Action::Filter => {
+                state.filter = match state.filter {
+                    Some(_) => None,
+                    None if !state.config.favorites.is_empty() => {
+                        Some(state.config.favorites.clone())
+                    }
+                    None => state
+                        .current()
+                        .and_then(|row| row.schedule.focused())
+                        .map(|game| {
+                            vec![
+                                game.away.abbreviation.clone(),
+                                game.home.abbreviation.clone(),
+                            ]
+                        }),
+                };
+                if let Some(schedule) = &mut state.schedule {
+                    schedule.set_filter(state.filter.as_deref());
+                }
+            }
+            // Games start and finish between refreshes, so the refreshes speed up while only
+            // some of them are showing. Otherwise a game that has just ended would hang around
+            // for as long as the regular refresh took.
+            Action::Progress => {
+                state.progress = state.progress.next();
+                if let Some(schedule) = &mut state.schedule {
+                    schedule.set_progress(state.progress);
+                }
+                if self.refreshing.is_some() {
+                    let config = &state.config;
+                    self.refreshing = Some(refresh(
+                        self.date,
+                        config.days,
+                        config.sport_id,
+                        config.language,
+                        config.timeout,
+                        refresh_every(config.refresh, state.progress),
+                    ));
+                }
+            }
+            // The focus stays on the same game, which may well be somewhere else now, so
+            // there's a banner saying why.
+            Action::Sort => {
+                state.sort = state.sort.next();
+                if let Some(schedule) = &mut state.schedule {
+                    schedule.set_sort(state.sort);
+                }
+                let id = match state.sort {
+                    Sort::Favorites => "sort-favorites",
+                    Sort::Time => "sort-time",
+                    Sort::Status => "sort-status",
+                };
+                state.banners.push(locale::text(id), Instant::now());
+            }
+            Action::Reveal => {
+                if let Some(row) = state.current() {
+                    row.schedule.reveal();
+                }
+            }
+            Action::Story => {
+                let story = state
+                    .current()
+                    .and_then(|row| row.schedule.story())
+                    .map(str::to_string);
+                if let Some(url) = story {
+                    open_in_browser(&url, "story-failed", state);
+                }
+            }
+            // Condensed games and recaps only mean anything once a game's details are open.
+            Action::Condensed | Action::Recap => (),
+            Action::Team => {
+                if let Some(team) = Team::new(None, state) {
+                    return Transition::Push(Box::new(team));
+                }
+            }
+            Action::Transactions => return Transition::Push(Box::new(Transactions::new(state))),
+            Action::Week => return Transition::Push(Box::new(Week::new(state))),
+            Action::Calendar => return Transition::Push(Box::new(Calendar::new(state))),
+            // The same day, one level down. The refreshes are for the old level, and whatever
+            // the filter was after is almost certainly not playing at the new one.
+            Action::Sport => {
+                let sport = api::Sport::after(state.config.sport_id);
+                state.config.sport_id = sport.id;
+                state.filter = None;
+                self.refreshing = None;
+                self.close_window();
+                self.walk(self.date, false, state);
+                let name = locale::text(&format!("sport-{}", sport.name));
+                let text = locale::text_with("sport-switched", &[("sport", &name)]);
+                state.banners.push(text, Instant::now());
+            }
+            // Everything that is loaded, which is every row and not just the one on screen.
+            Action::Export => {
+                if let Some(lineup) = &state.schedule {
+                    let path = PathBuf::from(format!("mlb-{}.json", self.showing));
+                    let entries = lineup.entries();
+                    let span = info_span!("export", path = %path.display());
+                    let _entered = span.enter();
+                    match mlb::export::write(&entries, &path) {
+                        Ok(()) => info!(games = entries.len(), "Exported the schedule"),
+                        Err(err) => {
+                            warn!(error = %err, "Failed to export the schedule");
+                            let path = path.display().to_string();
+                            let text = locale::text_with("export-failed", &[("path", &path)]);
+                            state.banners.push(text, Instant::now());
+                        }
+                    }
+                }
+            }
+            // The window (and what goes over top of all of it) is the app's business.
+            Action::Fullscreen | Action::Debug => return Transition::Pass,
+        }
+        Transition::Stay
+    }
+
+    // Clicking works out what was clicked on the same way that the drawing does, so the two
+    // always agree on where everything is. The arrows page, a small snippet is focused, and
+    // the focused (large) snippet opens up its details just like Enter does (or a club's page,
+    // for one of its logos).
+    fn click(&mut self, mouse: [f64; 2], state: &mut State) -> Transition {
+        let layout = state.layout;
+        if let Some(row) = state.current() {
+            let schedule = &mut row.schedule;
+            if schedule.has_less() && layout::contains(layout.left_arrow(), mouse) {
+                schedule.previous_page();
+            } else if schedule.has_more() && layout::contains(layout.right_arrow(), mouse) {
+                schedule.next_page();
+            } else {
+                let bounds = schedule.games.bounds();
+                let focus = schedule.games.page_focus();
+                let snippets = layout.snippets(bounds.len(), focus);
+                let clicked = snippets
+                    .iter()
+                    .position(|rect| layout::contains(*rect, mouse));
+                match clicked {
+                    // Either club's logo opens up their page instead.
+                    Some(index) if index == focus => {
+                        let [away, home] = layout.logos(snippets[index]);
+                        let team = schedule.focused().and_then(|game| {
+                            match (layout::contains(away, mouse), layout::contains(home, mouse)) {
+                                (true, _) => Some(game.away.id),
+                                (_, true) => Some(game.home.id),
+                                _ => None,
+                            }
+                        });
+                        return match team.and_then(|team| Team::new(Some(team), state)) {
+                            Some(team) => Transition::Push(Box::new(team)),
+                            None => Transition::Push(Box::new(Detail::new())),
+                        };
+                    }
+                    Some(index) => schedule.focus(bounds.start + index),
+                    None => (),
+                }
+            }
+        }
+        Transition::Stay
+    }
+}
+
+impl Screen for Carousel {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        // Somebody is at the controls after all, so kiosk mode gives them a full turn before
+        // it takes back over.
+        self.advanced = Instant::now();
+        match command {
+            Command::Action(action) => self.act(action, state),
+            // Typing a letter or number that doesn't do anything else starts a search with it.
+            Command::Text(typed) => {
+                let keymap = &state.config.keymap;
+                let unbound =
+                    |c: char| c.is_alphanumeric() && keymap.action(&c.to_string()).is_none();
+                match typed.chars().all(unbound) {
+                    true => Transition::Push(Box::new(Search::new(typed, state))),
+                    false => Transition::Stay,
+                }
+            }
+            Command::Click(point) => self.click(point, state),
+            Command::Erase => Transition::Stay,
+        }
+    }
+
+    fn update(&mut self, state: &mut State) -> Transition {
+        // A day picked out of the week view, which is already off of the stack by now.
+        if let Some((date, game)) = state.jump.take() {
+            self.focus = game;
+            self.walk(date, false, state);
+        }
+        let config = &state.config;
+        if let Some(rx) = &self.pending {
+            match rx.try_recv() {
+                Ok((fetched_date, Ok(fetched))) => {
+                    self.date = fetched_date;
+                    self.showing = fetched_date;
+                    let mut lineup = Lineup::new(fetched, state.settings());
+                    lineup.set_spoiler_free(state.spoiler_free);
+                    if state.filter.is_some() {
+                        lineup.set_filter(state.filter.as_deref());
+                    }
+                    if state.progress != Progress::All {
+                        lineup.set_progress(state.progress);
+                    }
+                    if state.sort != Sort::Favorites {
+                        lineup.set_sort(state.sort);
+                    }
+                    if self.from_below {
+                        lineup.focus_last();
+                    }
+                    if let (Some(cursor), Some(row)) = (self.restore.take(), lineup.current()) {
+                        row.schedule.focus(cursor);
+                    }
+                    if let Some(game) = self.focus.take() {
+                        lineup.focus_game(game);
+                    }
+                    state.schedule = Some(lineup);
+                    state.assets.textures.clear();
+                    state.metrics.fetched = Some(Instant::now());
+                    state.dirty = true;
+                    self.pending = None;
+                    self.refreshing = Some(refresh(
+                        fetched_date,
+                        config.days,
+                        config.sport_id,
+                        config.language,
+                        config.timeout,
+                        refresh_every(config.refresh, state.progress),
+                    ));
+                    self.prefetch(fetched_date, config);
+                }
+                // I handle the error of not being able to pull the initial API call and render
+                // as the sole text onto the screen. The error screen can try again. I admit
+                // that after this, any Result given back by the graphics library I just unwrap. This
+                // is because after this point everything is already in memory so we're not suffering
+                // from IO failures, however it is entirely possible that we were given back, say,
+                // images that don't parse out correctly. I simply did not have the time to scope
+                // out such rich error handling and how that would tie into the main window rendering.
+                //
+                // Other parts of this application that are more in my problem domain I am more careful with.
+                Ok((_, Err(err))) if state.schedule.is_none() => {
+                    return Transition::Replace(Box::new(Error::new(err)));
+                }
+                // Past startup we already have something perfectly good to show, so
+                // a failure here just gets logged and we stay put.
+                Ok((fetched_date, Err(err))) => {
+                    warn!(date = %fetched_date, error = %err, "Failed to retrieve the schedule");
+                    let date = locale::date("date-month", fetched_date);
+                    let text = locale::text_with("schedule-failed", &[("date", &date)]);
+                    state.banners.push(text, Instant::now());
+                    self.pending = None;
+                }
+                Err(crossbeam_channel::TryRecvError::Disconnected) => self.pending = None,
+                Err(crossbeam_channel::TryRecvError::Empty) => (),
+            }
+        }
+        if let Some(rx) = &self.prefetching {
+            match rx.try_recv() {
+                Ok((start, Ok(schedule))) => {
+                    let end = start + chrono::Duration::days(window_length(config.days) as i64 - 1);
+                    self.window =
+                        Some((Instant::now(), api::days::Days::new(schedule, start, end)));
+                    self.prefetching = None;
+                }
+                // It was only ever a head start, so walking the calendar goes back to asking
+                // for each day as it comes.
+                Ok((start, Err(err))) => {
+                    warn!(date = %start, error = %err, "Failed to prefetch the schedule");
+                    self.prefetching = None;
+                }
+                Err(crossbeam_channel::TryRecvError::Disconnected) => self.prefetching = None,
+                Err(crossbeam_channel::TryRecvError::Empty) => (),
+            }
+        }
+        if let Some(rx) = &self.refreshing {
+            match (rx.try_recv(), &mut state.schedule) {
+                (Ok((refreshed_date, Ok(fresh))), Some(schedule))
+                    if refreshed_date == self.date =>
+                {
+                    schedule.merge(fresh);
+                    state.metrics.fetched = Some(Instant::now());
+                    state.dirty = true;
+                }
+                (Ok((refreshed_date, Err(err))), _) => {
+                    warn!(date = %refreshed_date, error = %err, "Failed to refresh the schedule");
+                    let text = locale::text("refresh-failed");
+                    state.banners.push(text, Instant::now());
+                }
+                _ => (),
+            }
+        }
+        match &mut state.schedule {
+            Some(schedule) => {
+                state.dirty |= schedule.poll();
+                // Each failed photo has already said as much in the log, so one banner covers
+                // all of them.
+                if schedule.take_failures() > 0 {
+                    let text = locale::text("photos-failed");
+                    state.banners.push(text, Instant::now());
+                }
+                // Whatever the cursor lands on crossfades in behind the carousel, once its
+                // photo has shown up.
+                let backdrop = match config.backdrop {
+                    true => schedule
+                        .current()
+                        .and_then(|row| row.schedule.backdrop())
+                        .map(|image| image.id.to_string()),
+                    false => None,
+                };
+                // The lineup's games only change state when it's refreshed, which is plenty
+                // often to tell when to start and stop the ticker, which in turn keeps up with
+                // the scores on its own.
+                let live = schedule
+                    .live_date()
+                    .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+                match (live, &mut self.ticker) {
+                    (Some(date), Some(ticker))
+                        if ticker.date == date && ticker.sport_id == config.sport_id =>
+                    {
+                        state.dirty |= ticker.poll();
+                    }
+                    (Some(date), _) => self.ticker = Some(Ticker::new(date, config.sport_id)),
+                    (None, _) => self.ticker = None,
+                }
+                let now = Instant::now();
+                state.dirty |= self.backdrop.show(backdrop.as_deref(), now);
+                state.dirty |= self.backdrop.fading(now);
+                if let Some(every) = config.kiosk {
+                    if self.advanced.elapsed() >= every {
+                        schedule.advance();
+                        self.advanced = Instant::now();
+                        state.dirty = true;
+                    }
+                }
+            }
+            // The loading spinner is always on the move.
+            None => state.dirty = true,
+        }
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        let (layout, assets) = (&state.layout, &mut state.assets);
+        let theme = &state.config.theme;
+        let schedule = match &mut state.schedule {
+            Some(schedule) => schedule,
+            None => {
+                draw_loading(self.loading_since, layout, theme, &mut assets.glyphs, c, g);
+                return;
+            }
+        };
+        // The API asks that this be on everything that we show of what it gave us.
+        let copyright = schedule.copyright().to_string();
+        if schedule.is_empty() {
+            draw_no_games(
+                self.showing,
+                state.config.days,
+                layout,
+                theme,
+                &mut assets.glyphs,
+                c,
+                g,
+            );
+            let (teams, progress) = (state.filter.as_deref(), state.progress);
+            draw_filter(teams, progress, layout, theme, &mut assets.glyphs, c, g);
+            draw_footer(&copyright, layout, theme, &mut assets.glyphs, c, g);
+            return;
+        }
+        let (above, current, below) = schedule.neighborhood();
+        let row = match current {
+            Some(row) => row,
+            None => return,
+        };
+        if state.config.backdrop {
+            let backdrop = row.schedule.backdrop();
+            draw_backdrop(&self.backdrop, backdrop, layout, &mut assets.textures, c, g);
+        }
+        // The rows above and below (if there are any) are just strips of thumbnails with
+        // their dates over them, so that the user knows what is up there.
+        let font_size = layout.font_size(16);
+        for (row, above) in [(above, true), (below, false)] {
+            if let Some(row) = row {
+                draw_strip(
+                    row,
+                    above,
+                    layout,
+                    theme,
+                    &mut assets.textures,
+                    &mut assets.glyphs,
+                    c,
+                    g,
+                );
+            }
+        }
+        draw_header(&row.date, layout, theme, &mut assets.glyphs, c, g);
+        let (teams, progress) = (state.filter.as_deref(), state.progress);
+        draw_filter(teams, progress, layout, theme, &mut assets.glyphs, c, g);
+        let schedule = &mut row.schedule;
+        let favorites: Vec<bool> = schedule
+            .games
+            .current_page()
+            .iter()
+            .map(|game| game.favorite)
+            .collect();
+        let logos = schedule.logos();
+        // Only worth mentioning for games that have a story, and only if there's a key for it.
+        let story_hint = match (schedule.story(), state.config.keymap.key(Action::Story)) {
+            (Some(_), Some(key)) => Some(locale::text_with("story-hint", &[("key", &key)])),
+            _ => None,
+        };
+        let page = schedule.page();
+        let page_len = page.len();
+        let focus = page
+            .iter()
+            .position(|snippet| matches!(snippet, Snippet::Large { .. }))
+            .unwrap_or(0);
+        let rects = layout.snippets(page_len, focus);
+        let snippets = page.into_iter().zip(rects).zip(favorites).zip(logos);
+        for (((item, rect), favorite), logos) in snippets {
+            match item {
+                Snippet::Large {
+                    image,
+                    heading,
+                    subheading,
+                    live,
+                    badge,
+                    records,
+                } => {
+                    render::draw_image(&image, rect, &mut assets.textures, theme, c, g);
+                    if let Some(badge) = badge {
+                        draw_badge(&badge, rect, layout, theme, &mut assets.glyphs, c, g);
+                    }
+                    // Render our header and subheader
+                    let [x, y] = layout.heading(rect);
+                    render::text(
+                        theme.text,
+                        font_size,
+                        heading,
+                        &mut assets.glyphs,
+                        c.transform.trans(x, y),
+                        layout.density,
+                        g,
+                    );
+                    if let Some(records) = records {
+                        let [x, y] = layout.records(rect);
+                        render::text(
+                            theme.small_print,
+                            layout.font_size(14),
+                            &records,
+                            &mut assets.glyphs,
+                            c.transform.trans(x, y),
+                            layout.density,
+                            g,
+                        );
+                    }
+                    let [x, y] = layout.subheading(rect);
+                    render::text(
+                        theme.text,
+                        font_size,
+                        subheading,
+                        &mut assets.glyphs,
+                        c.transform.trans(x, y),
+                        layout.density,
+                        g,
+                    );
+                    // Games that are in progress also get their inning, count, and score, and
+                    // those that haven't started get who is pitching.
+                    if let Some(live) = live {
+                        let [x, y] = layout.live(rect);
+                        render::text(
+                            theme.text,
+                            font_size,
+                            &live,
+                            &mut assets.glyphs,
+                            c.transform.trans(x, y),
+                            layout.density,
+                            g,
+                        );
+                    }
+                    if let Some(hint) = &story_hint {
+                        let [x, y] = layout.story_hint(rect);
+                        render::text(
+                            theme.small_print,
+                            layout.font_size(14),
+                            hint,
+                            &mut assets.glyphs,
+                            c.transform.trans(x, y),
+                            layout.density,
+                            g,
+                        );
+                    }
+                }
+                Snippet::Small(image, badge) => {
+                    render::draw_image(&image, rect, &mut assets.textures, theme, c, g);
+                    if let Some(badge) = badge {
+                        draw_badge(&badge, rect, layout, theme, &mut assets.glyphs, c, g);
+                    }
+                }
+            }
+            // In the top corners, so that the game can be told apart before its photo shows up.
+            for (logo, corner) in logos.iter().zip(layout.logos(rect).iter()) {
+                if let Some(logo) = logo {
+                    render::draw_image(&logo.image(), *corner, &mut assets.textures, theme, c, g);
+                }
+            }
+            // Around the outside of the photo, so as not to cover any of it up.
+            if favorite {
+                graphics::Rectangle::new_border(theme.accent, layout.length(4.0)).draw(
+                    rect,
+                    &graphics::DrawState::default(),
+                    c.transform,
+                    g,
+                );
+            }
+        }
+        // has_less and has_more describe whether or not there is a page to left or the right,
+        // which drives the decision on whether or not to render the scroll arrow indicators.
+        //
+        // When you don't have enough time for large technical implementations goals
+        // (such as richer error handling or window responsiveness) then you should try to
+        // fill in the sprint/release with small attention to detail that often delight
+        // stakeholders. These small details don't take much time, they're going to be there
+        // eventually anyways, and their implementation buys you a bit more time (politically)
+        // to implement the harder stuff while keeping everyone happy.
+        if schedule.has_less() {
+            graphics::image::Image::new()
+                .rect(layout.left_arrow())
+                .draw(
+                    &assets.left_arrow,
+                    &graphics::DrawState::default(),
+                    c.transform,
+                    g,
+                );
+        }
+        if schedule.has_more() {
+            graphics::image::Image::new()
+                .rect(layout.right_arrow())
+                .draw(
+                    &assets.right_arrow,
+                    &graphics::DrawState::default(),
+                    c.transform,
+                    g,
+                );
+        }
+        if let Some(ticker) = &self.ticker {
+            let spoiler_free = state.spoiler_free;
+            draw_ticker(
+                ticker,
+                spoiler_free,
+                layout,
+                theme,
+                &mut assets.glyphs,
+                c,
+                g,
+            );
+        }
+        draw_footer(&copyright, layout, theme, &mut assets.glyphs, c, g);
+    }
+}
+
+// The score that's up in the ticker, if there is one, across a bar along the bottom of the
+// screen. It's flagged as live on the left, and says which of how many it is on the right.
+fn draw_ticker(
+    ticker: &Ticker,
+    spoiler_free: bool,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let score = match ticker.current() {
+        Some(score) => score,
+        None => return,
+    };
+    let bar = layout.ticker();
+    piston_window::rectangle(theme.scrim, bar, c.transform, g);
+    let size = layout.font_size(18);
+    let [x, y] = layout.ticker_text(bar);
+    let label = locale::text("ticker-live");
+    render::text(
+        theme.accent,
+        size,
+        &label,
+        glyphs,
+        c.transform.trans(x, y),
+        layout.density,
+        g,
+    );
+    let indent = glyphs.width(size, &label).unwrap_or(0.0) + layout.length(16.0);
+    render::text(
+        theme.text,
+        size,
+        &score.text(spoiler_free),
+        glyphs,
+        c.transform.trans(x + indent, y),
+        layout.density,
+        g,
+    );
+    let (index, count) = ticker.position();
+    let position = format!("{}/{}", index, count);
+    let width = glyphs.width(size, &position).unwrap_or(0.0);
+    render::text(
+        theme.small_print,
+        size,
+        &position,
+        glyphs,
+        c.transform.trans(bar[0] + bar[2] - (x - bar[0]) - width, y),
+        layout.density,
+        g,
+    );
+}
+
+// Fills the window with the focused game's blurred photo, over top of whichever one it is taking
+// over from for as long as they're crossfading. With nothing taking over, the old one fades
+// back out to the usual background instead.
+fn draw_backdrop(
+    backdrop: &Backdrop,
+    image: Option<Image>,
+    layout: &layout::Layout,
+    textures: &mut render::TextureCache,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let now = Instant::now();
+    let opacity = backdrop.opacity(now);
+    let cover = |texture: &piston_window::G2dTexture| {
+        let (width, height) = texture.get_size();
+        layout.background([width as f64, height as f64], Fit::Cover)
+    };
+    let previous = backdrop
+        .previous(now)
+        .filter(|id| Some(*id) != image.as_ref().map(|image| image.id));
+    if let Some(texture) = previous.and_then(|id| textures.cached(id)) {
+        let fading = match image {
+            Some(_) => 1.0,
+            None => 1.0 - opacity,
+        };
+        graphics::image::Image::new()
+            .color([1.0, 1.0, 1.0, fading])
+            .rect(cover(texture))
+            .draw(texture, &graphics::DrawState::default(), c.transform, g);
+    }
+    if let Some(image) = image {
+        let texture = textures.get(image.id, image.rgba);
+        graphics::image::Image::new()
+            .color([1.0, 1.0, 1.0, opacity])
+            .rect(cover(texture))
+            .draw(texture, &graphics::DrawState::default(), c.transform, g);
+    }
+}
+
+// What's on screen when there isn't a single game on the date in question. Up and down still
+// walk the calendar from here, which is exactly what the user ought to do next.
+fn draw_no_games(
+    date: NaiveDate,
+    days: u32,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    piston_window::rectangle(theme.scrim, layout.fullscreen(), c.transform, g);
+    let headline = match days {
+        1 => locale::text_with("no-games-on", &[("date", &locale::date("date-full", date))]),
+        _ => {
+            let through = date + chrono::Duration::days(days as i64 - 1);
+            locale::text_with(
+                "no-games-between",
+                &[
+                    ("from", &locale::date("date-month", date)),
+                    ("through", &locale::date("date-month-year", through)),
+                ],
+            )
+        }
+    };
+    let hint = locale::text("no-games-hint");
+    let lines = [(headline.as_str(), 32, 520.0), (hint.as_str(), 20, 580.0)];
+    for (line, size, top) in lines.iter() {
+        let size = layout.font_size(*size);
+        let width = glyphs.width(size, line).unwrap_or(0.0);
+        let [_, y] = layout.point(0.0, *top);
+        render::text(
+            theme.text,
+            size,
+            line,
+            glyphs,
+            c.transform.trans((layout.width - width) / 2.0, y),
+            layout.density,
+            g,
+        );
+    }
+}
+
+// A row other than the focused one, shrunk down to a strip of thumbnails with its date above them.
+fn draw_strip(
+    row: &mut Row,
+    above: bool,
+    layout: &layout::Layout,
+    theme: &Theme,
+    textures: &mut render::TextureCache,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let images = row.schedule.strip();
+    // A date without any games still gets its label, just with nothing underneath it.
+    let count = images.len().max(1);
+    let rects = if above {
+        layout.strip_above(count)
+    } else {
+        layout.strip_below(count)
+    };
+    let mut label = pretty_date(&row.date, "date-weekday");
+    if images.is_empty() {
+        label = locale::text_with("no-games-label", &[("date", &label)]);
+    }
+    let [x, y] = layout.strip_label(rects[0]);
+    render::text(
+        theme.text,
+        layout.font_size(16),
+        &label,
+        glyphs,
+        c.transform.trans(x, y),
+        layout.density,
+        g,
+    );
+    for (image, rect) in images.into_iter().zip(rects) {
+        render::draw_image(&image, rect, textures, theme, c, g);
+    }
+}
+
+// A game's score (or start time) over the bottom of its photo, with a scrim behind it since
+// there's no telling how light or busy the photo is going to be down there.
+fn draw_badge(
+    text: &str,
+    snippet: [f64; 4],
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let badge = layout.badge(snippet);
+    piston_window::rectangle(theme.badge, badge, c.transform, g);
+    let [x, y] = layout.badge_text(badge);
+    render::text(
+        theme.badge_text,
+        layout.font_size(16),
+        text,
+        glyphs,
+        c.transform.trans(x, y),
+        layout.density,
+        g,
+    );
+}
+
+// The date of the games that are in focus, front and center at the top of the screen.
+fn draw_header(
+    date: &str,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let header = pretty_date(date, "date-full");
+    let size = layout.font_size(32);
+    draw_centered(
+        &header,
+        theme.text,
+        size,
+        layout.header(),
+        layout.density,
+        glyphs,
+        c,
+        g,
+    );
+}
+
+// A reminder that there may be more games than the ones on screen, should the user have
+// forgotten that they asked for a filter (or for only some of the games by how far along they
+// are).
+fn draw_filter(
+    teams: Option<&[String]>,
+    progress: Progress,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let id = match progress {
+        Progress::All => "filter",
+        Progress::Unfinished => "filter-unfinished",
+        Progress::Live => "filter-live",
+    };
+    let label = match (teams, progress) {
+        (None, Progress::All) => return,
+        (None, _) => locale::text(id),
+        (Some(teams), Progress::All) => locale::text_with(id, &[("teams", &teams.join(", "))]),
+        (Some(teams), _) => {
+            locale::text_with(&format!("{}-teams", id), &[("teams", &teams.join(", "))])
+        }
+    };
+    let size = layout.font_size(20);
+    draw_centered(
+        &label,
+        theme.accent,
+        size,
+        layout.filter_label(),
+        layout.density,
+        glyphs,
+        c,
+        g,
+    );
+}
+
+// The splash screen that is up while the very first schedule is downloading. It's just a
+// quarter circle chasing its tail around the center of the screen.
+fn draw_loading(
+    since: Instant,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let radius = layout.length(40.0);
+    let center = [layout.width / 2.0, layout.height / 2.0];
+    let start = since.elapsed().as_secs_f64() * 2.0 * std::f64::consts::PI;
+    graphics::CircleArc::new(
+        theme.text,
+        layout.length(4.0),
+        start,
+        start + std::f64::consts::FRAC_PI_2,
+    )
+    .draw(
+        [
+            center[0] - radius,
+            center[1] - radius,
+            radius * 2.0,
+            radius * 2.0,
+        ],
+        &graphics::DrawState::default(),
+        c.transform,
+        g,
+    );
+    render::text(
+        theme.text,
+        layout.font_size(16),
+        &locale::text("loading"),
+        glyphs,
+        c.transform
+            .trans(center[0] - radius, center[1] + radius * 2.0),
+        layout.density,
+        g,
+    );
+}
+
+// The headlines and recaps come back in the same language as everything else on screen, for
+// the stories that MLB has written in it.
+fn schedule_request(
+    date: NaiveDate,
+    days: u32,
+    sport_id: u32,
+    language: Language,
+) -> api::ScheduleRequest {
+    api::ScheduleRequest::lineup(date, days, sport_id).language(language.code())
+}
+
+// A schedule download that is (maybe) still in flight, along with the date that it is for.
+//
+// Dropping this is the cancellation mechanism here. If the user mashes the up arrow then only
+// the most recent request is kept around, and the rest are called off wherever they happen to be.
+struct Pending {
+    rx: crossbeam_channel::Receiver<(NaiveDate, api::APIResult<api::Schedule>)>,
+    // Never used directly, it's just here to call the request off when we go away.
+    _cancel: api::Cancel,
+}
+
+impl Pending {
+    // A schedule that is already in hand, for going through the same motions as any other.
+    fn ready(date: NaiveDate, schedule: api::Schedule) -> Pending {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let _ = tx.send((date, Ok(schedule)));
+        let (cancel, _) = api::cancellation();
+        Pending {
+            rx,
+            _cancel: cancel,
+        }
+    }
+
+    fn try_recv(
+        &self,
+    ) -> Result<(NaiveDate, api::APIResult<api::Schedule>), crossbeam_channel::TryRecvError> {
+        self.rx.try_recv()
+    }
+}
+
+// How many days to prefetch on either side of the given number of days on screen. It's whole
+// walks of the calendar, and however many of those it takes to make at least three days.
+fn margin(days: u32) -> u32 {
+    const DAYS: u32 = 3;
+    let days = days.max(1);
+    days * DAYS.div_ceil(days)
+}
+
+// How many days a window is in all, given the number of days on screen.
+fn window_length(days: u32) -> u32 {
+    days + 2 * margin(days)
+}
+
+// Fires off the schedule request for the given date in the background. This works the same
+// way that lineup::Photo does - poll the receiver on each pass of the event loop.
+fn fetch(
+    date: NaiveDate,
+    days: u32,
+    sport_id: u32,
+    language: Language,
+    timeout: Duration,
+) -> Pending {
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let (cancel, cancelled) = api::cancellation();
+    let request = async move {
+        let url = schedule_request(date, days, sport_id, language).url();
+        let schedule = api::Schedule::try_from_within(url, timeout, cancelled).await;
+        let _ = tx.send((date, schedule));
+    };
+    api::spawn(request.instrument(info_span!("schedule", %date)));
+    Pending {
+        rx,
+        _cancel: cancel,
+    }
+}
+
+// How often to refresh the schedule. That's however often the user asked for, unless only some
+// of the games are showing by how far along they are, in which case it's at least once a minute
+// so that games come and go not long after they start and finish.
+fn refresh_every(refresh: Duration, progress: Progress) -> Duration {
+    match progress {
+        Progress::All => refresh,
+        _ => refresh.min(Duration::from_secs(60)),
+    }
+}
+
+// Re-downloads the schedule for the given date every so often, for as long as the Pending
+// is kept around. Game days are a moving target after all.
+fn refresh(
+    date: NaiveDate,
+    days: u32,
+    sport_id: u32,
+    language: Language,
+    timeout: Duration,
+    every: Duration,
+) -> Pending {
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let (cancel, mut cancelled) = api::cancellation();
+    let refreshing = async move {
+        let mut ticks = tokio::time::interval_at(tokio::time::Instant::now() + every, every);
+        loop {
+            tokio::select! {
+                _ = ticks.tick() => (),
+                _ = cancelled.wait() => return,
+            }
+            let url = schedule_request(date, days, sport_id, language).url();
+            let schedule = api::Schedule::try_from_within(url, timeout, cancelled.clone()).await;
+            if let Err(crossbeam_channel::TrySendError::Disconnected(_)) =
+                tx.try_send((date, schedule))
+            {
+                return;
+            }
+        }
+    };
+    api::spawn(refreshing.instrument(info_span!("refresh", %date)));
+    Pending {
+        rx,
+        _cancel: cancel,
+    }
+}
+
+// The same as fetch, except that if the user didn't ask for a particular day, and today is an
+// off day (or the schedule simply hasn't been posted yet), then yesterday's slate is a far more
+// interesting thing to open up to than an empty screen. The same goes for the days before a
+// whole range of days, should that come up empty.
+//
+// This also gets the given number of attempts at it, rather than just the one, with a backoff
+// in between them. Only the last failure makes it back out.
+fn fetch_initial(
+    requested: Option<NaiveDate>,
+    days: u32,
+    sport_id: u32,
+    language: Language,
+    timeout: Duration,
+    attempts: u32,
+) -> Pending {
+    const BACKOFF: Duration = Duration::from_secs(1);
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    let (cancel, mut cancelled) = api::cancellation();
+    let request = async move {
+        let mut attempt = 1;
+        loop {
+            let (date, schedule) = initial(
+                requested,
+                days,
+                sport_id,
+                language,
+                timeout,
+                cancelled.clone(),
+            )
+            .await;
+            match schedule {
+                Err(err) if attempt < attempts => {
+                    warn!(%date, error = %err, attempt, "Failed to retrieve the schedule");
+                    tokio::select! {
+                        _ = tokio::time::delay_for(download::backoff(BACKOFF, attempt)) => (),
+                        _ = cancelled.wait() => return,
+                    }
+                    attempt += 1;
+                }
+                schedule => {
+                    let _ = tx.send((date, schedule));
+                    return;
+                }
+            }
+        }
+    };
+    api::spawn(request.instrument(info_span!("schedule", ?requested)));
+    Pending {
+        rx,
+        _cancel: cancel,
+    }
+}
+
+/// The schedule that the carousel would open up to with the given config, fetched the once
+/// and without a window in sight. This is for --export.
+pub async fn first_schedule(config: &Config) -> (NaiveDate, api::APIResult<api::Schedule>) {
+    let (_cancel, cancelled) = api::cancellation();
+    initial(
+        config.date,
+        config.days,
+        config.sport_id,
+        config.language,
+        config.timeout,
+        cancelled,
+    )
+    .await
+}
+
+// One attempt at what fetch_initial is after.
+async fn initial(
+    requested: Option<NaiveDate>,
+    days: u32,
+    sport_id: u32,
+    language: Language,
+    timeout: Duration,
+    cancelled: api::Cancelled,
+) -> (NaiveDate, api::APIResult<api::Schedule>) {
+    let mut date = requested.unwrap_or_else(|| chrono::Local::now().naive_local().date());
+    let url = schedule_request(date, days, sport_id, language).url();
+    let mut schedule = api::Schedule::try_from_within(url, timeout, cancelled.clone()).await;
+    if requested.is_none() {
+        if let Ok(true) = schedule.as_ref().map(api::Schedule::is_empty) {
+            date -= chrono::Duration::days(days as i64);
+            let url = schedule_request(date, days, sport_id, language).url();
+            schedule = api::Schedule::try_from_within(url, timeout, cancelled).await;
+        }
+    }
+    (date, schedule)
+}
@@ -0,0 +1,675 @@
+use super::{draw_footer, fit_lines, open_in_browser, wrap, Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command, Keymap};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use graphics::character::CharacterCache;
+use mlb::api::live::{Play, Record, Weather};
+use mlb::lineup::{self, Game, Grid, Highlights, Live, Pitcher, Video};
+use piston_window::Transformed;
+
+/// The details of whichever game was focused in the carousel when it was opened up, along with
+/// a row of its highlights to scroll through.
+pub struct Detail {
+    // The focused game's highlights, once there's a game to have them (and unless they'd
+    // spoil how it went).
+    highlights: Option<Highlights>,
+    // The focused game's live feed, for games that are over. Games in progress have their own.
+    feed: Option<Live>,
+}
+
+impl Detail {
+    pub fn new() -> Detail {
+        Detail {
+            highlights: None,
+            feed: None,
+        }
+    }
+
+    // The condensed game or the recap, whichever goes with the given action, so long as the
+    // game is over.
+    fn video(&self, action: Action, state: &mut State) -> Option<&Video> {
+        let game = state.current()?.schedule.focused()?;
+        let highlights = self
+            .highlights
+            .as_ref()
+            .filter(|_| game.state == lineup::State::Final)?;
+        match action {
+            Action::Condensed => highlights.condensed(),
+            Action::Recap => highlights.recap(),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Detail {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Screen for Detail {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) => Transition::Pop,
+            // Left and right scroll through the highlights rather than through the games.
+            Command::Action(Action::Left) => {
+                if let Some(highlights) = &mut self.highlights {
+                    highlights.left();
+                }
+                Transition::Stay
+            }
+            Command::Action(Action::Right) => {
+                if let Some(highlights) = &mut self.highlights {
+                    highlights.right();
+                }
+                Transition::Stay
+            }
+            Command::Action(action @ Action::Condensed)
+            | Command::Action(action @ Action::Recap) => {
+                if let Some(url) = self.video(action, state).map(|video| video.url.clone()) {
+                    open_in_browser(&url, "video-failed", state);
+                }
+                Transition::Stay
+            }
+            // These don't have anything to do with which game is focused, so they work from
+            // here just as well as they do from the carousel.
+            Command::Action(Action::Refresh)
+            | Command::Action(Action::Spoilers)
+            | Command::Action(Action::Reveal)
+            | Command::Action(Action::Export)
+            | Command::Action(Action::Story)
+            | Command::Action(Action::Fullscreen)
+            | Command::Action(Action::Debug) => Transition::Pass,
+            // Same game, same clubs, so the carousel can open their page from here too.
+            Command::Action(Action::Team) => Transition::Pass,
+            Command::Action(Action::Transactions) => Transition::Pass,
+            _ => Transition::Stay,
+        }
+    }
+
+    // A refresh can take the game out from under us, in which case there's nothing left to
+    // do but go back to the carousel. Otherwise the highlights are started (or stopped, if
+    // spoilers are turned back off) to go along with whatever the game is showing.
+    fn update(&mut self, state: &mut State) -> Transition {
+        let schedule = match state.current() {
+            Some(row) if row.schedule.games.focused().is_some() => &mut row.schedule,
+            _ => return Transition::Pop,
+        };
+        let previous = self.highlights.take();
+        let started = previous.is_none();
+        self.highlights = schedule.highlights(previous);
+        self.feed = schedule.feed(self.feed.take());
+        if let Some(feed) = &mut self.feed {
+            state.dirty |= feed.poll();
+        }
+        if let Some(highlights) = &mut self.highlights {
+            state.dirty |= started | highlights.poll();
+        }
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        let (layout, assets, spoiler_free) = (&state.layout, &mut state.assets, state.spoiler_free);
+        let (theme, keymap) = (&state.config.theme, &state.config.keymap);
+        let schedule = match &mut state.schedule {
+            Some(schedule) => schedule,
+            None => return,
+        };
+        let copyright = schedule.copyright().to_string();
+        if let Some(game) = schedule.current().and_then(|row| row.schedule.focused()) {
+            let hidden = spoiler_free && game.spoils();
+            let finished = game.state == lineup::State::Final;
+            let linescore = game
+                .linescore()
+                .or_else(|| self.feed.as_mut().and_then(Live::get))
+                .cloned();
+            let scoring_plays = game
+                .scoring_plays()
+                .or_else(|| self.feed.as_mut().and_then(Live::scoring_plays))
+                .map(<[Play]>::to_vec)
+                .filter(|_| !hidden)
+                .unwrap_or_default();
+            let teams = (
+                game.away.abbreviation.clone(),
+                game.home.abbreviation.clone(),
+            );
+            let grid = match (hidden, linescore) {
+                (false, Some(linescore)) => Some(Grid::new(
+                    &linescore,
+                    &game.away.abbreviation,
+                    &game.home.abbreviation,
+                    finished,
+                )),
+                _ => None,
+            };
+            // Where the game is and what it's like out there don't give anything away, unlike
+            // who got the win.
+            let weather = game
+                .weather()
+                .or_else(|| self.feed.as_mut().and_then(Live::weather))
+                .and_then(conditions);
+            let decisions = match hidden {
+                true => None,
+                false => decisions(game, self.feed.as_mut()),
+            };
+            let notes: Vec<String> = game
+                .venue
+                .clone()
+                .into_iter()
+                .chain(weather)
+                .chain(decisions)
+                .chain(broadcasts(game))
+                .collect();
+            let bottom = draw_detail(
+                game,
+                hidden,
+                &notes,
+                layout,
+                theme,
+                &mut assets.textures,
+                &mut assets.glyphs,
+                c,
+                g,
+            );
+            if !scoring_plays.is_empty() {
+                draw_scoring_plays(
+                    &scoring_plays,
+                    &teams,
+                    bottom,
+                    layout,
+                    theme,
+                    &mut assets.glyphs,
+                    c,
+                    g,
+                );
+            }
+            // The linescore and then the videos go underneath the photo, one after the other.
+            let mut top = layout.length(100.0) + layout.large()[1] + layout.length(50.0);
+            if let Some(grid) = &grid {
+                top = draw_linescore(grid, top, layout, theme, &mut assets.glyphs, c, g);
+            }
+            if let (true, Some(highlights)) = (finished, &self.highlights) {
+                draw_videos(
+                    highlights,
+                    top,
+                    layout,
+                    theme,
+                    keymap,
+                    &mut assets.glyphs,
+                    c,
+                    g,
+                );
+            }
+            if let Some(highlights) = &mut self.highlights {
+                draw_highlights(
+                    highlights,
+                    layout,
+                    theme,
+                    &mut assets.textures,
+                    &mut assets.glyphs,
+                    c,
+                    g,
+                );
+            }
+            draw_footer(&copyright, layout, theme, &mut assets.glyphs, c, g);
+        }
+    }
+}
+
+// Who got the win, the loss, and the save, E.G. "W: Lopez (9-14)    L: Porcello (13-12)". Their
+// records come out of the game's feed, so until that's in it's only their names.
+fn decisions(game: &Game, mut feed: Option<&mut Live>) -> Option<String> {
+    let (winner, loser) = (game.winner.as_ref()?, game.loser.as_ref()?);
+    let mut pitcher = |id: &str, pitcher: &Pitcher, record: fn(Record) -> String| {
+        let name = match feed.as_mut().and_then(|feed| feed.record(pitcher.id)) {
+            Some(stats) => format!("{} ({})", pitcher.surname(), record(stats)),
+            None => pitcher.surname().to_string(),
+        };
+        locale::text_with(id, &[("pitcher", &name)])
+    };
+    let won_lost = |stats: Record| format!("{}-{}", stats.wins, stats.losses);
+    let mut decisions = vec![
+        pitcher("decision-win", winner, won_lost),
+        pitcher("decision-loss", loser, won_lost),
+    ];
+    if let Some(save) = &game.save {
+        decisions.push(pitcher("decision-save", save, |stats| {
+            stats.saves.to_string()
+        }));
+    }
+    Some(decisions.join("    "))
+}
+
+// Who is carrying the game, a line for the national broadcasts and then one for each club's
+// market, E.G. "BOS: NESN (TV), WEEI 850 (AM)". Nobody in particular means no lines at all.
+fn broadcasts(game: &Game) -> Vec<String> {
+    let broadcasts = &game.broadcasts;
+    let national = Some(&broadcasts.national)
+        .filter(|outlets| !outlets.is_empty())
+        .map(|outlets| {
+            locale::text_with("broadcasts-national", &[("outlets", &outlets.join(", "))])
+        });
+    let markets = [
+        (&game.away.abbreviation, &broadcasts.away),
+        (&game.home.abbreviation, &broadcasts.home),
+    ];
+    let markets = markets
+        .iter()
+        .filter(|(_, outlets)| !outlets.is_empty())
+        .map(|(team, outlets)| {
+            locale::text_with(
+                "broadcasts-market",
+                &[("team", team), ("outlets", &outlets.join(", "))],
+            )
+        });
+    national.into_iter().chain(markets).collect()
+}
+
+// What it was like out at first pitch, E.G. "Partly Cloudy, 72°F at first pitch, wind 9 mph, Out
+// To CF", or None if the API didn't say.
+fn conditions(weather: &Weather) -> Option<String> {
+    let temperature = weather
+        .temp
+        .as_ref()
+        .map(|degrees| locale::text_with("weather-temperature", &[("degrees", degrees)]));
+    let wind = weather
+        .wind
+        .as_ref()
+        .map(|wind| locale::text_with("weather-wind", &[("wind", wind)]));
+    let conditions: Vec<String> = weather
+        .condition
+        .clone()
+        .into_iter()
+        .chain(temperature)
+        .chain(wind)
+        .collect();
+    match conditions.is_empty() {
+        true => None,
+        false => Some(conditions.join(", ")),
+    }
+}
+
+// The large photo sits on the left with the full headline, score, ballpark, weather, pitching
+// decisions, broadcasts, and subhead stacked up to the right of it. A game that is hidden for the sake of spoilers gets
+// the MLB logo, its matchup, and its first pitch instead. Returns how far down the text went.
+fn draw_detail(
+    game: &mut Game,
+    hidden: bool,
+    notes: &[String],
+    layout: &layout::Layout,
+    theme: &Theme,
+    textures: &mut render::TextureCache,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) -> f64 {
+    let margin = layout.length(100.0);
+    piston_window::rectangle(theme.scrim, layout.fullscreen(), c.transform, g);
+    let image = match hidden {
+        true => lineup::large_logo(),
+        false => game.large(),
+    };
+    // The photo was decoded at however many pixels the screen has for it, which isn't the same
+    // thing as how big it is in the window, so it goes by the layout rather than by the photo.
+    let [image_width, image_height] = layout.large();
+    let rect = [margin, margin, image_width, image_height];
+    render::draw_image(&image, rect, textures, theme, c, g);
+    let left = margin * 2.0 + image_width;
+    let width = layout.width - left - margin;
+    let (headline, matchup, body) = (
+        layout.font_size(32),
+        layout.font_size(24),
+        layout.font_size(20),
+    );
+    // The teaser is the matchup without the score, so a hidden game doesn't need it twice.
+    let (title, matchup_line, subhead) = match hidden {
+        true => (
+            game.teaser().to_string(),
+            None,
+            locale::text("spoilers-hidden"),
+        ),
+        false => (
+            game.headline.clone(),
+            Some(game.matchup()),
+            game.subhead.clone(),
+        ),
+    };
+    let mut top = margin + layout.length(32.0);
+    for line in wrap(&title, headline, width, glyphs) {
+        render::text(
+            theme.text,
+            headline,
+            &line,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+        top += layout.length(48.0);
+    }
+    let first_pitch = game
+        .first_pitch
+        .as_ref()
+        .map(|time| locale::text_with("first-pitch", &[("time", time)]));
+    for line in matchup_line.iter().chain(first_pitch.iter()) {
+        top += layout.length(40.0);
+        render::text(
+            theme.text,
+            matchup,
+            line,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+    }
+    for note in notes {
+        top += layout.length(40.0);
+        render::text(
+            theme.text,
+            body,
+            note,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+    }
+    top += layout.length(64.0);
+    for line in wrap(&subhead, body, width, glyphs) {
+        render::text(
+            theme.text,
+            body,
+            &line,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+        top += layout.length(30.0);
+    }
+    top
+}
+
+// How the runs scored, one play to a line, under the rest of the text to the right of the
+// photo: the half inning, the score after the play, and as much of what happened as fits. There
+// isn't room for every run of a slugfest above the highlights, so the ones that don't fit are
+// only counted.
+fn draw_scoring_plays(
+    plays: &[Play],
+    (away, home): &(String, String),
+    mut top: f64,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let margin = layout.length(100.0);
+    let left = margin * 2.0 + layout.large()[0];
+    let width = layout.width - left - margin;
+    let (heading, size, line) = (
+        layout.font_size(20),
+        layout.font_size(16),
+        layout.length(28.0),
+    );
+    let bottom = layout.highlights(1)[0][1] - layout.length(50.0);
+    top += layout.length(34.0);
+    render::text(
+        theme.text,
+        heading,
+        &locale::text("scoring-plays"),
+        glyphs,
+        c.transform.trans(left, top),
+        layout.density,
+        g,
+    );
+    let rows: Vec<(String, String, &str)> = plays
+        .iter()
+        .map(|play| {
+            let inning = play.about.inning.to_string();
+            let half = match play.about.is_top_inning {
+                true => "inning-top",
+                false => "inning-bottom",
+            };
+            let score = format!(
+                "{} {}, {} {}",
+                away, play.result.away_score, home, play.result.home_score
+            );
+            (
+                locale::text_with(half, &[("inning", &inning)]),
+                score,
+                play.result.description.as_str(),
+            )
+        })
+        .collect();
+    // As many lines as there's room for, with the last of them given over to counting whatever
+    // else there is when that's not all of them.
+    let room = (((bottom - top) / line).floor().max(0.0)) as usize;
+    let shown = match rows.len() > room {
+        true => room.saturating_sub(1),
+        false => rows.len(),
+    };
+    // The innings and the scores each line up in a column, as wide as the widest one in it.
+    let (mut innings, mut scores) = (0.0f64, 0.0f64);
+    for (inning, score, _) in &rows[..shown] {
+        innings = innings.max(glyphs.width(size, inning).unwrap_or(0.0));
+        scores = scores.max(glyphs.width(size, score).unwrap_or(0.0));
+    }
+    let gap = layout.length(20.0);
+    for (inning, score, description) in &rows[..shown] {
+        top += line;
+        let columns = [
+            (left, inning.as_str(), theme.small_print),
+            (left + innings + gap, score.as_str(), theme.text),
+        ];
+        for (x, text, color) in columns.iter() {
+            render::text(
+                *color,
+                size,
+                text,
+                glyphs,
+                c.transform.trans(*x, top),
+                layout.density,
+                g,
+            );
+        }
+        let x = left + innings + scores + gap * 2.0;
+        for text in fit_lines(description, size, left + width - x, 1, glyphs) {
+            render::text(
+                theme.text,
+                size,
+                &text,
+                glyphs,
+                c.transform.trans(x, top),
+                layout.density,
+                g,
+            );
+        }
+    }
+    if shown < rows.len() {
+        let count = (rows.len() - shown).to_string();
+        render::text(
+            theme.small_print,
+            size,
+            &locale::text_with("scoring-more", &[("count", &count)]),
+            glyphs,
+            c.transform.trans(left, top + line),
+            layout.density,
+            g,
+        );
+    }
+}
+
+// The inning-by-inning linescore, starting at the given height under the photo, returning how
+// far down it went. Each column is as wide as the widest thing in it (which OpenSans makes
+// different for every digit), with the teams lined up on the left and everything else centered.
+fn draw_linescore(
+    grid: &Grid,
+    mut top: f64,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) -> f64 {
+    let size = layout.font_size(20);
+    let rows = grid.rows();
+    let widths: Vec<f64> = (0..grid.header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| glyphs.width(size, &row[column]).unwrap_or(0.0))
+                .fold(0.0, f64::max)
+        })
+        .collect();
+    let (gap, left) = (layout.length(20.0), layout.length(100.0));
+    for (index, row) in rows.iter().enumerate() {
+        // The header is there to be read past, so it's in the small print's color.
+        let color = match index {
+            0 => theme.small_print,
+            _ => theme.text,
+        };
+        let mut x = left;
+        for (column, (cell, width)) in row.iter().zip(&widths).enumerate() {
+            // The totals get a little more room, to set them apart from the innings.
+            if column == grid.totals() {
+                x += gap;
+            }
+            let offset = match column {
+                0 => 0.0,
+                _ => (width - glyphs.width(size, cell).unwrap_or(0.0)) / 2.0,
+            };
+            render::text(
+                color,
+                size,
+                cell,
+                glyphs,
+                c.transform.trans(x + offset, top),
+                layout.density,
+                g,
+            );
+            x += width + gap;
+        }
+        top += layout.length(32.0);
+    }
+    top + layout.length(18.0)
+}
+
+// What to press for the condensed game and the recap, under the photo, for whichever of them
+// have been put up (and have a key to press). How long each one runs goes off to the side.
+fn draw_videos(
+    highlights: &Highlights,
+    mut top: f64,
+    layout: &layout::Layout,
+    theme: &Theme,
+    keymap: &Keymap,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let videos = [
+        (Action::Condensed, "condensed-hint", highlights.condensed()),
+        (Action::Recap, "recap-hint", highlights.recap()),
+    ];
+    let (size, small_print) = (layout.font_size(20), layout.font_size(14));
+    let left = layout.length(100.0);
+    for (action, message, video) in videos.iter() {
+        let (video, key) = match (video, keymap.key(*action)) {
+            (Some(video), Some(key)) => (video, key),
+            _ => continue,
+        };
+        let hint = locale::text_with(message, &[("key", &key)]);
+        render::text(
+            theme.text,
+            size,
+            &hint,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+        if let Some(duration) = &video.duration {
+            let width = glyphs.width(size, &hint).unwrap_or(0.0);
+            render::text(
+                theme.small_print,
+                small_print,
+                duration,
+                glyphs,
+                c.transform.trans(left + width + layout.length(16.0), top),
+                layout.density,
+                g,
+            );
+        }
+        top += layout.length(36.0);
+    }
+}
+
+// The highlights in a row along the bottom, each with its title and how long it runs underneath
+// it, and the one that the cursor is on outlined. A game without any doesn't get the row at all.
+fn draw_highlights(
+    highlights: &mut Highlights,
+    layout: &layout::Layout,
+    theme: &Theme,
+    textures: &mut render::TextureCache,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    if highlights.is_empty() {
+        return;
+    }
+    let label = match highlights.loading() {
+        true => locale::text("highlights-loading"),
+        false => locale::text("highlights"),
+    };
+    // Plenty of room for a label even when there isn't anything to put under it yet.
+    let rects = layout.highlights(Highlights::PAGE_SIZE);
+    let [x, y] = layout.strip_label(rects[0]);
+    render::text(
+        theme.text,
+        layout.font_size(20),
+        &label,
+        glyphs,
+        c.transform.trans(x, y),
+        layout.density,
+        g,
+    );
+    let (title, small_print) = (layout.font_size(16), layout.font_size(14));
+    for (clip, rect) in highlights.page().into_iter().zip(rects) {
+        render::draw_image(&clip.image, rect, textures, theme, c, g);
+        if clip.focused {
+            graphics::Rectangle::new_border(theme.accent, layout.length(4.0)).draw(
+                rect,
+                &graphics::DrawState::default(),
+                c.transform,
+                g,
+            );
+        }
+        let [x, y] = layout.highlight_title(rect);
+        for line in fit_lines(clip.title, title, rect[2], 1, glyphs) {
+            render::text(
+                theme.text,
+                title,
+                &line,
+                glyphs,
+                c.transform.trans(x, y),
+                layout.density,
+                g,
+            );
+        }
+        if let Some(duration) = clip.duration {
+            render::text(
+                theme.small_print,
+                small_print,
+                duration,
+                glyphs,
+                c.transform.trans(x, y + layout.length(24.0)),
+                layout.density,
+                g,
+            );
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use super::{Carousel, Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command};
+use crate::locale;
+use crate::render;
+use mlb::api;
+use piston_window::Transformed;
+
+/// What takes over when the very first schedule couldn't be had, or when something panicked.
+/// It displays what went wrong until the user either backs out of it, which quits, or asks to
+/// try again, which goes back to the carousel's loading screen for another (more patient) go
+/// at it.
+///
+/// I am aware that the text needs to be wrapped around as the error messages fall off the
+/// screen. Wrapping text into columns is not difficult, however you have to handle the newlines
+/// manually within this text renderer which I did not have the time to do.
+pub struct Error {
+    text: String,
+}
+
+impl Error {
+    pub fn new(err: api::APIError) -> Error {
+        Error {
+            text: format!("{}", err),
+        }
+    }
+
+    /// For a panic, with whatever it had to say for itself.
+    pub fn panicked(message: String) -> Error {
+        Error {
+            text: locale::text_with("panicked", &[("message", &message)]),
+        }
+    }
+}
+
+impl Screen for Error {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) => Transition::Exit(1),
+            // Refresh is what this is everywhere else, but there's nothing to reveal here and
+            // R is what the screen says to press.
+            Command::Action(Action::Refresh) | Command::Action(Action::Reveal) => {
+                Transition::Replace(Box::new(Carousel::retrying(state)))
+            }
+            _ => Transition::Stay,
+        }
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        let layout = &state.layout;
+        let [x, y] = layout.point(0.0, 500.0);
+        render::text(
+            state.config.theme.text,
+            layout.font_size(16),
+            &self.text,
+            &mut state.assets.glyphs,
+            c.transform.trans(x, y),
+            layout.density,
+            g,
+        );
+        let [x, y] = layout.point(0.0, 540.0);
+        render::text(
+            state.config.theme.text,
+            layout.font_size(20),
+            &locale::text("error-hint"),
+            &mut state.assets.glyphs,
+            c.transform.trans(x, y),
+            layout.density,
+            g,
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use super::{Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use chrono::NaiveDate;
+use piston_window::Transformed;
+
+/// A box over top of the carousel for typing in a date to go to, E.G. 2021-10-03.
+///
+/// Like the search box, everything that is typed goes into it for as long as it is open. What's
+/// typed is checked as it goes, and anything that could never turn into a date is pointed out
+/// right away, under the box. Enter goes to the date, or says what's wrong with it.
+#[derive(Default)]
+pub struct GoTo {
+    date: String,
+    // What's wrong with what has been typed so far, already written out, if anything.
+    problem: Option<String>,
+}
+
+// What a date looks like.
+const FORMAT: &str = "YYYY-MM-DD";
+
+impl GoTo {
+    // Points out anything typed so far that has no chance of being a date, which is anything
+    // but digits and dashes, or too much of them. Anything else might be a date yet.
+    fn check(&mut self) {
+        let typo = self.date.len() > FORMAT.len()
+            || self.date.chars().any(|c| !c.is_ascii_digit() && c != '-');
+        self.problem = match typo {
+            true => Some(locale::text("goto-format")),
+            false => None,
+        };
+    }
+}
+
+impl Screen for GoTo {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) => return Transition::Pop,
+            Command::Action(Action::Select) => match parse(&self.date) {
+                Ok(date) => {
+                    state.jump = Some((date, None));
+                    return Transition::Pop;
+                }
+                Err(problem) => self.problem = Some(problem),
+            },
+            Command::Text(typed) => {
+                self.date.push_str(&typed);
+                self.check();
+            }
+            Command::Erase => {
+                self.date.pop();
+                self.check();
+            }
+            _ => (),
+        }
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        draw_goto(
+            &self.date,
+            self.problem.as_deref(),
+            &state.layout,
+            &state.config.theme,
+            &mut state.assets.glyphs,
+            c,
+            g,
+        );
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
+
+// Reads a date the same way that --date does, or else says (in the user's language) what's
+// wrong with it: either it isn't shaped like a date at all, or it is but there's no such day
+// (E.G. 2021-02-30).
+fn parse(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+        let shaped = date.len() == FORMAT.len()
+            && date.chars().zip(FORMAT.chars()).all(|(c, f)| match f {
+                '-' => c == '-',
+                _ => c.is_ascii_digit(),
+            });
+        match shaped {
+            true => locale::text_with("goto-no-such-day", &[("date", date)]),
+            false => locale::text("goto-format"),
+        }
+    })
+}
+
+// The same box as the search goes in, with whatever's wrong underneath it in the alert color.
+fn draw_goto(
+    date: &str,
+    problem: Option<&str>,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let size = layout.font_size(24);
+    let prompt_box = layout.search_box();
+    piston_window::rectangle(theme.scrim, prompt_box, c.transform, g);
+    let [x, y] = layout.search_text(prompt_box);
+    render::text(
+        theme.text,
+        size,
+        &locale::text_with("goto", &[("date", date)]),
+        glyphs,
+        c.transform.trans(x, y),
+        layout.density,
+        g,
+    );
+    if let Some(problem) = problem {
+        let below = [
+            prompt_box[0],
+            prompt_box[1] + prompt_box[3],
+            prompt_box[2],
+            prompt_box[3],
+        ];
+        piston_window::rectangle(theme.scrim, below, c.transform, g);
+        let [x, y] = layout.search_text(below);
+        render::text(
+            theme.alert,
+            size,
+            problem,
+            glyphs,
+            c.transform.trans(x, y),
+            layout.density,
+            g,
+        );
+    }
+}
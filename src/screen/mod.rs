@@ -0,0 +1,197 @@
+use crate::app::State;
+use crate::input;
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use chrono::NaiveDate;
+use graphics::character::CharacterCache;
+use piston_window::Transformed;
+use std::time::Instant;
+use tracing::{info, warn};
+
+mod calendar;
+mod carousel;
+mod detail;
+mod error;
+mod goto;
+mod player;
+mod search;
+mod team;
+mod transactions;
+mod week;
+
+pub use calendar::Calendar;
+pub use carousel::{first_schedule, Carousel};
+pub use detail::Detail;
+pub use error::Error;
+pub use goto::GoTo;
+pub use player::Player;
+pub use search::Search;
+pub use team::Team;
+pub use transactions::Transactions;
+pub use week::Week;
+
+/// One of the things that can be on screen, such as the carousel or a game's details.
+///
+/// Screens are stacked on top of one another by the App. The one on top is what the user is
+/// looking at and what they are poking at, and backing out of it pops it off of the stack to
+/// reveal whatever was underneath it.
+pub trait Screen {
+    /// Reacts to something that the user did. Anything that this screen doesn't know what to
+    /// do with can be passed down to the screen below it.
+    fn handle_input(&mut self, command: input::Command, state: &mut State) -> Transition;
+
+    /// Called on every pass of the event loop, for every screen in the stack and not just the
+    /// one on top, since whatever is underneath still has to keep up with the network.
+    fn update(&mut self, _state: &mut State) -> Transition {
+        Transition::Stay
+    }
+
+    /// Draws the screen over top of the background (and over top of the screen below, if this
+    /// is an overlay).
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d);
+
+    /// Whether or not the screen below this one still shows through it.
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
+
+/// What a screen would like to become of the stack.
+pub enum Transition {
+    /// Nothing changes.
+    Stay,
+    /// This screen had nothing to do with that, so let the screen below it have a go.
+    Pass,
+    /// Puts a new screen on top.
+    Push(Box<dyn Screen>),
+    /// Takes the top screen off.
+    Pop,
+    /// Throws out the whole stack in favor of the given screen.
+    Replace(Box<dyn Screen>),
+    /// Closes the window, with the given exit status.
+    Exit(i32),
+}
+
+// Greedily breaks the given text up into lines that are no wider than width when rendered
+// at the given font size. A single word that is wider than the whole line just gets a line
+// to itself and hangs off the edge.
+fn wrap<C: CharacterCache>(text: &str, size: u32, width: f64, glyphs: &mut C) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if line.is_empty() {
+            line.push_str(word);
+            continue;
+        }
+        let candidate = format!("{} {}", line, word);
+        if glyphs.width(size, &candidate).unwrap_or(0.0) > width {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        } else {
+            line = candidate;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+// Draws a single line of text with its middle at the given point.
+fn draw_centered(
+    text: &str,
+    color: [f32; 4],
+    size: u32,
+    [x, y]: [f64; 2],
+    density: f64,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let width = glyphs.width(size, text).unwrap_or(0.0);
+    render::text(
+        color,
+        size,
+        text,
+        glyphs,
+        c.transform.trans(x - width / 2.0, y),
+        density,
+        g,
+    );
+}
+
+// The copyright notice, in small print along the bottom. It is a long one, so it gets wrapped
+// onto a second line, and anything that doesn't fit on two lines is cut off with an ellipsis.
+fn draw_footer(
+    copyright: &str,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let [x, y, width, _] = layout.footer();
+    let size = layout.font_size(11);
+    for (index, line) in fit_lines(copyright, size, width, 2, glyphs)
+        .iter()
+        .enumerate()
+    {
+        let top = y + layout.length(12.0 + 14.0 * index as f64);
+        render::text(
+            theme.small_print,
+            size,
+            line,
+            glyphs,
+            c.transform.trans(x, top),
+            layout.density,
+            g,
+        );
+    }
+}
+
+// Wraps the given text and trims it down to however many lines there's room for, E.G. in the
+// footer or underneath a highlight.
+fn fit_lines<C: CharacterCache>(
+    text: &str,
+    size: u32,
+    width: f64,
+    max_lines: usize,
+    glyphs: &mut C,
+) -> Vec<String> {
+    let mut lines = wrap(text, size, width, glyphs);
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        let last = lines.last_mut().unwrap();
+        // Make room for the ellipsis a word at a time.
+        while glyphs.width(size, &format!("{}…", last)).unwrap_or(0.0) > width {
+            match last.rfind(' ') {
+                Some(space) => last.truncate(space),
+                None => break,
+            }
+        }
+        last.push('…');
+    }
+    lines
+}
+
+// Opens the given page in the browser, putting up a banner with the given message should that
+// not work out. The browser is whatever the system says it is. Nothing here waits on it past it
+// being started, and once it's up it's the browser's business.
+fn open_in_browser(url: &str, failed: &str, state: &mut State) {
+    match webbrowser::open(url) {
+        Ok(_) => info!(%url, "Opened in the browser"),
+        Err(err) => {
+            warn!(%url, error = %err, "Failed to open the browser");
+            state.banners.push(locale::text(failed), Instant::now());
+        }
+    }
+}
+
+// The API hands dates back as YYYY-MM-DD, which is a bit much to put in front of a person. The
+// message is which of the date-* messages to write it out with.
+fn pretty_date(date: &str, message: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|date| locale::date(message, date))
+        .unwrap_or_else(|_| date.to_string())
+}
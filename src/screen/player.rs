@@ -0,0 +1,248 @@
+use super::{draw_footer, Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use mlb::lineup::{self, Decoded};
+use piston_window::Transformed;
+use std::rc::Rc;
+
+/// A player's page, opened from their team's roster: their headshot, their biography, and their
+/// stat lines for the season and for their career.
+pub struct Player {
+    player: lineup::Player,
+}
+
+impl Player {
+    /// Opens the page of the player with the given id.
+    pub fn new(id: u64) -> Player {
+        Player {
+            player: lineup::Player::new(id),
+        }
+    }
+}
+
+impl Screen for Player {
+    fn handle_input(&mut self, command: Command, _state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) => Transition::Pop,
+            Command::Action(Action::Fullscreen) | Command::Action(Action::Debug) => {
+                Transition::Pass
+            }
+            _ => Transition::Stay,
+        }
+    }
+
+    fn update(&mut self, state: &mut State) -> Transition {
+        state.dirty |= self.player.poll();
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        // The headshot comes down along with every other one in the schedule, which is polled
+        // underneath us by the carousel.
+        let id = self.player.id;
+        let headshot = state.current().and_then(|row| row.schedule.headshot(id));
+        let (layout, assets, theme) = (&state.layout, &mut state.assets, &state.config.theme);
+        draw_player(
+            &self.player,
+            headshot,
+            layout,
+            theme,
+            &mut assets.textures,
+            &mut assets.glyphs,
+            c,
+            g,
+        );
+        if let Some(schedule) = &state.schedule {
+            draw_footer(
+                schedule.copyright(),
+                layout,
+                theme,
+                &mut assets.glyphs,
+                c,
+                g,
+            );
+        }
+    }
+}
+
+// The headshot sits on the left with the player's name and biography stacked up to the right of
+// it, and their stat lines go underneath, each under a heading of what it's for.
+fn draw_player(
+    player: &lineup::Player,
+    headshot: Option<Rc<Decoded>>,
+    layout: &layout::Layout,
+    theme: &Theme,
+    textures: &mut render::TextureCache,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    piston_window::rectangle(theme.scrim, layout.fullscreen(), c.transform, g);
+    let rect = layout.headshot();
+    if let Some(headshot) = headshot {
+        render::draw_image(&headshot.image(), rect, textures, theme, c, g);
+    }
+    let (headline, heading, body) = (
+        layout.font_size(32),
+        layout.font_size(24),
+        layout.font_size(20),
+    );
+    let left = rect[0] + rect[2] + layout.length(100.0);
+    let mut top = rect[1] + layout.length(32.0);
+    let card = match player.card() {
+        Some(card) => card,
+        None => {
+            let status = match player.failed() {
+                true => locale::text("player-failed"),
+                false => locale::text("loading"),
+            };
+            render::text(
+                theme.text,
+                headline,
+                &status,
+                glyphs,
+                c.transform.trans(left, top),
+                layout.density,
+                g,
+            );
+            return;
+        }
+    };
+    render::text(
+        theme.text,
+        headline,
+        &card.name,
+        glyphs,
+        c.transform.trans(left, top),
+        layout.density,
+        g,
+    );
+    top += layout.length(8.0);
+    for line in bio(card) {
+        top += layout.length(40.0);
+        render::text(
+            theme.text,
+            heading,
+            &line,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+    }
+    // The stats start under whichever runs longer, the headshot or the biography.
+    let mut top = top.max(rect[1] + rect[3]) + layout.length(40.0);
+    let left = rect[0];
+    if card.stats.is_empty() {
+        top += layout.length(32.0);
+        render::text(
+            theme.text,
+            heading,
+            &locale::text("player-no-stats"),
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+    }
+    for line in &card.stats {
+        top += layout.length(40.0);
+        render::text(
+            theme.text,
+            heading,
+            &label(line),
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+        top += layout.length(32.0);
+        render::text(
+            theme.text,
+            body,
+            &line.line,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+    }
+}
+
+// A line apiece for their number and position, their size, where they're from, and when they
+// debuted, leaving out whichever there's nothing to say about. E.G. "#22 P, bats R, throws R"
+// and "6' 5", 205 lb, age 30".
+fn bio(card: &lineup::Card) -> Vec<String> {
+    let role: Vec<String> = card
+        .number
+        .iter()
+        .map(|number| format!("#{}", number))
+        .chain(card.position.clone())
+        .collect();
+    let hands: Vec<String> = card
+        .bats
+        .iter()
+        .map(|hand| locale::text_with("player-bats", &[("hand", hand)]))
+        .chain(
+            card.throws
+                .iter()
+                .map(|hand| locale::text_with("player-throws", &[("hand", hand)])),
+        )
+        .collect();
+    let first = match (role.is_empty(), hands.is_empty()) {
+        (false, false) => Some(format!("{}, {}", role.join(" "), hands.join(", "))),
+        (false, true) => Some(role.join(" ")),
+        (true, false) => Some(hands.join(", ")),
+        (true, true) => None,
+    };
+    let size: Vec<String> =
+        card.height
+            .clone()
+            .into_iter()
+            .chain(card.weight.map(|weight| {
+                locale::text_with("player-weight", &[("pounds", &weight.to_string())])
+            }))
+            .chain(
+                card.age
+                    .map(|age| locale::text_with("player-age", &[("age", &age.to_string())])),
+            )
+            .collect();
+    let size = Some(size.join(", ")).filter(|size| !size.is_empty());
+    let date = |date| locale::date("date-month-year", date);
+    let born = match (card.born.map(date), &card.birthplace) {
+        (Some(date), Some(place)) => Some(locale::text_with(
+            "player-born-in",
+            &[("date", &date), ("place", place)],
+        )),
+        (Some(date), None) => Some(locale::text_with("player-born", &[("date", &date)])),
+        (None, Some(place)) => Some(locale::text_with("player-from", &[("place", place)])),
+        (None, None) => None,
+    };
+    let debut = card
+        .debut
+        .map(|debut| locale::text_with("player-debuted", &[("date", &date(debut))]));
+    first
+        .into_iter()
+        .chain(size)
+        .chain(born)
+        .chain(debut)
+        .collect()
+}
+
+// What a stat line is for, E.G. "2019 pitching" or "Career hitting".
+fn label(line: &lineup::StatLine) -> String {
+    match (&line.season, line.group) {
+        (Some(season), lineup::Group::Hitting) => {
+            locale::text_with("player-season-hitting", &[("season", season)])
+        }
+        (Some(season), lineup::Group::Pitching) => {
+            locale::text_with("player-season-pitching", &[("season", season)])
+        }
+        (None, lineup::Group::Hitting) => locale::text("player-career-hitting"),
+        (None, lineup::Group::Pitching) => locale::text("player-career-pitching"),
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use piston_window::Transformed;
+
+/// A search box over top of the carousel, for jumping to a team's game by typing its name.
+///
+/// Everything that is typed goes into the search for as long as the box is open. The keys that
+/// are bound to something still get their presses and releases, so the actions that they'd
+/// otherwise do are ignored until the box is out of the way.
+pub struct Search {
+    query: String,
+    // Whether or not any game matches the query.
+    found: bool,
+}
+
+impl Search {
+    /// Opens the search box with the given query already typed into it.
+    pub fn new(query: String, state: &mut State) -> Search {
+        let found = query.is_empty() || search(state, &query);
+        Search { query, found }
+    }
+}
+
+impl Screen for Search {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        match command {
+            // The cursor is already on whatever was found, so all that is left is to get the
+            // search box out of the way.
+            Command::Action(Action::Select) | Command::Action(Action::Back) => {
+                return Transition::Pop
+            }
+            Command::Text(typed) => {
+                self.query.push_str(&typed);
+                self.found = search(state, &self.query);
+            }
+            Command::Erase => {
+                self.query.pop();
+                self.found = self.query.is_empty() || search(state, &self.query);
+            }
+            _ => (),
+        }
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        draw_search(
+            &self.query,
+            self.found,
+            &state.layout,
+            &state.config.theme,
+            &mut state.assets.glyphs,
+            c,
+            g,
+        );
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
+
+// Jumps to whichever game on the focused row best matches the search, returning whether or
+// not any of them did.
+fn search(state: &mut State, query: &str) -> bool {
+    state
+        .current()
+        .is_some_and(|row| row.schedule.search(query))
+}
+
+// The search box, with a blinking-less cursor at the end of whatever has been typed so far.
+fn draw_search(
+    query: &str,
+    found: bool,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let search_box = layout.search_box();
+    piston_window::rectangle(theme.scrim, search_box, c.transform, g);
+    let text = match found {
+        true => locale::text_with("search", &[("query", query)]),
+        false => locale::text_with("search-no-matches", &[("query", query)]),
+    };
+    let [x, y] = layout.search_text(search_box);
+    render::text(
+        theme.text,
+        layout.font_size(24),
+        &text,
+        glyphs,
+        c.transform.trans(x, y),
+        layout.density,
+        g,
+    );
+}
@@ -0,0 +1,264 @@
+use super::{draw_footer, Player, Screen, Transition};
+use crate::app::State;
+use crate::config::Config;
+use crate::input::{Action, Command};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use chrono::NaiveDate;
+use mlb::lineup;
+use piston_window::Transformed;
+
+/// The page of one of the focused game's clubs: their record, how their last five games went,
+/// their next five, and their active roster. Left and right flip over to the other club, and up
+/// and down pick out a player on the roster to open up their page.
+///
+/// Each club's page is looked up the first time that it's shown, as of the day that the game is
+/// on, and held on to for flipping back.
+pub struct Team {
+    // The day that the game is on, which is what counts as today on either page.
+    date: NaiveDate,
+    // Both clubs in the game, by their id and name, with the away club first.
+    clubs: [(u32, String); 2],
+    // Which of the two is on screen.
+    showing: usize,
+    pages: [Option<lineup::Team>; 2],
+    // Which player on the roster is picked out, if anybody is yet.
+    picked: Option<usize>,
+}
+
+impl Team {
+    /// Opens the page of the focused game's club with the given id or, for None, whichever of
+    /// the two goes with the focused game (the one last searched for, or else the home club).
+    /// None if there's no game focused, or if neither club goes by that id.
+    pub fn new(team: Option<u32>, state: &mut State) -> Option<Team> {
+        let (sport_id, timezone) = (state.config.sport_id, state.config.timezone);
+        let row = state.current()?;
+        let date = NaiveDate::parse_from_str(&row.date, "%Y-%m-%d")
+            .unwrap_or_else(|_| chrono::Local::now().naive_local().date());
+        let team = match team {
+            Some(team) => team,
+            None => row.schedule.team()?,
+        };
+        let game = row.schedule.focused()?;
+        let clubs = [
+            (game.away.id, game.away.name.clone()),
+            (game.home.id, game.home.name.clone()),
+        ];
+        let showing = clubs.iter().position(|(id, _)| *id == team)?;
+        let mut pages = [None, None];
+        pages[showing] = Some(lineup::Team::new(team, date, sport_id, timezone));
+        Some(Team {
+            date,
+            clubs,
+            showing,
+            pages,
+            picked: None,
+        })
+    }
+
+    // Everybody on the roster of the club that is on screen, once their page has come in.
+    fn roster(&self) -> &[(u64, String)] {
+        self.pages[self.showing]
+            .as_ref()
+            .and_then(lineup::Team::page)
+            .map_or(&[][..], |page| page.roster.as_slice())
+    }
+
+    // Flips over to the other club, looking their page up if this is the first time.
+    fn flip(&mut self, config: &Config) {
+        self.showing = 1 - self.showing;
+        self.picked = None;
+        let (id, date) = (self.clubs[self.showing].0, self.date);
+        let (sport_id, timezone) = (config.sport_id, config.timezone);
+        self.pages[self.showing]
+            .get_or_insert_with(|| lineup::Team::new(id, date, sport_id, timezone));
+    }
+}
+
+impl Screen for Team {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) | Command::Action(Action::Team) => Transition::Pop,
+            Command::Action(Action::Left) | Command::Action(Action::Right) => {
+                self.flip(&state.config);
+                Transition::Stay
+            }
+            // Down starts at the top of the roster, and up off of the top lets go of it again.
+            Command::Action(Action::Down) => {
+                let players = self.roster().len();
+                if players > 0 {
+                    self.picked = Some(self.picked.map_or(0, |picked| picked + 1).min(players - 1));
+                }
+                Transition::Stay
+            }
+            Command::Action(Action::Up) => {
+                self.picked = self.picked.and_then(|picked| picked.checked_sub(1));
+                Transition::Stay
+            }
+            Command::Action(Action::Select) => {
+                match self.picked.and_then(|picked| self.roster().get(picked)) {
+                    Some((id, _)) => Transition::Push(Box::new(Player::new(*id))),
+                    None => Transition::Stay,
+                }
+            }
+            // Spoiler-free mode decides what goes on the page, so it can be flipped from here.
+            Command::Action(Action::Spoilers)
+            | Command::Action(Action::Fullscreen)
+            | Command::Action(Action::Debug) => Transition::Pass,
+            _ => Transition::Stay,
+        }
+    }
+
+    fn update(&mut self, state: &mut State) -> Transition {
+        if let Some(team) = &mut self.pages[self.showing] {
+            state.dirty |= team.poll(self.date);
+        }
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        let (layout, glyphs, theme) =
+            (&state.layout, &mut state.assets.glyphs, &state.config.theme);
+        let name = &self.clubs[self.showing].1;
+        let team = match &self.pages[self.showing] {
+            Some(team) => team,
+            None => return,
+        };
+        draw_team(
+            name,
+            team,
+            self.picked,
+            state.spoiler_free,
+            layout,
+            theme,
+            glyphs,
+            c,
+            g,
+        );
+        if let Some(schedule) = &state.schedule {
+            draw_footer(schedule.copyright(), layout, theme, glyphs, c, g);
+        }
+    }
+}
+
+// The club's name across the top with their record, ballpark, and how to get around underneath,
+// and then the columns, with the player who is picked out (if anybody is) picked out in the
+// accent color. Until the page comes in (or if it never does) there's only the name and a word
+// as to why that's all.
+fn draw_team(
+    name: &str,
+    team: &lineup::Team,
+    picked: Option<usize>,
+    spoiler_free: bool,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    piston_window::rectangle(theme.scrim, layout.fullscreen(), c.transform, g);
+    let (headline, heading, body) = (
+        layout.font_size(32),
+        layout.font_size(24),
+        layout.font_size(20),
+    );
+    let left = layout.length(100.0);
+    let mut top = left + layout.length(32.0);
+    let page = team.page();
+    let title = page.map_or(name, |page| page.name.as_str());
+    render::text(
+        theme.text,
+        headline,
+        title,
+        glyphs,
+        c.transform.trans(left, top),
+        layout.density,
+        g,
+    );
+    let page = match page {
+        Some(page) => page,
+        None => {
+            let status = match team.failed() {
+                true => "team-failed",
+                false => "team-loading",
+            };
+            let status = locale::text_with(status, &[("team", name)]);
+            top += layout.length(48.0);
+            render::text(
+                theme.text,
+                heading,
+                &status,
+                glyphs,
+                c.transform.trans(left, top),
+                layout.density,
+                g,
+            );
+            return;
+        }
+    };
+    let record = page
+        .record(spoiler_free)
+        .map(|record| locale::text_with("team-record", &[("record", &record)]));
+    let lines = record
+        .into_iter()
+        .chain(page.venue.clone())
+        .chain(Some(locale::text("team-hint")));
+    for line in lines {
+        top += layout.length(40.0);
+        render::text(
+            theme.text,
+            heading,
+            &line,
+            glyphs,
+            c.transform.trans(left, top),
+            layout.density,
+            g,
+        );
+    }
+    // The roster is usually 26 long, which is more than one column has room for. Each column
+    // knows where in the roster it starts, if it's a part of it at all.
+    let roster: Vec<String> = page
+        .roster
+        .iter()
+        .map(|(_, player)| player.clone())
+        .collect();
+    let half = roster.len().div_ceil(2);
+    let (roster, more) = roster.split_at(half);
+    let columns = [
+        (Some("team-played"), page.played(spoiler_free), None),
+        (Some("team-upcoming"), page.upcoming.clone(), None),
+        (Some("team-roster"), roster.to_vec(), Some(0)),
+        (None, more.to_vec(), Some(half)),
+    ];
+    for ((title, lines, start), [x, y]) in columns.iter().zip(layout.team_columns().iter()) {
+        if let Some(title) = title {
+            render::text(
+                theme.text,
+                heading,
+                &locale::text(title),
+                glyphs,
+                c.transform.trans(*x, *y),
+                layout.density,
+                g,
+            );
+        }
+        for (index, line) in lines.iter().enumerate() {
+            let top = y + layout.length(40.0 + 30.0 * index as f64);
+            let color = match (start, picked) {
+                (Some(start), Some(picked)) if start + index == picked => theme.accent,
+                _ => theme.text,
+            };
+            render::text(
+                color,
+                body,
+                line,
+                glyphs,
+                c.transform.trans(*x, top),
+                layout.density,
+                g,
+            );
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use super::{draw_footer, fit_lines, Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use chrono::NaiveDate;
+use mlb::api::transactions::Scope;
+use mlb::lineup;
+use piston_window::Transformed;
+
+/// The last month's trades, signings, and roster moves, newest first, for the team set with
+/// --transactions-team or else the whole league. Up and down scroll through them a move at a
+/// time, and page up and page down a page at a time.
+pub struct Transactions {
+    transactions: lineup::Transactions,
+}
+
+impl Transactions {
+    /// Looks up the month's moves leading up to the day on screen, or today if there's nothing
+    /// on screen yet.
+    pub fn new(state: &mut State) -> Transactions {
+        let scope = match state.config.transactions_team {
+            Some(team) => Scope::Team(team),
+            None => Scope::Sport(state.config.sport_id),
+        };
+        let today = state
+            .current()
+            .and_then(|row| NaiveDate::parse_from_str(&row.date, "%Y-%m-%d").ok())
+            .unwrap_or_else(|| chrono::Local::now().naive_local().date());
+        Transactions {
+            transactions: lineup::Transactions::new(scope, today),
+        }
+    }
+}
+
+impl Screen for Transactions {
+    fn handle_input(&mut self, command: Command, _state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) | Command::Action(Action::Transactions) => {
+                return Transition::Pop
+            }
+            Command::Action(Action::Up) => self.transactions.up(),
+            Command::Action(Action::Down) => self.transactions.down(),
+            Command::Action(Action::PreviousPage) => self.transactions.previous_page(),
+            Command::Action(Action::NextPage) => self.transactions.next_page(),
+            Command::Action(Action::Fullscreen) | Command::Action(Action::Debug) => {
+                return Transition::Pass
+            }
+            _ => (),
+        }
+        Transition::Stay
+    }
+
+    fn update(&mut self, state: &mut State) -> Transition {
+        state.dirty |= self.transactions.poll();
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        let (layout, glyphs, theme) =
+            (&state.layout, &mut state.assets.glyphs, &state.config.theme);
+        draw_transactions(&self.transactions, layout, theme, glyphs, c, g);
+        if let Some(schedule) = &state.schedule {
+            draw_footer(schedule.copyright(), layout, theme, glyphs, c, g);
+        }
+    }
+}
+
+// A title across the top with how to get around underneath, and then a line per move on the
+// current page, with the one that the cursor is on in the accent color. Descriptions that run
+// past the edge of the screen are cut off with an ellipsis.
+fn draw_transactions(
+    transactions: &lineup::Transactions,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    piston_window::rectangle(theme.scrim, layout.fullscreen(), c.transform, g);
+    let (headline, heading, body) = (
+        layout.font_size(32),
+        layout.font_size(24),
+        layout.font_size(20),
+    );
+    let left = layout.length(100.0);
+    let mut top = left + layout.length(32.0);
+    let title = match transactions.scope {
+        Scope::Team(_) => "transactions-team",
+        Scope::Sport(_) => "transactions-league",
+    };
+    render::text(
+        theme.text,
+        headline,
+        &locale::text(title),
+        glyphs,
+        c.transform.trans(left, top),
+        layout.density,
+        g,
+    );
+    let status = match (transactions.loading(), transactions.is_empty()) {
+        (true, _) => "transactions-loading",
+        (false, true) => "transactions-none",
+        (false, false) => "transactions-hint",
+    };
+    top += layout.length(48.0);
+    render::text(
+        theme.text,
+        heading,
+        &locale::text(status),
+        glyphs,
+        c.transform.trans(left, top),
+        layout.density,
+        g,
+    );
+    let [when, kind, what] = layout.transactions_columns();
+    let width = layout.fullscreen()[2] - what[0] - left;
+    for (index, (entry, focused)) in transactions.page().into_iter().enumerate() {
+        let offset = layout.length(44.0 * index as f64);
+        let color = match focused {
+            true => theme.accent,
+            false => theme.text,
+        };
+        let description = fit_lines(&entry.description, body, width, 1, glyphs).concat();
+        let columns = [
+            (&entry.date, when),
+            (&entry.kind, kind),
+            (&description, what),
+        ];
+        for (text, [x, y]) in columns.iter() {
+            render::text(
+                color,
+                body,
+                text,
+                glyphs,
+                c.transform.trans(*x, y + offset),
+                layout.density,
+                g,
+            );
+        }
+    }
+}
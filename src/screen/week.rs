@@ -0,0 +1,210 @@
+use super::{draw_footer, fit_lines, Screen, Transition};
+use crate::app::State;
+use crate::input::{Action, Command};
+use crate::layout;
+use crate::locale;
+use crate::render::{self, Fonts};
+use crate::theme::Theme;
+use chrono::{Datelike, Duration, NaiveDate};
+use mlb::lineup;
+use piston_window::Transformed;
+
+/// Seven days of games side by side, Monday through Sunday, with each game boiled down to a
+/// line. Left and right move between the days, up and down between a day's games, and page up
+/// and page down go back and forth a week at a time. Enter (or W again) opens the carousel up
+/// to the focused day, with the focused game picked out.
+pub struct Week {
+    week: lineup::Week,
+    // The day that the cursor is on, counting from Monday.
+    day: usize,
+    // The game that the cursor is on within that day, which is kept as is while moving
+    // between days so that going across a row of early games stays on them.
+    game: usize,
+}
+
+impl Week {
+    /// Opens up to the week of the day on screen, or of today if there's nothing on screen
+    /// yet, with the cursor on that day.
+    pub fn new(state: &mut State) -> Week {
+        let today = state
+            .current()
+            .and_then(|row| NaiveDate::parse_from_str(&row.date, "%Y-%m-%d").ok())
+            .unwrap_or_else(|| chrono::Local::now().naive_local().date());
+        let day = today.weekday().num_days_from_monday() as usize;
+        let start = today - Duration::days(day as i64);
+        Week {
+            week: lineup::Week::new(start, state.config.sport_id, state.config.timezone),
+            day,
+            game: 0,
+        }
+    }
+
+    // Sends for the week that starts the given number of weeks from this one, keeping the
+    // cursor where it is.
+    fn turn(&mut self, weeks: i64, state: &State) {
+        let start = self.week.start + Duration::weeks(weeks);
+        self.week = lineup::Week::new(start, state.config.sport_id, state.config.timezone);
+    }
+
+    // The focused day, and the focused game on it (if it has any games), for the carousel to
+    // open up to.
+    fn selected(&self) -> (NaiveDate, Option<u64>) {
+        let date = self.week.start + Duration::days(self.day as i64);
+        let pk = self
+            .week
+            .days()
+            .and_then(|days| days.get(self.day))
+            .and_then(|day| {
+                day.games
+                    .get(self.game.min(day.games.len().saturating_sub(1)))
+            })
+            .map(|game| game.pk);
+        (date, pk)
+    }
+
+    // How many games are on the focused day, which is none until the week comes in.
+    fn games(&self) -> usize {
+        self.week
+            .days()
+            .and_then(|days| days.get(self.day))
+            .map(|day| day.games.len())
+            .unwrap_or(0)
+    }
+}
+
+impl Screen for Week {
+    fn handle_input(&mut self, command: Command, state: &mut State) -> Transition {
+        match command {
+            Command::Action(Action::Back) => return Transition::Pop,
+            Command::Action(Action::Select) | Command::Action(Action::Week) => {
+                state.jump = Some(self.selected());
+                return Transition::Pop;
+            }
+            Command::Action(Action::Left) => self.day = self.day.saturating_sub(1),
+            Command::Action(Action::Right) => self.day = (self.day + 1).min(lineup::Week::DAYS - 1),
+            // Coming off of a longer day onto a shorter one, the cursor is wherever it shows up.
+            Command::Action(Action::Up) => {
+                self.game = self
+                    .game
+                    .min(self.games().saturating_sub(1))
+                    .saturating_sub(1)
+            }
+            Command::Action(Action::Down) => {
+                self.game = (self.game + 1).min(self.games().saturating_sub(1))
+            }
+            Command::Action(Action::PreviousPage) => self.turn(-1, state),
+            Command::Action(Action::NextPage) => self.turn(1, state),
+            Command::Action(Action::Fullscreen)
+            | Command::Action(Action::Debug)
+            | Command::Action(Action::Spoilers) => return Transition::Pass,
+            _ => (),
+        }
+        Transition::Stay
+    }
+
+    fn update(&mut self, state: &mut State) -> Transition {
+        state.dirty |= self.week.poll();
+        Transition::Stay
+    }
+
+    fn draw(&mut self, state: &mut State, c: graphics::Context, g: &mut piston_window::G2d) {
+        let (layout, glyphs, theme) =
+            (&state.layout, &mut state.assets.glyphs, &state.config.theme);
+        draw_week(self, state.spoiler_free, layout, theme, glyphs, c, g);
+        if let Some(schedule) = &state.schedule {
+            draw_footer(schedule.copyright(), layout, theme, glyphs, c, g);
+        }
+    }
+}
+
+// How to get around across the top, and then a column per day with the weekday and the date
+// over top of a line per game. The focused day's heading and its focused game are in the
+// accent color. Lines that run into the next column over are cut off with an ellipsis.
+fn draw_week(
+    screen: &Week,
+    spoiler_free: bool,
+    layout: &layout::Layout,
+    theme: &Theme,
+    glyphs: &mut Fonts,
+    c: graphics::Context,
+    g: &mut piston_window::G2d,
+) {
+    let week = &screen.week;
+    let focused_day = screen.day;
+    let focused_game = screen.game.min(screen.games().saturating_sub(1));
+    piston_window::rectangle(theme.scrim, layout.fullscreen(), c.transform, g);
+    let (heading, body) = (layout.font_size(24), layout.font_size(16));
+    let left = layout.length(40.0);
+    let status = match (week.loading(), week.failed()) {
+        (true, _) => "week-loading",
+        (false, true) => "week-failed",
+        (false, false) => "week-hint",
+    };
+    render::text(
+        theme.text,
+        heading,
+        &locale::text(status),
+        glyphs,
+        c.transform.trans(left, layout.length(120.0)),
+        layout.density,
+        g,
+    );
+    let columns = layout.week_columns();
+    let width = layout.length(240.0);
+    for (index, [x, y]) in columns.iter().enumerate() {
+        let date = week.start + Duration::days(index as i64);
+        let color = match index == focused_day {
+            true => theme.accent,
+            false => theme.text,
+        };
+        let headings = [
+            locale::date("date-weekday-only", date),
+            locale::date("date-month", date),
+        ];
+        for (line, text) in headings.iter().enumerate() {
+            render::text(
+                color,
+                heading,
+                text,
+                glyphs,
+                c.transform.trans(*x, y + layout.length(32.0 * line as f64)),
+                layout.density,
+                g,
+            );
+        }
+        let day = match week.days().and_then(|days| days.get(index)) {
+            Some(day) => day,
+            None => continue,
+        };
+        let top = y + layout.length(96.0);
+        if day.games.is_empty() {
+            let text = locale::text("week-none");
+            render::text(
+                theme.text,
+                body,
+                &text,
+                glyphs,
+                c.transform.trans(*x, top),
+                layout.density,
+                g,
+            );
+        }
+        for (number, game) in day.games.iter().enumerate() {
+            let color = match (index, number) == (focused_day, focused_game) {
+                true => theme.accent,
+                false => theme.text,
+            };
+            let text = fit_lines(&game.text(spoiler_free), body, width, 1, glyphs).concat();
+            render::text(
+                color,
+                body,
+                &text,
+                glyphs,
+                c.transform
+                    .trans(*x, top + layout.length(32.0 * number as f64)),
+                layout.density,
+                g,
+            );
+        }
+    }
+}
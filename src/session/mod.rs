@@ -0,0 +1,97 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Where the user left off, so that the next run can pick right back up from there. This is
+/// written out when the window closes and read back in when the next one opens.
+///
+/// Like the cache, nothing that goes wrong in here is worth stopping the show over. The worst
+/// case of a session that can't be read is starting out on today, which is what we always did.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    /// The date of the row that was up, E.G. "2018-06-10".
+    pub date: Option<String>,
+    /// Which of that row's games the cursor was on.
+    pub cursor: usize,
+    /// How big the window was, the last time that it wasn't fullscreen.
+    pub size: Option<[u32; 2]>,
+    /// Where the window's top left corner was on the screen, likewise.
+    pub position: Option<[i32; 2]>,
+}
+
+impl Session {
+    /// Where the session lives, E.G. ~/.local/state/mlb/session.json. That is, in
+    /// $XDG_STATE_HOME, falling back to ~/.local/state per the XDG spec.
+    pub fn user() -> Option<PathBuf> {
+        std::env::var_os("XDG_STATE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".local").join("state"))
+            })
+            .map(|root| root.join("mlb").join("session.json"))
+    }
+
+    /// The session saved at the given path. There not being one at all is just a first run,
+    /// and one that can't be made sense of is logged, but either way it's a fresh start.
+    pub fn load(path: &Path) -> Session {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(_) => return Session::default(),
+        };
+        serde_json::from_str(&text).unwrap_or_else(|err| {
+            warn!(path = %path.display(), error = %err, "Ignoring the saved session");
+            Session::default()
+        })
+    }
+
+    /// Writes the session out to the given path, replacing whatever was there.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // There is nothing in a Session that can fail to serialize.
+        std::fs::write(path, serde_json::to_string_pretty(self).unwrap())
+    }
+
+    /// The date of the row that was up, if it was a date at all.
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.date
+            .as_deref()
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("mlb-session-{}", std::process::id()))
+            .join("session.json");
+        assert_eq!(Session::load(&path), Session::default());
+        let session = Session {
+            date: Some("2018-06-10".to_string()),
+            cursor: 3,
+            size: Some([1280, 720]),
+            position: Some([-20, 40]),
+        };
+        session.save(&path).unwrap();
+        assert_eq!(Session::load(&path), session);
+        assert_eq!(
+            session.date(),
+            Some(NaiveDate::from_ymd_opt(2018, 6, 10).unwrap())
+        );
+        // Sessions from before the window was remembered still make sense.
+        std::fs::write(&path, r#"{"date": "2018-06-10", "cursor": 3}"#).unwrap();
+        assert_eq!(Session::load(&path).size, None);
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(Session::load(&path), Session::default());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
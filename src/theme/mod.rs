@@ -0,0 +1,145 @@
+use crate::layout;
+use serde::{Deserialize, Deserializer};
+use std::path::{Path, PathBuf};
+
+/// The colors (and the spacing) that everything is drawn with.
+///
+/// Colors are written the way that they are on the web, as "#RRGGBB" or "#RRGGBBAA" where the
+/// last two digits are the opacity. A theme file is TOML, and anything that it leaves out is
+/// left as it is by default, like so:
+///
+/// ```toml
+/// # Red Sox, more or less.
+/// background = "#0c2340"
+/// accent = "#bd3039"
+/// badge = "#bd3039cc"
+/// padding = 40
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    /// A solid color to fill the window with, in place of the usual background photo. One of
+    /// the user's own photos still goes over top of it (see --background).
+    #[serde(deserialize_with = "some_color")]
+    pub background: Option<[f32; 4]>,
+    /// Headlines, dates, and just about every other word on screen.
+    #[serde(deserialize_with = "color")]
+    pub text: [f32; 4],
+    /// The copyright notice along the bottom.
+    #[serde(deserialize_with = "color")]
+    pub small_print: [f32; 4],
+    /// Outlines the games that a favorite team is playing in, and labels the filter.
+    #[serde(deserialize_with = "color")]
+    pub accent: [f32; 4],
+    /// Dims the background behind anything that is text heavy.
+    #[serde(deserialize_with = "color")]
+    pub scrim: [f32; 4],
+    /// Behind the banners for things that went wrong.
+    #[serde(deserialize_with = "color")]
+    pub alert: [f32; 4],
+    /// Behind a game's score (or start time) along the bottom of its photo.
+    #[serde(deserialize_with = "color")]
+    pub badge: [f32; 4],
+    /// The score itself.
+    #[serde(deserialize_with = "color")]
+    pub badge_text: [f32; 4],
+    /// The room between the games on the carousel and around the edges of the screen, in
+    /// pixels on the 1920x1080 design.
+    pub padding: f64,
+}
+
+impl Theme {
+    /// Reads and parses the theme file at the given path.
+    pub fn load(path: &Path) -> Result<Theme, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {} ({})", path.display(), err))?;
+        Self::parse(&text).map_err(|err| format!("{}, {}", path.display(), err))
+    }
+
+    /// The defaults with whatever the given TOML says laid over top of them.
+    pub fn parse(text: &str) -> Result<Theme, String> {
+        let theme: Theme = toml::from_str(text).map_err(|err| err.to_string())?;
+        if !theme.padding.is_finite() || theme.padding < 0.0 {
+            return Err(format!("padding must be at least 0, not {}", theme.padding));
+        }
+        Ok(theme)
+    }
+
+    /// Where the theme lives when nobody says otherwise, E.G. ~/.config/mlb/theme.toml.
+    pub fn user() -> Option<PathBuf> {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .map(|root| root.join("mlb").join("theme.toml"))
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            background: None,
+            text: [1.0, 1.0, 1.0, 1.0],
+            small_print: [0.7, 0.7, 0.7, 1.0],
+            accent: [1.0, 0.75, 0.0, 1.0],
+            scrim: [0.0, 0.0, 0.0, 0.75],
+            alert: [0.55, 0.08, 0.08, 0.9],
+            badge: [0.0, 0.0, 0.0, 0.75],
+            badge_text: [1.0, 1.0, 1.0, 1.0],
+            padding: layout::DEFAULT_PADDING,
+        }
+    }
+}
+
+/// Reads a color the way that a theme file has it, E.G. "#ffbf00" or "#000000bf", with or
+/// without the # and in either case.
+pub fn parse_color(text: &str) -> Result<[f32; 4], String> {
+    let hex = text.trim_start_matches('#');
+    let err = || format!("{} is not a color, try something like #ffbf00", text);
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+        return Err(err());
+    }
+    let mut color = [1.0; 4];
+    for (index, channel) in color.iter_mut().enumerate().take(hex.len() / 2) {
+        let byte = u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).map_err(|_| err())?;
+        *channel = byte as f32 / 255.0;
+    }
+    Ok(color)
+}
+
+fn color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[f32; 4], D::Error> {
+    let text = String::deserialize(deserializer)?;
+    parse_color(&text).map_err(serde::de::Error::custom)
+}
+
+fn some_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<[f32; 4]>, D::Error> {
+    color(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors() {
+        assert_eq!(parse_color("#ffffff"), Ok([1.0; 4]));
+        assert_eq!(parse_color("FF000000"), Ok([1.0, 0.0, 0.0, 0.0]));
+        assert!(parse_color("#336699").is_ok());
+        assert!(parse_color("#fff").is_err());
+        assert!(parse_color("#gggggg").is_err());
+        assert!(parse_color("#ffé0f").is_err());
+    }
+
+    #[test]
+    fn partial_themes() {
+        assert_eq!(Theme::parse(""), Ok(Theme::default()));
+        let theme = Theme::parse("background = \"#0c2340\"\npadding = 40\n").unwrap();
+        assert_eq!(theme.padding, 40.0);
+        assert_eq!(theme.background.map(|color| color[3]), Some(1.0));
+        assert_eq!(theme.text, Theme::default().text);
+        // Typos are worth hearing about rather than quietly doing nothing.
+        assert!(Theme::parse("backgound = \"#000000\"").is_err());
+        assert!(Theme::parse("text = \"white\"").is_err());
+        assert!(Theme::parse("padding = -1").is_err());
+    }
+}
@@ -0,0 +1,284 @@
+//! The schedule in a terminal, for when there's no display to open a window on (over SSH, say).
+//!
+//! It's the very same Lineup that the window draws, paged and navigated the very same way,
+//! only drawn in text. Photos have no business in a terminal, so they're never downloaded.
+use chrono::NaiveDate;
+use clap::{App, Arg};
+use mlb::api;
+use mlb::lineup::{Lineup, Settings};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use std::time::{Duration, Instant};
+
+mod view;
+
+/// Everything that the user gets a say in at launch time. A much shorter list than the
+/// window's, since most of that list is about the window.
+struct Options {
+    date: Option<NaiveDate>,
+    days: u32,
+    sport_id: u32,
+    spoiler_free: bool,
+}
+
+impl Options {
+    fn from_args() -> Options {
+        let matches = App::new("mlb-tui")
+            .about("Browse the MLB schedule in a terminal")
+            .arg(
+                Arg::with_name("date")
+                    .long("date")
+                    .takes_value(true)
+                    .value_name("YYYY-MM-DD")
+                    .validator(|date| parse_date(&date).map(|_| ()))
+                    .help("The date to show (defaults to today)"),
+            )
+            .arg(
+                Arg::with_name("days")
+                    .long("days")
+                    .takes_value(true)
+                    .value_name("COUNT")
+                    .default_value("1")
+                    .validator(|days| parse_days(&days).map(|_| ()))
+                    .help("How many days to show at once, one row per day, up to 31"),
+            )
+            .arg(
+                Arg::with_name("sport-id")
+                    .long("sport-id")
+                    .takes_value(true)
+                    .value_name("ID")
+                    .default_value("1")
+                    .validator(|id| api::Sport::parse(&id).map(|_| ()))
+                    .help("The statsapi sportId to query (1 is MLB proper), or a level by name"),
+            )
+            .arg(
+                Arg::with_name("spoiler-free")
+                    .long("spoiler-free")
+                    .help("Hide scores and recaps until a game is revealed (toggle with S)"),
+            )
+            .get_matches();
+        Options {
+            date: matches
+                .value_of("date")
+                .map(|date| parse_date(date).unwrap()),
+            days: parse_days(matches.value_of("days").unwrap()).unwrap(),
+            sport_id: api::Sport::parse(matches.value_of("sport-id").unwrap()).unwrap(),
+            spoiler_free: matches.is_present("spoiler-free"),
+        }
+    }
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|err| {
+        format!(
+            "{} is not a valid date, expected YYYY-MM-DD ({})",
+            date, err
+        )
+    })
+}
+
+// More than a month at once is more rows than anybody could make sense of, the same as for
+// the window.
+fn parse_days(days: &str) -> Result<u32, String> {
+    match days.parse::<u32>() {
+        Ok(days) if (1..=31).contains(&days) => Ok(days),
+        _ => Err(format!(
+            "{} is not a valid number of days, it must be from 1 to 31",
+            days
+        )),
+    }
+}
+
+/// What is on screen, and what is on its way.
+pub struct Tui {
+    date: NaiveDate,
+    days: u32,
+    sport_id: u32,
+    /// None until the very first schedule comes in.
+    pub schedule: Option<Lineup>,
+    pub spoiler_free: bool,
+    /// Why the last attempt at the schedule didn't work out, if it didn't.
+    pub error: Option<String>,
+    pending: Option<crossbeam_channel::Receiver<api::APIResult<api::Schedule>>>,
+    // Dropping this calls off whatever is pending, which is what replacing it is for.
+    cancel: Option<api::Cancel>,
+    fetched: Instant,
+}
+
+impl Tui {
+    // The same as the window's refresh, give or take.
+    const REFRESH: Duration = Duration::from_secs(300);
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn new(options: Options) -> Tui {
+        let date = options
+            .date
+            .unwrap_or_else(|| chrono::Local::now().naive_local().date());
+        let mut tui = Tui {
+            date,
+            days: options.days,
+            sport_id: options.sport_id,
+            schedule: None,
+            spoiler_free: options.spoiler_free,
+            error: None,
+            pending: None,
+            cancel: None,
+            fetched: Instant::now(),
+        };
+        tui.fetch();
+        tui
+    }
+
+    /// Whether or not the schedule is on its way.
+    pub fn loading(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    // Starts downloading the schedule for the date that is up, replacing whatever download
+    // was already in flight.
+    fn fetch(&mut self) {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        let url = api::ScheduleRequest::lineup(self.date, self.days, self.sport_id).url();
+        let (cancel, cancelled) = api::cancellation();
+        api::spawn(async move {
+            let schedule = api::Schedule::try_from_within(url, Self::TIMEOUT, cancelled).await;
+            // Nobody waiting on this anymore just means that it was replaced.
+            let _ = tx.send(schedule);
+        });
+        self.pending = Some(rx);
+        self.cancel = Some(cancel);
+        self.fetched = Instant::now();
+    }
+
+    // Takes in whatever has come in since the last time, returning whether or not anything did.
+    fn update(&mut self) -> bool {
+        let mut changed = false;
+        if let Some(rx) = &self.pending {
+            match rx.try_recv() {
+                Ok(Ok(fresh)) => {
+                    match &mut self.schedule {
+                        Some(lineup) => lineup.merge(fresh),
+                        None => {
+                            // There's no way of showing photos in a terminal, so there's no
+                            // sense in downloading them.
+                            let settings = Settings {
+                                photos: false,
+                                ..Settings::default()
+                            };
+                            let mut lineup = Lineup::new(fresh, settings);
+                            lineup.set_spoiler_free(self.spoiler_free);
+                            self.schedule = Some(lineup);
+                        }
+                    }
+                    self.error = None;
+                    self.pending = None;
+                    changed = true;
+                }
+                Ok(Err(err)) => {
+                    self.error = Some(err.to_string());
+                    self.pending = None;
+                    changed = true;
+                }
+                Err(crossbeam_channel::TryRecvError::Empty) => (),
+                Err(crossbeam_channel::TryRecvError::Disconnected) => self.pending = None,
+            }
+        }
+        if self.pending.is_none() && self.fetched.elapsed() >= Self::REFRESH {
+            self.fetch();
+        }
+        if let Some(lineup) = &mut self.schedule {
+            changed |= lineup.poll();
+        }
+        changed
+    }
+
+    // Moves over to the given date, starting over with a fresh lineup.
+    fn walk(&mut self, date: NaiveDate) {
+        self.date = date;
+        self.schedule = None;
+        self.fetch();
+    }
+
+    // Reacts to the given key, returning whether or not to keep on going.
+    fn handle(&mut self, key: KeyCode) -> bool {
+        let span = chrono::Duration::days(self.days as i64);
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            // Up and down move between the rows, and walk the calendar once they run out.
+            KeyCode::Up | KeyCode::Char('k') => {
+                if !self.schedule.as_mut().is_some_and(Lineup::up) {
+                    self.walk(self.date - span);
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if !self.schedule.as_mut().is_some_and(Lineup::down) {
+                    self.walk(self.date + span);
+                }
+            }
+            KeyCode::Char('t') => self.walk(chrono::Local::now().naive_local().date()),
+            KeyCode::F(5) => self.fetch(),
+            KeyCode::Char('s') => {
+                self.spoiler_free = !self.spoiler_free;
+                let spoiler_free = self.spoiler_free;
+                if let Some(lineup) = &mut self.schedule {
+                    lineup.set_spoiler_free(spoiler_free);
+                }
+            }
+            // Everything else is about the row that is up.
+            key => {
+                if let Some(row) = self.schedule.as_mut().and_then(Lineup::current) {
+                    let schedule = &mut row.schedule;
+                    match key {
+                        KeyCode::Left | KeyCode::Char('h') => schedule.left(),
+                        KeyCode::Right | KeyCode::Char('l') => schedule.right(),
+                        KeyCode::PageUp => schedule.previous_page(),
+                        KeyCode::PageDown => schedule.next_page(),
+                        KeyCode::Home => schedule.first(),
+                        KeyCode::End => schedule.last(),
+                        KeyCode::Char('r') => schedule.reveal(),
+                        _ => (),
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let options = Options::from_args();
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, Tui::new(options));
+    ratatui::restore();
+    mlb::api::shutdown();
+    if let Err(err) = result {
+        eprintln!("mlb-tui: {}", err);
+        std::process::exit(1);
+    }
+}
+
+// The main loop. The terminal is redrawn whenever something has changed, and otherwise
+// every so often to keep up with the network.
+fn run(terminal: &mut ratatui::DefaultTerminal, mut tui: Tui) -> std::io::Result<()> {
+    let mut dirty = true;
+    loop {
+        dirty |= tui.update();
+        if dirty {
+            terminal.draw(|frame| view::draw(frame, &mut tui))?;
+            dirty = false;
+        }
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                if !tui.handle(key.code) {
+                    return Ok(());
+                }
+                dirty = true;
+            }
+            Event::Resize(_, _) => dirty = true,
+            _ => (),
+        }
+    }
+}
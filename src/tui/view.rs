@@ -0,0 +1,153 @@
+use crate::Tui;
+use mlb::lineup::Game;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+
+// The same gold that the window focuses things with, or as close as a terminal gets.
+const GOLD: Color = Color::Rgb(255, 215, 0);
+
+/// Draws everything: the date up top, the page of games, the focused game's details, and
+/// the keys along the bottom.
+pub fn draw(frame: &mut Frame, tui: &mut Tui) {
+    let [header, games, detail, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(7),
+        Constraint::Length(8),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    frame.render_widget(Paragraph::new(heading(tui)), header);
+    frame.render_widget(
+        Paragraph::new(
+            "←/→ games  PgUp/PgDn pages  ↑/↓ days  t today  s spoilers  r reveal  F5 refresh  q quit",
+        )
+        .style(Style::default().fg(Color::DarkGray)),
+        footer,
+    );
+    let spoiler_free = tui.spoiler_free;
+    let row = match tui.schedule.as_mut().and_then(|lineup| lineup.current()) {
+        Some(row) => row,
+        None => {
+            let text = match (&tui.error, tui.loading()) {
+                (Some(err), _) => format!("Couldn't get the schedule ({})", err),
+                (None, true) => "Loading the schedule...".to_string(),
+                (None, false) => "No games today!".to_string(),
+            };
+            frame.render_widget(Paragraph::new(text).wrap(Wrap { trim: true }), games);
+            return;
+        }
+    };
+    let schedule = &mut row.schedule;
+    if schedule.is_empty() {
+        frame.render_widget(Paragraph::new("No games today!"), games);
+        return;
+    }
+    let title = format!(
+        " Page {} of {} ",
+        schedule.games.page() + 1,
+        schedule.games.pages()
+    );
+    let focus = schedule.games.page_focus();
+    let items: Vec<ListItem> = schedule
+        .games
+        .current_page()
+        .iter()
+        .enumerate()
+        .map(|(index, game)| item(game, index == focus, spoiler_free))
+        .collect();
+    frame.render_widget(
+        List::new(items).block(Block::default().borders(Borders::ALL).title(title)),
+        games,
+    );
+    if let Some(game) = schedule.focused() {
+        draw_detail(frame, game, spoiler_free, detail);
+    }
+}
+
+// E.G. "Sunday, June 10", and then whether there are more days above or below, and whether
+// the schedule is on its way or has failed to get here.
+fn heading(tui: &mut Tui) -> Line<'static> {
+    let mut spans = vec![];
+    if let Some(lineup) = tui.schedule.as_mut() {
+        if let (above, Some(row), below) = lineup.neighborhood() {
+            let date = chrono::NaiveDate::parse_from_str(&row.date, "%Y-%m-%d")
+                .map(|date| date.format("%A, %B %-d").to_string())
+                .unwrap_or_else(|_| row.date.clone());
+            let bold = Style::default().add_modifier(Modifier::BOLD);
+            spans.push(Span::styled(date, bold));
+            if above.is_some() || below.is_some() {
+                spans.push(Span::raw(" (more days ↑↓)"));
+            }
+        }
+    }
+    if tui.loading() && tui.schedule.is_some() {
+        spans.push(Span::raw("  refreshing..."));
+    }
+    if tui.spoiler_free {
+        spans.push(Span::styled("  spoiler-free", Style::default().fg(GOLD)));
+    }
+    Line::from(spans)
+}
+
+// One line for one game: its badge (the score, or when it starts) and its headline. Games
+// that would give something away get neither, just who is playing and when.
+fn item(game: &Game, focused: bool, spoiler_free: bool) -> ListItem<'static> {
+    let hidden = spoiler_free && game.spoils();
+    let (badge, text) = match hidden {
+        true => (game.first_pitch.clone(), game.teaser().to_string()),
+        false => (game.badge(), game.headline.clone()),
+    };
+    let marker = if focused { "▶ " } else { "  " };
+    let style = match focused {
+        true => Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
+        false => Style::default(),
+    };
+    ListItem::new(Line::from(vec![
+        Span::styled(marker, style),
+        Span::styled(format!("{:<32}", badge.unwrap_or_default()), style),
+        Span::styled(text, style),
+    ]))
+}
+
+// Everything there is to say about the focused game, short of the photo.
+fn draw_detail(frame: &mut Frame, game: &mut Game, spoiler_free: bool, area: Rect) {
+    let hidden = spoiler_free && game.spoils();
+    let block = Block::default().borders(Borders::ALL);
+    let mut lines = vec![];
+    if hidden {
+        lines.push(Line::from(game.teaser().to_string()));
+        if let Some(first_pitch) = &game.first_pitch {
+            lines.push(Line::from(format!("First pitch at {}", first_pitch)));
+        }
+        lines.push(Line::from("Press r to see how it went."));
+    } else {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        lines.push(Line::from(Span::styled(game.headline.clone(), bold)));
+        if !game.subhead.is_empty() {
+            lines.push(Line::from(game.subhead.clone()));
+        }
+        lines.push(Line::from(game.matchup()));
+        if let Some(records) = game.records() {
+            lines.push(Line::from(records));
+        }
+        let status = match game.live() {
+            Some(live) => format!("{}, {}", game.status, live),
+            None => game.status.clone(),
+        };
+        lines.push(Line::from(status));
+        if let (Some(winner), Some(loser)) = (&game.winner, &game.loser) {
+            let mut decisions = format!("W: {}  L: {}", winner.name, loser.name);
+            if let Some(save) = &game.save {
+                decisions.push_str(&format!("  SV: {}", save.name));
+            }
+            lines.push(Line::from(decisions));
+        }
+    }
+    frame.render_widget(
+        Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+        area,
+    );
+}